@@ -1,6 +1,820 @@
-pub fn format_amount(amount: i32) -> String {
-    amount.to_string()
+//! 金额工具类模块
+//! 提供金额格式化与定点数运算相关的工具函数，使用整数存储金额以避免浮点误差
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
+use std::str::FromStr;
+
+/// 按指定的千分位分隔符和小数点格式化金额
+///
+/// # 参数
+/// * `value` - 未缩放的整数金额，例如 `123456` 配合 `scale = 2` 表示 `1234.56`
+/// * `scale` - 小数位数
+/// * `thousands_sep` - 千分位分隔符，如 `,`、`.` 或空格
+/// * `decimal_sep` - 小数点，如 `.` 或 `,`
+///
+/// # 返回值
+/// 返回格式化后的金额字符串，负数的负号位于最前面
+///
+/// # 注意
+/// `scale` 不能超过 18，否则此函数会 panic
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::amount;
+///
+/// assert_eq!(amount::format_amount(123456, 2, ',', '.'), "1,234.56"); // 美国
+/// assert_eq!(amount::format_amount(123456, 2, '.', ','), "1.234,56"); // 德国
+/// assert_eq!(amount::format_amount(123456, 2, ' ', ','), "1 234,56"); // 法国
+/// assert_eq!(amount::format_amount(-5, 2, ',', '.'), "-0.05");
+/// ```
+pub fn format_amount(value: i64, scale: u8, thousands_sep: char, decimal_sep: char) -> String {
+    assert!(scale <= MAX_SCALE, "scale must not exceed {}", MAX_SCALE);
+    let abs = value.unsigned_abs();
+    let factor = 10u128.pow(scale as u32);
+    let integer = (abs as u128 / factor).to_string();
+    let fraction = abs as u128 % factor;
+
+    let mut result = String::new();
+    if value < 0 {
+        result.push('-');
+    }
+    for (i, c) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i).is_multiple_of(3) {
+            result.push(thousands_sep);
+        }
+        result.push(c);
+    }
+    if scale > 0 {
+        result.push(decimal_sep);
+        result.push_str(&format!("{:0width$}", fraction, width = scale as usize));
+    }
+    result
+}
+
+/// 格式化带货币符号的金额，使用 `,` 作为千分位分隔符、`.` 作为小数点
+///
+/// # 参数
+/// * `value` - 未缩放的整数金额
+/// * `scale` - 小数位数
+/// * `symbol` - 货币符号，如 `$`、`¥` 或 ` €`
+/// * `symbol_after` - 为 `true` 时货币符号追加在金额之后，否则放在金额之前（负号之后）
+///
+/// # 返回值
+/// 返回带货币符号的金额字符串
+///
+/// # 注意
+/// `scale` 不能超过 18，否则此函数会 panic
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::amount;
+///
+/// assert_eq!(amount::format_currency(123456, 2, "$", false), "$1,234.56");
+/// assert_eq!(amount::format_currency(-123456, 2, "$", false), "-$1,234.56");
+/// assert_eq!(amount::format_currency(123456, 2, " €", true), "1,234.56 €");
+/// ```
+pub fn format_currency(value: i64, scale: u8, symbol: &str, symbol_after: bool) -> String {
+    let formatted = format_amount(value, scale, ',', '.');
+    if symbol_after {
+        return format!("{}{}", formatted, symbol);
+    }
+    match formatted.strip_prefix('-') {
+        Some(abs) => format!("-{}{}", symbol, abs),
+        None => format!("{}{}", symbol, formatted),
+    }
+}
+
+/// 金额支持的最大小数位数，保证 `10^scale` 不会超出 `i64` 的范围
+const MAX_SCALE: u8 = 18;
+
+/// 金额解析与计算过程中可能出现的错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmountError {
+    /// 输入为空
+    EmptyInput,
+    /// 包含非法字符
+    InvalidCharacter(char),
+    /// 包含多个小数点
+    MultipleDecimalPoints,
+    /// 数值超出 `i64` 的表示范围
+    Overflow,
+}
+
+impl fmt::Display for AmountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AmountError::EmptyInput => write!(f, "amount is empty"),
+            AmountError::InvalidCharacter(c) => write!(f, "invalid character '{}' in amount", c),
+            AmountError::MultipleDecimalPoints => {
+                write!(f, "amount contains multiple decimal points")
+            }
+            AmountError::Overflow => write!(f, "amount overflow"),
+        }
+    }
+}
+
+impl std::error::Error for AmountError {}
+
+/// 定点数金额
+///
+/// 金额以未缩放的整数 `value` 和小数位数 `scale` 表示，例如 `12.34` 表示为
+/// `value = 1234, scale = 2`，所有运算均为整数运算，不会产生浮点误差
+///
+/// 不同小数位数的金额进行加减或比较时，会先统一到较大的小数位数
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::amount::Amount;
+///
+/// let price: Amount = "12.34".parse().unwrap();
+/// let total = price * 3 + Amount::new(5, 1);
+/// assert_eq!(total.to_string(), "37.52");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Amount {
+    /// 未缩放的整数值
+    value: i64,
+    /// 小数位数
+    scale: u8,
+}
+
+impl Amount {
+    /// 创建一个金额
+    ///
+    /// # 参数
+    /// * `value` - 未缩放的整数值
+    /// * `scale` - 小数位数
+    ///
+    /// # 注意
+    /// `scale` 不能超过 18，否则此函数会 panic
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::amount::Amount;
+    ///
+    /// let amount = Amount::new(1234, 2);
+    /// assert_eq!(amount.to_string(), "12.34");
+    /// ```
+    pub fn new(value: i64, scale: u8) -> Self {
+        assert!(scale <= MAX_SCALE, "scale must not exceed {}", MAX_SCALE);
+        Amount { value, scale }
+    }
+
+    /// 创建一个值为零的金额
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::amount::Amount;
+    ///
+    /// assert_eq!(Amount::zero(2).to_string(), "0.00");
+    /// ```
+    pub fn zero(scale: u8) -> Self {
+        Amount::new(0, scale)
+    }
+
+    /// 获取未缩放的整数值
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::amount::Amount;
+    ///
+    /// assert_eq!(Amount::new(1234, 2).value(), 1234);
+    /// ```
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    /// 获取小数位数
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::amount::Amount;
+    ///
+    /// assert_eq!(Amount::new(1234, 2).scale(), 2);
+    /// ```
+    pub fn scale(&self) -> u8 {
+        self.scale
+    }
+
+    /// 转换为浮点数
+    ///
+    /// # 注意
+    /// 转换结果可能存在精度损失，仅建议用于展示或近似计算
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::amount::Amount;
+    ///
+    /// assert_eq!(Amount::new(1234, 2).to_f64(), 12.34);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        self.value as f64 / 10f64.powi(self.scale as i32)
+    }
+
+    /// 将金额调整为指定的小数位数
+    ///
+    /// # 参数
+    /// * `scale` - 目标小数位数，不能小于当前小数位数
+    ///
+    /// # 返回值
+    /// 返回调整后的金额，如果目标小数位数小于当前小数位数或结果溢出则返回 `None`
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::amount::Amount;
+    ///
+    /// let amount = Amount::new(15, 1).rescale(3).unwrap();
+    /// assert_eq!(amount.value(), 1500);
+    /// assert_eq!(Amount::new(1500, 3).rescale(1), None);
+    /// ```
+    pub fn rescale(&self, scale: u8) -> Option<Amount> {
+        if scale < self.scale || scale > MAX_SCALE {
+            return None;
+        }
+        10i64
+            .checked_pow((scale - self.scale) as u32)
+            .and_then(|factor| self.value.checked_mul(factor))
+            .map(|value| Amount { value, scale })
+    }
+
+    /// 带溢出检查的加法
+    ///
+    /// # 返回值
+    /// 返回两个金额之和，小数位数取两者中较大的一个，如果溢出则返回 `None`
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::amount::Amount;
+    ///
+    /// let sum = Amount::new(150, 2).checked_add(Amount::new(5, 1)).unwrap();
+    /// assert_eq!(sum.to_string(), "2.00");
+    /// assert_eq!(Amount::new(i64::MAX, 2).checked_add(Amount::new(1, 2)), None);
+    /// ```
+    pub fn checked_add(self, rhs: Amount) -> Option<Amount> {
+        let (lhs, rhs) = align(self, rhs)?;
+        lhs.value
+            .checked_add(rhs.value)
+            .map(|value| Amount::new(value, lhs.scale))
+    }
+
+    /// 带溢出检查的减法
+    ///
+    /// # 返回值
+    /// 返回两个金额之差，小数位数取两者中较大的一个，如果溢出则返回 `None`
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::amount::Amount;
+    ///
+    /// let diff = Amount::new(150, 2).checked_sub(Amount::new(5, 1)).unwrap();
+    /// assert_eq!(diff.to_string(), "1.00");
+    /// assert_eq!(Amount::new(i64::MIN, 2).checked_sub(Amount::new(1, 2)), None);
+    /// ```
+    pub fn checked_sub(self, rhs: Amount) -> Option<Amount> {
+        let (lhs, rhs) = align(self, rhs)?;
+        lhs.value
+            .checked_sub(rhs.value)
+            .map(|value| Amount::new(value, lhs.scale))
+    }
+
+    /// 带溢出检查的乘法
+    ///
+    /// # 返回值
+    /// 返回金额乘以整数后的结果，如果溢出则返回 `None`
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::amount::Amount;
+    ///
+    /// assert_eq!(Amount::new(150, 2).checked_mul(3).unwrap().to_string(), "4.50");
+    /// assert_eq!(Amount::new(i64::MAX, 2).checked_mul(2), None);
+    /// ```
+    pub fn checked_mul(self, rhs: i64) -> Option<Amount> {
+        self.value
+            .checked_mul(rhs)
+            .map(|value| Amount::new(value, self.scale))
+    }
+
+    /// 带溢出检查的除法
+    ///
+    /// # 返回值
+    /// 返回金额除以整数后的结果（向零截断），如果除数为零或溢出则返回 `None`
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::amount::Amount;
+    ///
+    /// assert_eq!(Amount::new(1000, 2).checked_div(3).unwrap().to_string(), "3.33");
+    /// assert_eq!(Amount::new(1000, 2).checked_div(0), None);
+    /// ```
+    pub fn checked_div(self, rhs: i64) -> Option<Amount> {
+        self.value
+            .checked_div(rhs)
+            .map(|value| Amount::new(value, self.scale))
+    }
+}
+
+/// 将两个金额统一到较大的小数位数
+fn align(lhs: Amount, rhs: Amount) -> Option<(Amount, Amount)> {
+    let scale = lhs.scale.max(rhs.scale);
+    Some((lhs.rescale(scale)?, rhs.rescale(scale)?))
+}
+
+impl Add for Amount {
+    type Output = Amount;
+
+    fn add(self, rhs: Amount) -> Amount {
+        self.checked_add(rhs)
+            .expect("attempt to add amounts with overflow")
+    }
+}
+
+impl Sub for Amount {
+    type Output = Amount;
+
+    fn sub(self, rhs: Amount) -> Amount {
+        self.checked_sub(rhs)
+            .expect("attempt to subtract amounts with overflow")
+    }
+}
+
+impl Mul<i64> for Amount {
+    type Output = Amount;
+
+    fn mul(self, rhs: i64) -> Amount {
+        self.checked_mul(rhs)
+            .expect("attempt to multiply amount with overflow")
+    }
 }
 
+impl Div<i64> for Amount {
+    type Output = Amount;
 
+    fn div(self, rhs: i64) -> Amount {
+        self.checked_div(rhs)
+            .expect("attempt to divide amount by zero or with overflow")
+    }
+}
+
+impl PartialEq for Amount {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
 
+impl Eq for Amount {}
+
+impl PartialOrd for Amount {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Amount {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // 使用 i128 比较，避免统一小数位数时溢出
+        let scale = self.scale.max(other.scale);
+        let lhs = self.value as i128 * 10i128.pow((scale - self.scale) as u32);
+        let rhs = other.value as i128 * 10i128.pow((scale - other.scale) as u32);
+        lhs.cmp(&rhs)
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.value < 0 { "-" } else { "" };
+        let abs = self.value.unsigned_abs();
+        if self.scale == 0 {
+            return write!(f, "{}{}", sign, abs);
+        }
+        let factor = 10u64.pow(self.scale as u32);
+        write!(
+            f,
+            "{}{}.{:0width$}",
+            sign,
+            abs / factor,
+            abs % factor,
+            width = self.scale as usize
+        )
+    }
+}
+
+impl FromStr for Amount {
+    type Err = AmountError;
+
+    /// 从十进制字符串解析金额，小数位数由小数点后的位数决定
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::amount::{Amount, AmountError};
+    /// use std::str::FromStr;
+    ///
+    /// let amount = Amount::from_str("-12.340").unwrap();
+    /// assert_eq!(amount.value(), -12340);
+    /// assert_eq!(amount.scale(), 3);
+    /// assert_eq!(Amount::from_str("1.2.3"), Err(AmountError::MultipleDecimalPoints));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = split_sign(s.trim());
+        parse_digits(digits, negative, '.', None).map(|(value, scale)| Amount::new(value, scale))
+    }
+}
+
+/// 解析金额时会被忽略的常见货币符号
+const CURRENCY_SYMBOLS: [char; 14] = [
+    '$', '€', '£', '¥', '￥', '₹', '₩', '₽', '₺', '₫', '₱', '฿', '¢', '元',
+];
+
+/// 从格式化的金额字符串中解析出未缩放的整数金额和小数位数
+///
+/// 解析时会去除首尾的常见货币符号（如 `$`、`€`、`¥`）和空白字符，并自动识别小数点：
+/// * 同时出现 `.` 和 `,` 时，最后出现的一个为小数点，另一个为千分位分隔符
+/// * 只出现一种且出现多次时，视为千分位分隔符
+/// * 只出现一种且只出现一次时，视为小数点
+///
+/// 数字中间的空格和 `'` 始终视为千分位分隔符
+///
+/// # 参数
+/// * `s` - 格式化的金额字符串
+///
+/// # 返回值
+/// 成功时返回 `(未缩放的整数金额, 小数位数)`，失败时返回对应的 `AmountError`
+///
+/// # 注意
+/// `"1,234"` 这类只有一个分隔符的字符串会被解析为 `1.234`，
+/// 如需明确指定分隔符，请使用 `parse_amount_with_config`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::amount::{self, AmountError};
+///
+/// assert_eq!(amount::parse_amount("$1,234.56"), Ok((123456, 2)));
+/// assert_eq!(amount::parse_amount("1.234,56 €"), Ok((123456, 2)));
+/// assert_eq!(amount::parse_amount("-500.00"), Ok((-50000, 2)));
+/// assert_eq!(amount::parse_amount(""), Err(AmountError::EmptyInput));
+/// ```
+pub fn parse_amount(s: &str) -> Result<(i64, u8), AmountError> {
+    let (negative, body) = split_amount_body(s);
+    let body: String = body
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '\'')
+        .collect();
+
+    let dots = body.matches('.').count();
+    let commas = body.matches(',').count();
+    let (decimal_sep, thousands_sep) = match (dots, commas) {
+        (0, 0) => ('.', None),
+        (_, 0) if dots > 1 => (',', Some('.')),
+        (0, _) if commas > 1 => ('.', Some(',')),
+        (_, 0) => ('.', None),
+        (0, _) => (',', None),
+        _ if body.rfind('.') > body.rfind(',') => ('.', Some(',')),
+        _ => (',', Some('.')),
+    };
+
+    if let Some(sep) = thousands_sep {
+        let integer = body.split(decimal_sep).next().unwrap_or("");
+        if !is_valid_grouping(integer, sep) {
+            // 同一个分隔符出现多次且无法构成千分位分组，更可能是多个小数点
+            return Err(if dots == 0 || commas == 0 {
+                AmountError::MultipleDecimalPoints
+            } else {
+                AmountError::InvalidCharacter(sep)
+            });
+        }
+    }
+
+    parse_digits(&body, negative, decimal_sep, thousands_sep)
+}
+
+/// 按指定的小数点和千分位分隔符解析格式化的金额字符串
+///
+/// 与 `parse_amount` 一样会去除首尾的货币符号和空白字符，但不会自动识别分隔符，
+/// 千分位分隔符可以出现在整数部分的任意位置
+///
+/// # 参数
+/// * `s` - 格式化的金额字符串
+/// * `decimal_sep` - 小数点
+/// * `thousands_sep` - 千分位分隔符
+///
+/// # 返回值
+/// 成功时返回 `(未缩放的整数金额, 小数位数)`，失败时返回对应的 `AmountError`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::amount::{self, AmountError};
+///
+/// assert_eq!(amount::parse_amount_with_config("1,234", '.', ','), Ok((1234, 0)));
+/// assert_eq!(amount::parse_amount_with_config("1 234,5", ',', ' '), Ok((12345, 1)));
+/// assert_eq!(
+///     amount::parse_amount_with_config("1,2,3", ',', '.'),
+///     Err(AmountError::MultipleDecimalPoints)
+/// );
+/// ```
+pub fn parse_amount_with_config(
+    s: &str,
+    decimal_sep: char,
+    thousands_sep: char,
+) -> Result<(i64, u8), AmountError> {
+    let (negative, body) = split_amount_body(s);
+    parse_digits(body, negative, decimal_sep, Some(thousands_sep))
+}
+
+/// 拆分出正负号和剩余部分
+fn split_sign(s: &str) -> (bool, &str) {
+    match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    }
+}
+
+/// 去除首尾的货币符号和空白字符，并拆分出正负号，符号可位于货币符号之前或之后
+fn split_amount_body(s: &str) -> (bool, &str) {
+    let is_trimmed = |c: char| c.is_whitespace() || CURRENCY_SYMBOLS.contains(&c);
+    let (negative, rest) = split_sign(s.trim_matches(is_trimmed));
+    let rest = rest.trim_matches(is_trimmed);
+    if negative {
+        return (true, rest);
+    }
+    split_sign(rest)
+}
+
+/// 校验整数部分的千分位分组：第一组 1 ~ 3 位，其余每组 3 位
+fn is_valid_grouping(integer: &str, sep: char) -> bool {
+    integer.split(sep).enumerate().all(|(i, group)| {
+        if i == 0 {
+            (1..=3).contains(&group.len()) || !integer.contains(sep)
+        } else {
+            group.len() == 3
+        }
+    })
+}
+
+/// 解析不含正负号的数字部分
+fn parse_digits(
+    digits: &str,
+    negative: bool,
+    decimal_sep: char,
+    thousands_sep: Option<char>,
+) -> Result<(i64, u8), AmountError> {
+    if digits.is_empty() {
+        return Err(AmountError::EmptyInput);
+    }
+
+    let mut value: i64 = 0;
+    let mut scale: u8 = 0;
+    let mut seen_point = false;
+    let mut seen_digit = false;
+    for c in digits.chars() {
+        match c {
+            c if c == decimal_sep && seen_point => return Err(AmountError::MultipleDecimalPoints),
+            c if c == decimal_sep => seen_point = true,
+            c if Some(c) == thousands_sep && !seen_point => {}
+            '0'..='9' => {
+                seen_digit = true;
+                let digit = c as i64 - '0' as i64;
+                let digit = if negative { -digit } else { digit };
+                value = value
+                    .checked_mul(10)
+                    .and_then(|v| v.checked_add(digit))
+                    .ok_or(AmountError::Overflow)?;
+                if seen_point {
+                    scale += 1;
+                    if scale > MAX_SCALE {
+                        return Err(AmountError::Overflow);
+                    }
+                }
+            }
+            _ => return Err(AmountError::InvalidCharacter(c)),
+        }
+    }
+    if !seen_digit {
+        return Err(AmountError::InvalidCharacter(decimal_sep));
+    }
+    Ok((value, scale))
+}
+
+/// 将金额尽可能平均地拆分为 `n` 份
+///
+/// 无法整除时，余下的最小单位（如"分"）依次分配给前面的若干份，
+/// 保证拆分结果之和始终等于 `total`，且任意两份之间最多相差 1 个最小单位
+///
+/// # 参数
+/// * `total` - 未缩放的整数总金额
+/// * `scale` - 小数位数，拆分以 `10^-scale` 为最小单位进行
+/// * `n` - 拆分的份数
+///
+/// # 返回值
+/// 返回拆分后的金额列表，`n` 为 0 时返回空列表
+///
+/// # 注意
+/// 拆分本身与小数位数无关，但为了与本模块其它函数保持一致，`scale` 不能超过 18，否则此函数会 panic
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::amount;
+///
+/// // 100.00 元分给 3 个人
+/// assert_eq!(amount::split_amount(10000, 2, 3), vec![3334, 3333, 3333]);
+/// assert_eq!(amount::split_amount(-10, 2, 3), vec![-4, -3, -3]);
+/// ```
+pub fn split_amount(total: i64, scale: u8, n: u32) -> Vec<i64> {
+    assert!(scale <= MAX_SCALE, "scale must not exceed {}", MAX_SCALE);
+    if n == 0 {
+        return Vec::new();
+    }
+    let quotient = total / n as i64;
+    let remainder = (total % n as i64).unsigned_abs();
+    (0..n as u64)
+        .map(|i| {
+            if i < remainder {
+                quotient + total.signum()
+            } else {
+                quotient
+            }
+        })
+        .collect()
+}
+
+/// 按比例分配金额
+///
+/// 使用最大余数法：先按比例向零取整得到每份的基础金额，
+/// 再将剩余的最小单位依次分配给余数最大的几份（余数相同时优先分配给靠前的份），
+/// 保证分配结果之和始终等于 `total`
+///
+/// # 参数
+/// * `total` - 未缩放的整数总金额
+/// * `scale` - 小数位数，分配以 `10^-scale` 为最小单位进行
+/// * `ratios` - 各份的比例
+///
+/// # 返回值
+/// 返回与 `ratios` 一一对应的金额列表，如果 `ratios` 为空或比例之和为 0 则返回空列表
+///
+/// # 注意
+/// 分配本身与小数位数无关，但为了与本模块其它函数保持一致，`scale` 不能超过 18，否则此函数会 panic
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::amount;
+///
+/// assert_eq!(amount::allocate(10000, 2, &[1, 1, 1]), vec![3334, 3333, 3333]);
+/// assert_eq!(amount::allocate(10000, 2, &[70, 20, 10]), vec![7000, 2000, 1000]);
+/// assert_eq!(amount::allocate(5, 2, &[1, 2]), vec![2, 3]);
+/// ```
+pub fn allocate(total: i64, scale: u8, ratios: &[u32]) -> Vec<i64> {
+    assert!(scale <= MAX_SCALE, "scale must not exceed {}", MAX_SCALE);
+    let ratio_sum: i128 = ratios.iter().map(|&r| r as i128).sum();
+    if ratio_sum == 0 {
+        return Vec::new();
+    }
+
+    // 使用 i128 计算，避免 total * ratio 溢出
+    let mut shares = Vec::with_capacity(ratios.len());
+    let mut remainders = Vec::with_capacity(ratios.len());
+    for (i, &ratio) in ratios.iter().enumerate() {
+        let product = total as i128 * ratio as i128;
+        shares.push((product / ratio_sum) as i64);
+        remainders.push((i, (product % ratio_sum).abs()));
+    }
+
+    let allocated: i64 = shares.iter().sum();
+    let leftover = (total - allocated).unsigned_abs() as usize;
+    remainders.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    for &(i, _) in remainders.iter().take(leftover) {
+        shares[i] += total.signum();
+    }
+    shares
+}
+
+/// 计算不含税金额对应的税额
+///
+/// 税额按 `amount * tax_rate_bps / 10000` 计算，全程使用整数运算，
+/// 不足一个最小单位的部分四舍五入（远离零方向）
+///
+/// # 参数
+/// * `amount` - 未缩放的不含税金额
+/// * `scale` - 小数位数
+/// * `tax_rate_bps` - 税率，单位为基点（万分之一），如 `2000` 表示 20%
+///
+/// # 返回值
+/// 返回元组 `(不含税金额, 税额)`，两者之和即为含税总额
+///
+/// # 注意
+/// `scale` 不能超过 18，否则此函数会 panic；税额超出 `i64` 的范围时（如 `tax_rate_bps` 大于 10000
+/// 且 `amount` 接近 `i64::MAX`），此函数同样会 panic
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::amount;
+///
+/// // 100.00 元，税率 13%
+/// assert_eq!(amount::tax_calculate(10000, 2, 1300), (10000, 1300));
+/// // 0.99 元，税率 6%，税额 0.0594 四舍五入为 0.06
+/// assert_eq!(amount::tax_calculate(99, 2, 600), (99, 6));
+/// ```
+pub fn tax_calculate(amount: i64, scale: u8, tax_rate_bps: u32) -> (i64, i64) {
+    assert!(scale <= MAX_SCALE, "scale must not exceed {}", MAX_SCALE);
+    let tax = mul_div_round(amount, tax_rate_bps as i128, BASIS_POINTS);
+    (amount, tax)
+}
+
+/// 从含税金额中倒算出不含税金额和税额
+///
+/// 不含税金额按 `gross * 10000 / (10000 + tax_rate_bps)` 计算并四舍五入，
+/// 税额为含税金额与不含税金额之差，保证两者之和等于 `gross`
+///
+/// # 参数
+/// * `gross` - 未缩放的含税金额
+/// * `scale` - 小数位数
+/// * `tax_rate_bps` - 税率，单位为基点（万分之一）
+///
+/// # 返回值
+/// 返回元组 `(不含税金额, 税额)`
+///
+/// # 注意
+/// `scale` 不能超过 18，否则此函数会 panic
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::amount;
+///
+/// // 含税 113.00 元，税率 13%
+/// assert_eq!(amount::tax_inclusive(11300, 2, 1300), (10000, 1300));
+/// ```
+pub fn tax_inclusive(gross: i64, scale: u8, tax_rate_bps: u32) -> (i64, i64) {
+    assert!(scale <= MAX_SCALE, "scale must not exceed {}", MAX_SCALE);
+    let net = mul_div_round(gross, BASIS_POINTS, BASIS_POINTS + tax_rate_bps as i128);
+    (net, gross - net)
+}
+
+/// 计算折扣后的金额
+///
+/// 折扣金额按 `amount * discount_rate_bps / 10000` 计算并四舍五入，
+/// 保证折后金额与折扣金额之和等于 `amount`
+///
+/// # 参数
+/// * `amount` - 未缩放的原始金额
+/// * `scale` - 小数位数
+/// * `discount_rate_bps` - 折扣率，单位为基点（万分之一），如 `1500` 表示减免 15%
+///
+/// # 返回值
+/// 返回元组 `(折后金额, 折扣金额)`；`discount_rate_bps` 大于 10000 时折后金额为负数
+///
+/// # 注意
+/// `scale` 不能超过 18，否则此函数会 panic；折扣金额或折后金额超出 `i64` 的范围时
+/// （如 `discount_rate_bps` 大于 10000 且 `amount` 接近 `i64::MAX`），此函数同样会 panic
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::amount;
+///
+/// // 200.00 元打八五折
+/// assert_eq!(amount::discount(20000, 2, 1500), (17000, 3000));
+/// ```
+pub fn discount(amount: i64, scale: u8, discount_rate_bps: u32) -> (i64, i64) {
+    assert!(scale <= MAX_SCALE, "scale must not exceed {}", MAX_SCALE);
+    let discount_given = mul_div_round(amount, discount_rate_bps as i128, BASIS_POINTS);
+    let discounted = amount.checked_sub(discount_given).expect("amount overflow");
+    (discounted, discount_given)
+}
+
+/// 100% 对应的基点数
+const BASIS_POINTS: i128 = 10_000;
+
+/// 计算 `value * numerator / denominator`，结果四舍五入（远离零方向）
+///
+/// 结果超出 `i64` 的范围时 panic
+fn mul_div_round(value: i64, numerator: i128, denominator: i128) -> i64 {
+    let product = value as i128 * numerator;
+    let quotient = product / denominator;
+    let remainder = product % denominator;
+    let rounded = if remainder.abs() * 2 >= denominator {
+        quotient + product.signum()
+    } else {
+        quotient
+    };
+    i64::try_from(rounded).expect("amount overflow")
+}
@@ -1,8 +1,10 @@
 //! 日期时间工具类模块
 //! 提供日期时间处理相关的工具函数，包括格式化、解析和偏移计算
 
-use chrono::{DateTime, Datelike, Local, NaiveDateTime, TimeZone, Timelike};
-use std::fmt::Display;
+use chrono::{
+    DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, Offset, TimeZone, Timelike,
+};
+use std::fmt::{Display, Write};
 use std::io::Error;
 use std::ops::Add;
 
@@ -52,6 +54,102 @@ where
     Some(date.format(fmt).to_string())
 }
 
+/// 将日期时间格式化为仅包含日期的字符串（`%Y-%m-%d`）
+///
+/// # 参数
+/// * `dt` - 要格式化的日期时间对象
+///
+/// # 返回值
+/// 返回形如 `2024-06-15` 的字符串
+///
+/// # 示例
+/// ```
+/// use chrono::{Local, NaiveDateTime, TimeZone};
+/// use huturs_core::datetime;
+/// let naive = NaiveDateTime::parse_from_str("2024-06-15 10:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let dt = Local.from_local_datetime(&naive).unwrap();
+/// assert_eq!(datetime::format_date_only(&dt), "2024-06-15");
+/// ```
+pub fn format_date_only<T>(dt: &DateTime<T>) -> String
+where
+    T: TimeZone,
+    <T as TimeZone>::Offset: Display,
+{
+    dt.format("%Y-%m-%d").to_string()
+}
+
+/// 将日期时间格式化为仅包含时间的字符串（`%H:%M:%S`）
+///
+/// # 参数
+/// * `dt` - 要格式化的日期时间对象
+///
+/// # 返回值
+/// 返回形如 `10:30:00` 的字符串
+///
+/// # 示例
+/// ```
+/// use chrono::{Local, NaiveDateTime, TimeZone};
+/// use huturs_core::datetime;
+/// let naive = NaiveDateTime::parse_from_str("2024-06-15 10:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let dt = Local.from_local_datetime(&naive).unwrap();
+/// assert_eq!(datetime::format_time_only(&dt), "10:30:00");
+/// ```
+pub fn format_time_only<T>(dt: &DateTime<T>) -> String
+where
+    T: TimeZone,
+    <T as TimeZone>::Offset: Display,
+{
+    dt.format("%H:%M:%S").to_string()
+}
+
+/// 将日期时间格式化为 ISO 8601 标准格式（`%Y-%m-%dT%H:%M:%S`）
+///
+/// # 参数
+/// * `dt` - 要格式化的日期时间对象
+///
+/// # 返回值
+/// 返回形如 `2024-06-15T10:30:00` 的字符串，不包含时区信息
+///
+/// # 示例
+/// ```
+/// use chrono::{Local, NaiveDateTime, TimeZone};
+/// use huturs_core::datetime;
+/// let naive = NaiveDateTime::parse_from_str("2024-06-15 10:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let dt = Local.from_local_datetime(&naive).unwrap();
+/// assert_eq!(datetime::format_datetime_standard(&dt), "2024-06-15T10:30:00");
+/// ```
+pub fn format_datetime_standard<T>(dt: &DateTime<T>) -> String
+where
+    T: TimeZone,
+    <T as TimeZone>::Offset: Display,
+{
+    dt.format("%Y-%m-%dT%H:%M:%S").to_string()
+}
+
+/// 将日期时间格式化为常用的展示格式（`%Y-%m-%d %H:%M:%S`）
+///
+/// # 参数
+/// * `dt` - 要格式化的日期时间对象
+///
+/// # 返回值
+/// 返回形如 `2024-06-15 10:30:00` 的字符串
+///
+/// # 示例
+/// ```
+/// use chrono::{Local, NaiveDateTime, TimeZone};
+/// use huturs_core::datetime;
+/// let naive = NaiveDateTime::parse_from_str("2024-06-15 10:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let dt = Local.from_local_datetime(&naive).unwrap();
+/// assert_eq!(datetime::format_datetime_display(&dt), "2024-06-15 10:30:00");
+/// ```
+pub fn format_datetime_display<T>(dt: &DateTime<T>) -> String
+where
+    T: TimeZone,
+    <T as TimeZone>::Offset: Display,
+{
+    dt.format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
 /// 将日期时间字符串解析为 DateTime 对象
 ///
 /// # 参数
@@ -89,6 +187,49 @@ where
         })
 }
 
+/// 将日期时间字符串解析为本地时间，解析失败时返回默认值
+///
+/// # 参数
+/// * `content` - 日期时间字符串
+/// * `fmt` - 日期时间格式字符串，遵循 `chrono` 的格式规范
+/// * `default` - 解析失败时返回的默认值
+///
+/// # 返回值
+/// 解析成功时返回解析结果，否则返回 `default`
+///
+/// # 示例
+/// ```
+/// use chrono::{Local, TimeZone};
+/// use huturs_core::datetime;
+/// let default = Local.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+/// let parsed = datetime::parse_or_default("2024-01-01 12:00:00", "%Y-%m-%d %H:%M:%S", default);
+/// assert_eq!(parsed, Local.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap());
+/// assert_eq!(datetime::parse_or_default("not a date", "%Y-%m-%d %H:%M:%S", default), default);
+/// ```
+pub fn parse_or_default(content: &str, fmt: &str, default: DateTime<Local>) -> DateTime<Local> {
+    parse(&content.to_string(), &fmt.to_string(), Local).unwrap_or(default)
+}
+
+/// 将日期时间字符串解析为本地时间，解析失败时返回当前时间
+///
+/// # 参数
+/// * `content` - 日期时间字符串
+/// * `fmt` - 日期时间格式字符串，遵循 `chrono` 的格式规范
+///
+/// # 返回值
+/// 解析成功时返回解析结果，否则返回 `Local::now()`
+///
+/// # 示例
+/// ```
+/// use chrono::{Local, TimeZone};
+/// use huturs_core::datetime;
+/// let parsed = datetime::parse_or_now("2024-01-01 12:00:00", "%Y-%m-%d %H:%M:%S");
+/// assert_eq!(parsed, Local.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap());
+/// ```
+pub fn parse_or_now(content: &str, fmt: &str) -> DateTime<Local> {
+    parse(&content.to_string(), &fmt.to_string(), Local).unwrap_or_else(|_| Local::now())
+}
+
 /// 将日期时间字符串从一种格式重新格式化为另一种格式
 ///
 /// # 参数
@@ -414,6 +555,68 @@ where
         .expect("Failed to calculate start of year")
 }
 
+/// 保留日期部分，将时间设置为指定的时、分、秒
+///
+/// # 参数
+/// * `dt` - 日期时间对象
+/// * `hour` - 小时（0-23）
+/// * `minute` - 分钟（0-59）
+/// * `second` - 秒（0-59）
+///
+/// # 返回值
+/// 返回同一天指定时刻的日期时间，纳秒部分清零；时间值无效，或该时刻在时区中不存在或有歧义
+/// （如夏令时切换）时返回 `None`
+///
+/// # 示例
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use huturs_core::datetime;
+/// let dt = Utc.with_ymd_and_hms(2024, 6, 15, 10, 30, 0).unwrap();
+/// let nine_am = datetime::with_time(&dt, 9, 0, 0).unwrap();
+/// assert_eq!(nine_am, Utc.with_ymd_and_hms(2024, 6, 15, 9, 0, 0).unwrap());
+/// assert!(datetime::with_time(&dt, 24, 0, 0).is_none());
+/// ```
+pub fn with_time<T: TimeZone>(
+    dt: &DateTime<T>,
+    hour: u32,
+    minute: u32,
+    second: u32,
+) -> Option<DateTime<T>> {
+    let naive = dt.date_naive().and_hms_opt(hour, minute, second)?;
+    dt.timezone().from_local_datetime(&naive).single()
+}
+
+/// 保留时间部分，将日期设置为指定的年、月、日
+///
+/// # 参数
+/// * `dt` - 日期时间对象
+/// * `year` - 年
+/// * `month` - 月（1-12）
+/// * `day` - 日（1-31）
+///
+/// # 返回值
+/// 返回指定日期同一时刻的日期时间；日期无效（如 2 月 30 日），或该时刻在时区中不存在或有歧义
+/// （如夏令时切换）时返回 `None`
+///
+/// # 示例
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use huturs_core::datetime;
+/// let dt = Utc.with_ymd_and_hms(2024, 6, 15, 10, 30, 0).unwrap();
+/// let moved = datetime::with_date(&dt, 2025, 1, 31).unwrap();
+/// assert_eq!(moved, Utc.with_ymd_and_hms(2025, 1, 31, 10, 30, 0).unwrap());
+/// assert!(datetime::with_date(&dt, 2025, 2, 29).is_none());
+/// ```
+pub fn with_date<T: TimeZone>(
+    dt: &DateTime<T>,
+    year: i32,
+    month: u32,
+    day: u32,
+) -> Option<DateTime<T>> {
+    let naive = NaiveDate::from_ymd_opt(year, month, day)?.and_time(dt.naive_local().time());
+    dt.timezone().from_local_datetime(&naive).single()
+}
+
 /// 日期时间偏移单位枚举
 ///
 /// 用于指定在进行日期时间偏移计算时使用的时间单位
@@ -552,3 +755,799 @@ pub fn after<T: TimeZone>(dt1: DateTime<T>, dt2: DateTime<T>) -> bool {
 pub fn equal<T: TimeZone, U: TimeZone>(dt1: &DateTime<T>, dt2: &DateTime<U>) -> bool {
     dt1.naive_utc().and_utc().timestamp() == dt2.naive_utc().and_utc().timestamp()
 }
+
+/// 将日期时间转换为 Unix 时间戳（秒）
+///
+/// # 参数
+/// * `dt` - 要转换的日期时间对象
+///
+/// # 返回值
+/// 返回自 `1970-01-01 00:00:00 UTC` 起经过的秒数，早于该时刻的日期时间返回负数
+///
+/// # 示例
+/// ```
+/// use chrono::{NaiveDateTime, TimeZone, Utc};
+/// use huturs_core::datetime;
+/// let naive = NaiveDateTime::parse_from_str("2000-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let dt = Utc.from_utc_datetime(&naive);
+/// assert_eq!(datetime::to_unix_timestamp(&dt), 946_684_800);
+/// ```
+pub fn to_unix_timestamp<T: TimeZone>(dt: &DateTime<T>) -> i64 {
+    dt.timestamp()
+}
+
+/// 将 Unix 时间戳（秒）转换为指定时区的日期时间
+///
+/// # 参数
+/// * `ts` - 自 `1970-01-01 00:00:00 UTC` 起经过的秒数，可以为负数
+/// * `tz` - 目标时区
+///
+/// # 返回值
+/// 返回 `Option<DateTime<T>>`，时间戳超出可表示范围时返回 `None`
+///
+/// # 示例
+/// ```
+/// use chrono::Utc;
+/// use huturs_core::datetime;
+/// let dt = datetime::from_unix_timestamp(946_684_800, Utc).unwrap();
+/// assert_eq!(datetime::format_datetime_display(&dt), "2000-01-01 00:00:00");
+/// ```
+pub fn from_unix_timestamp<T: TimeZone>(ts: i64, tz: T) -> Option<DateTime<T>> {
+    tz.timestamp_opt(ts, 0).single()
+}
+
+/// 判断给定年份是否为闰年
+///
+/// 采用公历规则：能被 4 整除且不能被 100 整除，或者能被 400 整除的年份为闰年
+///
+/// # 参数
+/// * `year` - 年份
+///
+/// # 返回值
+/// 如果是闰年，返回 `true`，否则返回 `false`
+///
+/// # 示例
+/// ```
+/// use huturs_core::datetime;
+/// assert!(datetime::is_leap_year(2024));
+/// assert!(datetime::is_leap_year(2000));
+/// assert!(!datetime::is_leap_year(1900));
+/// assert!(!datetime::is_leap_year(2023));
+/// ```
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// 获取给定年份中指定月份的天数
+///
+/// # 参数
+/// * `year` - 年份
+/// * `month` - 月份，取值范围为 `1..=12`
+///
+/// # 返回值
+/// 返回该月的天数，月份无效时返回 `None`
+///
+/// # 示例
+/// ```
+/// use huturs_core::datetime;
+/// assert_eq!(datetime::days_in_month(2024, 2), Some(29));
+/// assert_eq!(datetime::days_in_month(2023, 2), Some(28));
+/// assert_eq!(datetime::days_in_month(2023, 4), Some(30));
+/// assert_eq!(datetime::days_in_month(2023, 13), None);
+/// ```
+pub fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+        4 | 6 | 9 | 11 => Some(30),
+        2 if is_leap_year(year) => Some(29),
+        2 => Some(28),
+        _ => None,
+    }
+}
+
+/// 获取给定年份的天数
+///
+/// # 参数
+/// * `year` - 年份
+///
+/// # 返回值
+/// 闰年返回 `366`，平年返回 `365`
+///
+/// # 示例
+/// ```
+/// use huturs_core::datetime;
+/// assert_eq!(datetime::days_in_year(2024), 366);
+/// assert_eq!(datetime::days_in_year(2023), 365);
+/// ```
+pub fn days_in_year(year: i32) -> u32 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+/// 获取给定年月的最后一天的日期号
+///
+/// # 参数
+/// * `year` - 年份，需在 `chrono` 支持的范围内
+/// * `month` - 月份，取值范围为 `1..=12`
+///
+/// # 返回值
+/// 返回 `Some(28..=31)`，月份无效或年份超出范围时返回 `None`
+///
+/// # 示例
+/// ```
+/// use huturs_core::datetime;
+/// assert_eq!(datetime::last_day_of_month(2024, 2), Some(29));
+/// assert_eq!(datetime::last_day_of_month(2024, 6), Some(30));
+/// assert_eq!(datetime::last_day_of_month(2024, 0), None);
+/// ```
+pub fn last_day_of_month(year: i32, month: u32) -> Option<u32> {
+    NaiveDate::from_ymd_opt(year, month, 1)?;
+    days_in_month(year, month)
+}
+
+/// 判断给定日期时间是否为所在月的最后一天
+///
+/// # 参数
+/// * `dt` - 日期时间对象
+///
+/// # 返回值
+/// 如果是所在月的最后一天，返回 `true`，否则返回 `false`
+///
+/// # 示例
+/// ```
+/// use chrono::{Local, NaiveDateTime, TimeZone};
+/// use huturs_core::datetime;
+/// let naive = NaiveDateTime::parse_from_str("2024-02-29 10:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let dt = Local.from_local_datetime(&naive).unwrap();
+/// assert!(datetime::is_last_day_of_month(&dt));
+/// ```
+pub fn is_last_day_of_month<T: TimeZone>(dt: &DateTime<T>) -> bool {
+    last_day_of_month(dt.year(), dt.month()) == Some(dt.day())
+}
+
+/// 判断给定日期时间是否为所在月的第一天
+///
+/// # 参数
+/// * `dt` - 日期时间对象
+///
+/// # 返回值
+/// 如果是所在月的第一天，返回 `true`，否则返回 `false`
+///
+/// # 示例
+/// ```
+/// use chrono::{Local, NaiveDateTime, TimeZone};
+/// use huturs_core::datetime;
+/// let naive = NaiveDateTime::parse_from_str("2024-03-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let dt = Local.from_local_datetime(&naive).unwrap();
+/// assert!(datetime::is_first_day_of_month(&dt));
+/// ```
+pub fn is_first_day_of_month<T: TimeZone>(dt: &DateTime<T>) -> bool {
+    dt.day() == 1
+}
+
+/// 获取日期时间相对 UTC 的时区偏移量（秒）
+///
+/// # 参数
+/// * `dt` - 日期时间对象
+///
+/// # 返回值
+/// 返回相对 UTC 的偏移秒数，东时区为正数，西时区为负数
+///
+/// # 示例
+/// ```
+/// use chrono::{FixedOffset, TimeZone};
+/// use huturs_core::datetime;
+/// let east8 = FixedOffset::east_opt(8 * 3600).unwrap();
+/// let dt = east8.with_ymd_and_hms(2024, 6, 15, 10, 30, 0).unwrap();
+/// assert_eq!(datetime::timezone_offset_seconds(&dt), 28800);
+/// ```
+pub fn timezone_offset_seconds<T: TimeZone>(dt: &DateTime<T>) -> i32 {
+    dt.offset().fix().local_minus_utc()
+}
+
+/// 获取日期时间相对 UTC 的时区偏移量（小时和分钟）
+///
+/// # 参数
+/// * `dt` - 日期时间对象
+///
+/// # 返回值
+/// 返回 `(小时, 分钟)`，小时带符号，分钟始终为非负数
+///
+/// # 注意
+/// 对于 `-00:30` 这类不足一小时的西时区偏移，小时部分为 `0`，符号信息会丢失，
+/// 此时请使用 [`timezone_offset_seconds`]
+///
+/// # 示例
+/// ```
+/// use chrono::{FixedOffset, TimeZone};
+/// use huturs_core::datetime;
+/// let west = FixedOffset::west_opt(9 * 3600 + 30 * 60).unwrap();
+/// let dt = west.with_ymd_and_hms(2024, 6, 15, 10, 30, 0).unwrap();
+/// assert_eq!(datetime::timezone_offset_hours_minutes(&dt), (-9, 30));
+/// ```
+pub fn timezone_offset_hours_minutes<T: TimeZone>(dt: &DateTime<T>) -> (i32, u32) {
+    let seconds = timezone_offset_seconds(dt);
+    let hours = seconds / 3600;
+    let minutes = (seconds.unsigned_abs() % 3600) / 60;
+    (hours, minutes)
+}
+
+/// 获取本地时区的名称
+///
+/// # 参数
+/// * `dt` - 本地日期时间对象
+///
+/// # 返回值
+/// 返回本地时区的标识字符串
+///
+/// # 注意
+/// `chrono` 的 `Local` 时区不携带时区缩写（如 `CST`），因此返回的是 `+08:00` 形式的偏移量
+///
+/// # 示例
+/// ```
+/// use chrono::Local;
+/// use huturs_core::datetime;
+/// let name = datetime::timezone_name(&Local::now());
+/// assert!(!name.is_empty());
+/// ```
+pub fn timezone_name(dt: &DateTime<Local>) -> String {
+    dt.format("%Z").to_string()
+}
+
+/// 获取日期时间的 UTC 偏移量（秒）
+///
+/// 与 [`timezone_offset_seconds`] 相同，东时区为正数，西时区为负数；
+/// 对于 `Local` 时间，结果会随夏令时变化
+///
+/// # 参数
+/// * `dt` - 日期时间对象
+///
+/// # 返回值
+/// 返回相对 UTC 的偏移秒数
+///
+/// # 示例
+/// ```
+/// use chrono::{FixedOffset, TimeZone};
+/// use huturs_core::datetime;
+/// let west5 = FixedOffset::west_opt(5 * 3600).unwrap();
+/// let dt = west5.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap();
+/// assert_eq!(datetime::utc_offset_seconds(&dt), -18000);
+/// ```
+pub fn utc_offset_seconds<T: TimeZone>(dt: &DateTime<T>) -> i32 {
+    timezone_offset_seconds(dt)
+}
+
+/// 判断日期时间是否处于夏令时
+///
+/// 取所在时区在当年每月 1 日的 UTC 偏移量中的最小值作为标准时间偏移量，
+/// 当前偏移量大于该值时认为处于夏令时
+///
+/// # 参数
+/// * `dt` - 日期时间对象，通常为 `DateTime<Local>`
+///
+/// # 返回值
+/// 如果处于夏令时，返回 `true`；否则返回 `false`。固定偏移量的时区总是返回 `false`
+///
+/// # 注意
+/// 这是一种启发式判断，结果依赖操作系统的时区数据。对于当年调整过标准时间的时区、
+/// 夏令时不足一个月或"冬令时"之类的特殊规则，判断结果可能不正确
+///
+/// # 示例
+/// ```
+/// use chrono::{FixedOffset, Local, TimeZone};
+/// use huturs_core::datetime;
+/// let east8 = FixedOffset::east_opt(8 * 3600).unwrap();
+/// let dt = east8.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+/// assert!(!datetime::is_dst_observed(&dt));
+///
+/// let in_dst = datetime::is_dst_observed(&Local::now());
+/// println!("当前是否为夏令时: {}", in_dst);
+/// ```
+pub fn is_dst_observed<T: TimeZone>(dt: &DateTime<T>) -> bool {
+    let timezone = dt.timezone();
+    let standard_offset = (1..=12)
+        .filter_map(|month| NaiveDate::from_ymd_opt(dt.year(), month, 1))
+        .map(|date| {
+            timezone
+                .offset_from_utc_datetime(&date.and_time(chrono::NaiveTime::MIN))
+                .fix()
+                .local_minus_utc()
+        })
+        .min();
+    standard_offset.is_some_and(|standard| timezone_offset_seconds(dt) > standard)
+}
+
+/// 单个语言环境下的星期、月份和上下午名称
+struct LocaleNames {
+    /// 语言代码
+    code: &'static str,
+    /// 星期名称，从星期一开始
+    weekdays: [&'static str; 7],
+    /// 月份名称，从一月开始
+    months: [&'static str; 12],
+    /// 上午
+    am: &'static str,
+    /// 下午
+    pm: &'static str,
+}
+
+/// 内置的语言环境数据，第一项（英语）同时作为未知语言环境的回退
+static LOCALE_TABLE: [LocaleNames; 3] = [
+    LocaleNames {
+        code: "en",
+        weekdays: [
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+            "Sunday",
+        ],
+        months: [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        am: "AM",
+        pm: "PM",
+    },
+    LocaleNames {
+        code: "zh",
+        weekdays: [
+            "星期一",
+            "星期二",
+            "星期三",
+            "星期四",
+            "星期五",
+            "星期六",
+            "星期日",
+        ],
+        months: [
+            "一月",
+            "二月",
+            "三月",
+            "四月",
+            "五月",
+            "六月",
+            "七月",
+            "八月",
+            "九月",
+            "十月",
+            "十一月",
+            "十二月",
+        ],
+        am: "上午",
+        pm: "下午",
+    },
+    LocaleNames {
+        code: "ja",
+        weekdays: [
+            "月曜日",
+            "火曜日",
+            "水曜日",
+            "木曜日",
+            "金曜日",
+            "土曜日",
+            "日曜日",
+        ],
+        months: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        am: "午前",
+        pm: "午後",
+    },
+];
+
+/// 按指定语言环境格式化日期时间
+///
+/// 在 [`format`] 的基础上，将 `%A`（星期全称）、`%B`（月份全称）和 `%p`（上午/下午）
+/// 替换为对应语言环境的名称，其余格式说明符保持 `chrono` 的默认行为
+///
+/// # 参数
+/// * `dt` - 要格式化的日期时间对象
+/// * `fmt` - 日期时间格式字符串，遵循 `chrono` 的格式规范
+/// * `locale` - 语言环境，目前支持 `en`、`zh`、`ja`，也接受 `zh-CN`、`ja_JP` 这类带地区的写法；
+///   未知的语言环境回退为英语
+///
+/// # 返回值
+/// 返回格式化后的字符串，如果格式字符串无效则返回 `None`
+///
+/// # 示例
+/// ```
+/// use chrono::{FixedOffset, TimeZone};
+/// use huturs_core::datetime;
+/// let east8 = FixedOffset::east_opt(8 * 3600).unwrap();
+/// let dt = east8.with_ymd_and_hms(2024, 6, 15, 14, 30, 0).unwrap();
+/// assert_eq!(
+///     datetime::format_with_locale(&dt, "%Y年%-m月%-d日 %A %p", "zh").unwrap(),
+///     "2024年6月15日 星期六 下午"
+/// );
+/// assert_eq!(
+///     datetime::format_with_locale(&dt, "%A, %B %-d", "fr").unwrap(),
+///     "Saturday, June 15"
+/// );
+/// ```
+pub fn format_with_locale<T>(dt: &DateTime<T>, fmt: &str, locale: &str) -> Option<String>
+where
+    T: TimeZone,
+    <T as TimeZone>::Offset: Display,
+{
+    let language = locale.split(['-', '_']).next().unwrap_or_default();
+    let names = LOCALE_TABLE
+        .iter()
+        .find(|names| names.code.eq_ignore_ascii_case(language))
+        .unwrap_or(&LOCALE_TABLE[0]);
+
+    let mut localized_fmt = String::with_capacity(fmt.len());
+    let mut chars = fmt.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            localized_fmt.push(c);
+            continue;
+        }
+        let replacement = match chars.next() {
+            Some('A') => names.weekdays[dt.weekday().num_days_from_monday() as usize],
+            Some('B') => names.months[dt.month0() as usize],
+            Some('p') if dt.hour() < 12 => names.am,
+            Some('p') => names.pm,
+            Some(other) => {
+                localized_fmt.push('%');
+                localized_fmt.push(other);
+                continue;
+            }
+            None => {
+                localized_fmt.push('%');
+                continue;
+            }
+        };
+        localized_fmt.push_str(&replacement.replace('%', "%%"));
+    }
+
+    let mut result = String::new();
+    write!(result, "{}", dt.format(&localized_fmt)).ok()?;
+    Some(result)
+}
+
+/// 计算距离目标时间的剩余时长
+///
+/// # 参数
+/// * `target` - 目标日期时间
+/// * `now` - 当前日期时间
+///
+/// # 返回值
+/// 如果 `target` 晚于 `now`，返回 `Some(剩余时长)`；否则返回 `None`
+///
+/// # 注意
+/// 基于 [`between`] 计算，精度为秒，不足一秒的部分会被舍去
+///
+/// # 示例
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use huturs_core::datetime;
+/// let now = Utc.with_ymd_and_hms(2024, 6, 15, 10, 0, 0).unwrap();
+/// let deadline = Utc.with_ymd_and_hms(2024, 6, 15, 12, 30, 0).unwrap();
+/// assert_eq!(datetime::countdown_to(&deadline, &now), Some(chrono::Duration::minutes(150)));
+/// assert_eq!(datetime::countdown_to(&now, &deadline), None);
+/// ```
+pub fn countdown_to<T: TimeZone>(target: &DateTime<T>, now: &DateTime<T>) -> Option<Duration> {
+    let seconds = between(now, target);
+    (seconds > 0).then(|| Duration::seconds(seconds))
+}
+
+/// 计算自过去某一时间起已经过的时长
+///
+/// # 参数
+/// * `past` - 过去的日期时间
+/// * `now` - 当前日期时间
+///
+/// # 返回值
+/// 如果 `past` 不晚于 `now`，返回 `Some(已经过的时长)`；否则返回 `None`
+///
+/// # 注意
+/// 基于 [`between`] 计算，精度为秒，不足一秒的部分会被舍去
+///
+/// # 示例
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use huturs_core::datetime;
+/// let login = Utc.with_ymd_and_hms(2024, 6, 15, 10, 0, 0).unwrap();
+/// let now = Utc.with_ymd_and_hms(2024, 6, 15, 10, 0, 45).unwrap();
+/// assert_eq!(datetime::elapsed_since(&login, &now), Some(chrono::Duration::seconds(45)));
+/// assert_eq!(datetime::elapsed_since(&now, &login), None);
+/// ```
+pub fn elapsed_since<T: TimeZone>(past: &DateTime<T>, now: &DateTime<T>) -> Option<Duration> {
+    let seconds = between(past, now);
+    (seconds >= 0).then(|| Duration::seconds(seconds))
+}
+
+/// 判断两个日期时间是否在同一天
+///
+/// # 参数
+/// * `dt1` - 第一个日期时间
+/// * `dt2` - 第二个日期时间
+///
+/// # 返回值
+/// 如果两者的年、月、日都相同，返回 `true`；否则返回 `false`
+///
+/// # 示例
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use huturs_core::datetime;
+/// let morning = Utc.with_ymd_and_hms(2024, 6, 15, 8, 0, 0).unwrap();
+/// let evening = Utc.with_ymd_and_hms(2024, 6, 15, 22, 0, 0).unwrap();
+/// assert!(datetime::is_same_day(&morning, &evening));
+/// ```
+pub fn is_same_day<T: TimeZone>(dt1: &DateTime<T>, dt2: &DateTime<T>) -> bool {
+    dt1.date_naive() == dt2.date_naive()
+}
+
+/// 判断两个日期时间是否在同一个月
+///
+/// # 参数
+/// * `dt1` - 第一个日期时间
+/// * `dt2` - 第二个日期时间
+///
+/// # 返回值
+/// 如果两者的年、月都相同，返回 `true`；否则返回 `false`
+///
+/// # 示例
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use huturs_core::datetime;
+/// let first = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+/// let last = Utc.with_ymd_and_hms(2024, 6, 30, 23, 59, 59).unwrap();
+/// assert!(datetime::is_same_month(&first, &last));
+/// ```
+pub fn is_same_month<T: TimeZone>(dt1: &DateTime<T>, dt2: &DateTime<T>) -> bool {
+    dt1.year() == dt2.year() && dt1.month() == dt2.month()
+}
+
+/// 判断两个日期时间是否在同一年
+///
+/// # 参数
+/// * `dt1` - 第一个日期时间
+/// * `dt2` - 第二个日期时间
+///
+/// # 返回值
+/// 如果两者的年份相同，返回 `true`；否则返回 `false`
+///
+/// # 示例
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use huturs_core::datetime;
+/// let new_year = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+/// let new_year_eve = Utc.with_ymd_and_hms(2023, 12, 31, 23, 59, 59).unwrap();
+/// assert!(!datetime::is_same_year(&new_year, &new_year_eve));
+/// ```
+pub fn is_same_year<T: TimeZone>(dt1: &DateTime<T>, dt2: &DateTime<T>) -> bool {
+    dt1.year() == dt2.year()
+}
+
+/// 将时长格式化为易读的英文描述
+///
+/// 依次输出天、小时、分钟、秒中不为零的部分，单位按数量使用单复数形式，
+/// 例如 `"3 minutes 42 seconds"`、`"1 day 2 hours"`
+///
+/// # 参数
+/// * `duration` - 要格式化的时长
+///
+/// # 返回值
+/// 返回格式化后的字符串；时长为零时返回 `"0 seconds"`，时长为负时添加 `"negative "` 前缀
+///
+/// # 注意
+/// 精度为秒，不足一秒的部分会被舍去
+///
+/// # 示例
+/// ```
+/// use chrono::Duration;
+/// use huturs_core::datetime;
+/// assert_eq!(datetime::format_duration(&Duration::seconds(222)), "3 minutes 42 seconds");
+/// assert_eq!(datetime::format_duration(&Duration::hours(26)), "1 day 2 hours");
+/// assert_eq!(datetime::format_duration(&Duration::seconds(-1)), "negative 1 second");
+/// assert_eq!(datetime::format_duration(&Duration::zero()), "0 seconds");
+/// ```
+pub fn format_duration(duration: &Duration) -> String {
+    let seconds = duration.num_seconds();
+    let total = seconds.unsigned_abs();
+    let parts: Vec<String> = [
+        (total / 86_400, "day"),
+        (total % 86_400 / 3_600, "hour"),
+        (total % 3_600 / 60, "minute"),
+        (total % 60, "second"),
+    ]
+    .iter()
+    .filter(|(value, _)| *value > 0)
+    .map(|(value, name)| {
+        if *value == 1 {
+            format!("{} {}", value, name)
+        } else {
+            format!("{} {}s", value, name)
+        }
+    })
+    .collect();
+
+    if parts.is_empty() {
+        return String::from("0 seconds");
+    }
+    let text = parts.join(" ");
+    if seconds < 0 {
+        format!("negative {}", text)
+    } else {
+        text
+    }
+}
+
+/// 将两个日期时间之间的间隔格式化为易读的描述
+///
+/// # 参数
+/// * `start` - 起始日期时间
+/// * `end` - 结束日期时间
+///
+/// # 返回值
+/// 返回 [`format_duration`] 格式的描述；如果 `end` 早于 `start`，添加 `"negative "` 前缀
+///
+/// # 注意
+/// 基于 [`between`] 计算，精度为秒
+///
+/// # 示例
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use huturs_core::datetime;
+/// let start = Utc.with_ymd_and_hms(2024, 6, 15, 10, 0, 0).unwrap();
+/// let end = Utc.with_ymd_and_hms(2024, 6, 15, 10, 3, 42).unwrap();
+/// assert_eq!(datetime::format_elapsed(&start, &end), "3 minutes 42 seconds");
+/// assert_eq!(datetime::format_elapsed(&end, &start), "negative 3 minutes 42 seconds");
+/// ```
+pub fn format_elapsed<T: TimeZone>(start: &DateTime<T>, end: &DateTime<T>) -> String {
+    format_duration(&Duration::seconds(between(start, end)))
+}
+
+/// 将从指定时间到当前时间的间隔格式化为易读的描述
+///
+/// # 参数
+/// * `start` - 起始日期时间
+///
+/// # 返回值
+/// 返回 [`format_elapsed`] 格式的描述，结束时间为 `Local::now()`
+///
+/// # 示例
+/// ```
+/// use chrono::{Duration, Local};
+/// use huturs_core::datetime;
+/// let start = Local::now() - Duration::hours(2);
+/// assert!(datetime::format_elapsed_since(&start).starts_with("2 hours"));
+/// ```
+pub fn format_elapsed_since<T: TimeZone>(start: &DateTime<T>) -> String {
+    let now = Local::now().with_timezone(&start.timezone());
+    format_elapsed(start, &now)
+}
+
+/// 依次生成时间范围内每个月的第一天
+///
+/// # 参数
+/// * `start` - 起始日期时间
+/// * `end` - 结束日期时间
+///
+/// # 返回值
+/// 返回从 `start` 所在月到 `end` 所在月（包含）每月 1 日 00:00:00 的迭代器，时区与 `start` 相同；
+/// `start` 所在月晚于 `end` 所在月时不生成任何值
+///
+/// # 注意
+/// 如果某个月 1 日的 00:00:00 在该时区中不存在（如恰逢夏令时切换），该月会被跳过
+///
+/// # 示例
+/// ```
+/// use chrono::{Datelike, TimeZone, Utc};
+/// use huturs_core::datetime;
+/// let start = Utc.with_ymd_and_hms(2023, 11, 15, 10, 0, 0).unwrap();
+/// let end = Utc.with_ymd_and_hms(2024, 2, 3, 8, 0, 0).unwrap();
+/// let months: Vec<(i32, u32)> = datetime::iter_months_in_range(&start, &end)
+///     .map(|dt| (dt.year(), dt.month()))
+///     .collect();
+/// assert_eq!(months, vec![(2023, 11), (2023, 12), (2024, 1), (2024, 2)]);
+/// ```
+pub fn iter_months_in_range<T: TimeZone>(
+    start: &DateTime<T>,
+    end: &DateTime<T>,
+) -> impl Iterator<Item = DateTime<T>> {
+    iter_period_starts_in_range(start, end, 1)
+}
+
+/// 依次生成时间范围内每个季度的第一天
+///
+/// # 参数
+/// * `start` - 起始日期时间
+/// * `end` - 结束日期时间
+///
+/// # 返回值
+/// 返回从 `start` 所在季度到 `end` 所在季度（包含）每季度首日（1、4、7、10 月 1 日）00:00:00 的迭代器，
+/// 时区与 `start` 相同；`start` 所在季度晚于 `end` 所在季度时不生成任何值
+///
+/// # 注意
+/// 如果某个季度首日的 00:00:00 在该时区中不存在（如恰逢夏令时切换），该季度会被跳过
+///
+/// # 示例
+/// ```
+/// use chrono::{Datelike, TimeZone, Utc};
+/// use huturs_core::datetime;
+/// let start = Utc.with_ymd_and_hms(2023, 11, 15, 10, 0, 0).unwrap();
+/// let end = Utc.with_ymd_and_hms(2024, 5, 3, 8, 0, 0).unwrap();
+/// let quarters: Vec<(i32, u32)> = datetime::iter_quarters_in_range(&start, &end)
+///     .map(|dt| (dt.year(), dt.month()))
+///     .collect();
+/// assert_eq!(quarters, vec![(2023, 10), (2024, 1), (2024, 4)]);
+/// ```
+pub fn iter_quarters_in_range<T: TimeZone>(
+    start: &DateTime<T>,
+    end: &DateTime<T>,
+) -> impl Iterator<Item = DateTime<T>> {
+    iter_period_starts_in_range(start, end, 3)
+}
+
+/// 依次生成时间范围内每年的第一天
+///
+/// # 参数
+/// * `start` - 起始日期时间
+/// * `end` - 结束日期时间
+///
+/// # 返回值
+/// 返回从 `start` 所在年到 `end` 所在年（包含）每年 1 月 1 日 00:00:00 的迭代器，时区与 `start` 相同；
+/// `start` 所在年晚于 `end` 所在年时不生成任何值
+///
+/// # 注意
+/// 如果某年 1 月 1 日的 00:00:00 在该时区中不存在（如恰逢夏令时切换），该年会被跳过
+///
+/// # 示例
+/// ```
+/// use chrono::{Datelike, TimeZone, Utc};
+/// use huturs_core::datetime;
+/// let start = Utc.with_ymd_and_hms(2022, 6, 15, 10, 0, 0).unwrap();
+/// let end = Utc.with_ymd_and_hms(2024, 2, 3, 8, 0, 0).unwrap();
+/// let years: Vec<i32> = datetime::iter_years_in_range(&start, &end)
+///     .map(|dt| dt.year())
+///     .collect();
+/// assert_eq!(years, vec![2022, 2023, 2024]);
+/// ```
+pub fn iter_years_in_range<T: TimeZone>(
+    start: &DateTime<T>,
+    end: &DateTime<T>,
+) -> impl Iterator<Item = DateTime<T>> {
+    iter_period_starts_in_range(start, end, 12)
+}
+
+/// 按 `months_per_period` 个月为一个周期，依次生成范围内每个周期首日的 00:00:00
+///
+/// 月份以 `年 * 12 + (月 - 1)` 的形式编号，周期从每年 1 月起对齐
+fn iter_period_starts_in_range<T: TimeZone>(
+    start: &DateTime<T>,
+    end: &DateTime<T>,
+    months_per_period: i64,
+) -> impl Iterator<Item = DateTime<T>> {
+    let period_index = |dt: &DateTime<T>| {
+        let month_index = i64::from(dt.year()) * 12 + i64::from(dt.month0());
+        month_index.div_euclid(months_per_period)
+    };
+    let first = period_index(start);
+    let last = period_index(&end.with_timezone(&start.timezone()));
+    let timezone = start.timezone();
+    (first..=last).filter_map(move |period| {
+        let month_index = period * months_per_period;
+        let year = i32::try_from(month_index.div_euclid(12)).ok()?;
+        let month = month_index.rem_euclid(12) as u32 + 1;
+        let naive = NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?;
+        timezone.from_local_datetime(&naive).earliest()
+    })
+}
@@ -2,9 +2,21 @@
 //! 提供文件读写相关的工具函数
 
 use crate::is_blank;
+#[cfg(feature = "file-json")]
+use serde::{de::DeserializeOwned, Serialize};
+#[cfg(feature = "file-watch")]
+use std::collections::HashMap;
 use std::fs;
-use std::io::{Error, Write};
-use std::path::PathBuf;
+use std::io::{BufRead, BufReader, Error, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "file-watch")]
+use std::sync::{atomic::AtomicBool, Arc};
+#[cfg(feature = "file-watch")]
+use std::thread::JoinHandle;
+#[cfg(feature = "file-watch")]
+use std::time::Duration;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// 读取文件内容
 ///
@@ -60,6 +72,66 @@ pub fn write_file(path: &str, contents: &str) -> Result<(), Error> {
     fs::write(&path, contents)
 }
 
+/// 确保文件所在的父目录存在
+///
+/// 写入嵌套路径下的文件前调用，父目录及其所有上级目录不存在时会被一并创建
+///
+/// # 参数
+/// * `path` - 文件路径
+///
+/// # 返回值
+/// 返回 `Result<(), Error>`，父目录已存在或创建成功时返回 `Ok(())`，失败时包含错误信息
+///
+/// # 注意
+/// 路径没有父目录（如 `"output.txt"` 或 `"/"`）时无需创建，直接返回 `Ok(())`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::file;
+///
+/// let dir = std::env::temp_dir().join("huturs_ensure_parent");
+/// let path = dir.join("a").join("b").join("output.txt");
+/// file::ensure_parent_dir_exists(path.to_str().unwrap()).unwrap();
+/// assert!(dir.join("a").join("b").is_dir());
+/// # let _ = std::fs::remove_dir_all(&dir);
+/// ```
+pub fn ensure_parent_dir_exists(path: &str) -> Result<(), Error> {
+    if is_blank(path) {
+        return Err(Error::other(format!("File {} is blank", path)));
+    }
+    match Path::new(path).parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => fs::create_dir_all(parent),
+        _ => Ok(()),
+    }
+}
+
+/// 写入内容到文件，父目录不存在时自动创建
+///
+/// # 参数
+/// * `path` - 文件路径
+/// * `contents` - 要写入的内容
+///
+/// # 返回值
+/// 返回 `Result<(), Error>`，成功时返回 `Ok(())`，失败时包含错误信息
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::file;
+///
+/// let dir = std::env::temp_dir().join("huturs_write_creating_dirs");
+/// let path = dir.join("a").join("b").join("output.txt");
+/// let path = path.to_str().unwrap();
+/// file::write_file_creating_dirs(path, "Hello, world!").unwrap();
+/// assert_eq!(file::read_file(path).unwrap(), "Hello, world!");
+/// # let _ = std::fs::remove_dir_all(&dir);
+/// ```
+pub fn write_file_creating_dirs(path: &str, contents: &str) -> Result<(), Error> {
+    ensure_parent_dir_exists(path)?;
+    write_file(path, contents)
+}
+
 /// 追加内容到文件末尾
 ///
 /// # 参数
@@ -162,4 +234,987 @@ pub fn read_dirs(path: &str) -> Result<Vec<PathBuf>, Error> {
     fs::read_dir(path)?
         .map(|entry| entry.map(|e| e.path()))
         .collect()
-}
\ No newline at end of file
+}
+
+/// 读取 JSON 文件并反序列化为指定类型
+///
+/// # 参数
+/// * `path` - 文件路径
+///
+/// # 返回值
+/// 返回 `Result<T, Error>`，成功时包含反序列化后的值；
+/// 文件内容不是合法 JSON 或与目标类型不匹配时，返回 `ErrorKind::InvalidData` 错误
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::file;
+/// use std::collections::HashMap;
+///
+/// match file::read_json::<HashMap<String, i32>>("config.json") {
+///     Ok(config) => println!("配置项数量: {}", config.len()),
+///     Err(e) => eprintln!("读取失败: {}", e),
+/// }
+/// ```
+#[cfg(feature = "file-json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "file-json")))]
+pub fn read_json<T: DeserializeOwned>(path: &str) -> Result<T, Error> {
+    if is_blank(path) {
+        return Err(Error::other(format!("File {} is blank", path)));
+    }
+    let contents = fs::read_to_string(path)?;
+    serde_json::from_str(&contents).map_err(|e| Error::new(std::io::ErrorKind::InvalidData, e))
+}
+
+/// 将值序列化为 JSON 并写入文件
+///
+/// # 参数
+/// * `path` - 文件路径
+/// * `value` - 要序列化的值
+/// * `pretty` - 为 `true` 时输出带缩进的格式化 JSON，否则输出紧凑格式
+///
+/// # 返回值
+/// 返回 `Result<(), Error>`，成功时返回 `Ok(())`；序列化失败时返回 `ErrorKind::InvalidData` 错误
+///
+/// # 注意
+/// 如果文件已存在，其内容将被覆盖
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::file;
+/// use std::collections::HashMap;
+///
+/// let path = std::env::temp_dir().join("huturs_config.json");
+/// let path = path.to_str().unwrap();
+/// let mut config = HashMap::new();
+/// config.insert("port", 8080);
+/// match file::write_json(path, &config, true) {
+///     Ok(()) => println!("写入成功"),
+///     Err(e) => eprintln!("写入失败: {}", e),
+/// }
+/// # let _ = file::delete_file(path);
+/// ```
+#[cfg(feature = "file-json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "file-json")))]
+pub fn write_json<T: Serialize>(path: &str, value: &T, pretty: bool) -> Result<(), Error> {
+    let contents = if pretty {
+        serde_json::to_string_pretty(value)
+    } else {
+        serde_json::to_string(value)
+    }
+    .map_err(|e| Error::new(std::io::ErrorKind::InvalidData, e))?;
+    write_file(path, &contents)
+}
+
+/// 将表头和数据行写入 CSV 文件
+///
+/// # 参数
+/// * `path` - 文件路径
+/// * `headers` - 表头字段
+/// * `rows` - 数据行，每行的列数以表头为准，不足时补空字符串，多余时截断
+///
+/// # 返回值
+/// 返回 `Result<(), Error>`，成功时返回 `Ok(())`，失败时包含错误信息
+///
+/// # 注意
+/// 按照 RFC 4180 输出：行以 `\r\n` 结尾，包含逗号、双引号或换行符的字段会用双引号包裹，
+/// 字段内的双引号转义为两个双引号。如果文件已存在，其内容将被覆盖
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::file;
+///
+/// let path = std::env::temp_dir().join("huturs_users.csv");
+/// let path = path.to_str().unwrap();
+/// let rows = vec![vec!["1".to_string(), "Tom, Jr.".to_string()]];
+/// match file::write_csv_rows(path, &["id", "name"], &rows) {
+///     Ok(()) => println!("写入成功"),
+///     Err(e) => eprintln!("写入失败: {}", e),
+/// }
+/// # let _ = file::delete_file(path);
+/// ```
+#[cfg(feature = "file-csv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "file-csv")))]
+pub fn write_csv_rows(path: &str, headers: &[&str], rows: &[Vec<String>]) -> Result<(), Error> {
+    let mut contents = String::new();
+    push_csv_record(&mut contents, headers.iter().copied());
+    for row in rows {
+        let fields = (0..headers.len()).map(|i| row.get(i).map_or("", |field| field.as_str()));
+        push_csv_record(&mut contents, fields);
+    }
+    write_file(path, &contents)
+}
+
+/// 读取 CSV 文件的表头和数据行
+///
+/// # 参数
+/// * `path` - 文件路径
+///
+/// # 返回值
+/// 返回 `Result<(Vec<String>, Vec<Vec<String>>), Error>`，成功时包含表头和数据行，空文件返回两个空向量；
+/// 存在未闭合的引号等格式错误时，返回 `ErrorKind::InvalidData` 错误
+///
+/// # 注意
+/// 按照 RFC 4180 解析，支持带引号的字段以及字段内的逗号、换行符和转义双引号，行尾可以是 `\n` 或 `\r\n`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::file;
+///
+/// match file::read_csv_rows("users.csv") {
+///     Ok((headers, rows)) => println!("{:?} 共 {} 行", headers, rows.len()),
+///     Err(e) => eprintln!("读取失败: {}", e),
+/// }
+/// ```
+#[cfg(feature = "file-csv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "file-csv")))]
+pub fn read_csv_rows(path: &str) -> Result<(Vec<String>, Vec<Vec<String>>), Error> {
+    if is_blank(path) {
+        return Err(Error::other(format!("File {} is blank", path)));
+    }
+    let contents = fs::read_to_string(path)?;
+    let mut records = parse_csv(&contents)?.into_iter();
+    let headers = records.next().unwrap_or_default();
+    Ok((headers, records.collect()))
+}
+
+/// 将一行记录按 CSV 格式追加到缓冲区
+#[cfg(feature = "file-csv")]
+fn push_csv_record<'a>(buffer: &mut String, fields: impl Iterator<Item = &'a str>) {
+    for (index, field) in fields.enumerate() {
+        if index > 0 {
+            buffer.push(',');
+        }
+        if field.contains([',', '"', '\n', '\r']) {
+            buffer.push('"');
+            buffer.push_str(&field.replace('"', "\"\""));
+            buffer.push('"');
+        } else {
+            buffer.push_str(field);
+        }
+    }
+    buffer.push_str("\r\n");
+}
+
+/// 将 CSV 文本解析为记录列表
+#[cfg(feature = "file-csv")]
+fn parse_csv(contents: &str) -> Result<Vec<Vec<String>>, Error> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = contents.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(Error::new(
+            std::io::ErrorKind::InvalidData,
+            "CSV content has an unterminated quoted field",
+        ));
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// 读取文件内容并自动识别文本编码
+///
+/// 依次尝试以下编码：
+/// 1. UTF-8（如有 BOM `EF BB BF` 会被去除）
+/// 2. 带 BOM 的 UTF-16：`FF FE` 为小端序，`FE FF` 为大端序
+/// 3. ISO-8859-1：每个字节一一映射为 `U+0000` ~ `U+00FF` 的字符，任何字节序列都能解码
+///
+/// # 参数
+/// * `path` - 文件路径
+///
+/// # 返回值
+/// 返回 `Result<(String, &'static str), Error>`，成功时包含文件内容和识别出的编码名称
+/// （`"UTF-8"`、`"UTF-16LE"`、`"UTF-16BE"` 或 `"ISO-8859-1"`），失败时包含错误信息
+///
+/// # 注意
+/// 无法区分 GBK 等多字节编码，此类文件会被当作 ISO-8859-1 解码；
+/// 带 BOM 但内容不是合法 UTF-16 的文件同样按 ISO-8859-1 解码
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::file;
+///
+/// let path = std::env::temp_dir().join("huturs_latin1.txt");
+/// let path = path.to_str().unwrap();
+/// std::fs::write(path, b"caf\xe9").unwrap();
+/// let (content, encoding) = file::read_file_with_encoding_detection(path).unwrap();
+/// assert_eq!(content, "café");
+/// assert_eq!(encoding, "ISO-8859-1");
+/// # let _ = file::delete_file(path);
+/// ```
+#[cfg(feature = "file-encoding")]
+#[cfg_attr(docsrs, doc(cfg(feature = "file-encoding")))]
+pub fn read_file_with_encoding_detection(path: &str) -> Result<(String, &'static str), Error> {
+    if is_blank(path) {
+        return Err(Error::other(format!("File {} is blank", path)));
+    }
+    let bytes = fs::read(path)?;
+
+    let without_bom = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(&bytes);
+    if let Ok(content) = std::str::from_utf8(without_bom) {
+        return Ok((content.to_string(), "UTF-8"));
+    }
+
+    let utf16 = if let Some(body) = bytes.strip_prefix(b"\xFF\xFE") {
+        decode_utf16(body, u16::from_le_bytes).map(|content| (content, "UTF-16LE"))
+    } else if let Some(body) = bytes.strip_prefix(b"\xFE\xFF") {
+        decode_utf16(body, u16::from_be_bytes).map(|content| (content, "UTF-16BE"))
+    } else {
+        None
+    };
+    if let Some(decoded) = utf16 {
+        return Ok(decoded);
+    }
+
+    Ok((bytes.iter().map(|&b| b as char).collect(), "ISO-8859-1"))
+}
+
+/// 按指定字节序解码 UTF-16 内容，长度为奇数或包含非法代理对时返回 `None`
+#[cfg(feature = "file-encoding")]
+fn decode_utf16(bytes: &[u8], to_unit: fn([u8; 2]) -> u16) -> Option<String> {
+    if !bytes.len().is_multiple_of(2) {
+        return None;
+    }
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| to_unit([pair[0], pair[1]]))
+        .collect();
+    String::from_utf16(&units).ok()
+}
+
+/// 以原子方式将字符串内容写入文件
+///
+/// # 参数
+/// * `path` - 文件路径
+/// * `contents` - 要写入的内容
+///
+/// # 返回值
+/// 返回 `Result<(), Error>`，成功时返回 `Ok(())`，失败时包含错误信息
+///
+/// # 注意
+/// 详见 [`atomic_write_bytes`]
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::file;
+///
+/// let path = std::env::temp_dir().join("huturs_atomic.txt");
+/// let path = path.to_str().unwrap();
+/// match file::atomic_write(path, "Hello, world!") {
+///     Ok(()) => println!("写入成功"),
+///     Err(e) => eprintln!("写入失败: {}", e),
+/// }
+/// # let _ = file::delete_file(path);
+/// ```
+pub fn atomic_write(path: &str, contents: &str) -> Result<(), Error> {
+    atomic_write_bytes(path, contents.as_bytes())
+}
+
+/// 以原子方式将字节内容写入文件
+///
+/// # 参数
+/// * `path` - 文件路径
+/// * `contents` - 要写入的字节内容
+///
+/// # 返回值
+/// 返回 `Result<(), Error>`，成功时返回 `Ok(())`，失败时包含错误信息
+///
+/// # 注意
+/// 先将内容写入同一目录下的临时文件（文件名为目标文件名加随机后缀），落盘后再通过 `fs::rename`
+/// 替换目标文件，因此读取方要么看到旧内容，要么看到完整的新内容。重命名失败时会删除临时文件。
+/// 在 POSIX 系统上重命名是原子操作；在 Windows 上重命名并不严格保证原子性，只能尽力而为
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::file;
+///
+/// let path = std::env::temp_dir().join("huturs_atomic.bin");
+/// let path = path.to_str().unwrap();
+/// match file::atomic_write_bytes(path, &[0xCA, 0xFE]) {
+///     Ok(()) => println!("写入成功"),
+///     Err(e) => eprintln!("写入失败: {}", e),
+/// }
+/// # let _ = file::delete_file(path);
+/// ```
+pub fn atomic_write_bytes(path: &str, contents: &[u8]) -> Result<(), Error> {
+    if is_blank(path) {
+        return Err(Error::other(format!("File {} is blank", path)));
+    }
+    let target = Path::new(path);
+    let file_name = target.file_name().ok_or_else(|| {
+        Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("File {} has no file name", path),
+        )
+    })?;
+    let temp_path = target.with_file_name(format!(
+        ".{}.{}.tmp",
+        file_name.to_string_lossy(),
+        temp_file_suffix()
+    ));
+
+    let result = fs::File::create(&temp_path)
+        .and_then(|mut file| {
+            file.write_all(contents)?;
+            file.sync_all()
+        })
+        .and_then(|_| fs::rename(&temp_path, target));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result
+}
+
+/// 生成临时文件的随机后缀，由进程号、当前纳秒时间和自增计数组成
+fn temp_file_suffix() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or_default();
+    format!(
+        "{}{:08x}{}",
+        std::process::id(),
+        nanos,
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// 递归计算目录下所有普通文件的大小之和
+///
+/// # 参数
+/// * `path` - 目录路径
+///
+/// # 返回值
+/// 返回 `Result<u64, Error>`，成功时包含所有普通文件的字节数之和，目录本身无法读取时包含错误信息
+///
+/// # 注意
+/// 符号链接和特殊文件不计入统计；扫描过程中无法访问的子目录或无法获取元数据的文件会被跳过
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::file;
+///
+/// match file::total_dir_size("./src") {
+///     Ok(size) => println!("目录大小: {} 字节", size),
+///     Err(e) => eprintln!("统计失败: {}", e),
+/// }
+/// ```
+pub fn total_dir_size(path: &str) -> Result<u64, Error> {
+    Ok(walk_files(path)?
+        .iter()
+        .filter_map(|file| fs::symlink_metadata(file).ok())
+        .map(|metadata| metadata.len())
+        .sum())
+}
+
+/// 递归查找目录下最大的 N 个普通文件
+///
+/// # 参数
+/// * `path` - 目录路径
+/// * `n` - 返回的文件数量上限
+///
+/// # 返回值
+/// 返回 `Result<Vec<(PathBuf, u64)>, Error>`，成功时包含按文件大小降序排列的 `(路径, 字节数)`，
+/// 大小相同时按路径升序排列；目录本身无法读取时包含错误信息
+///
+/// # 注意
+/// 符号链接和特殊文件不参与排序；扫描过程中无法访问的子目录或无法获取元数据的文件会被跳过
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::file;
+///
+/// match file::largest_files("./src", 3) {
+///     Ok(files) => {
+///         for (path, size) in files {
+///             println!("{:?}: {} 字节", path, size);
+///         }
+///     }
+///     Err(e) => eprintln!("查找失败: {}", e),
+/// }
+/// ```
+pub fn largest_files(path: &str, n: usize) -> Result<Vec<(PathBuf, u64)>, Error> {
+    let mut files: Vec<(PathBuf, u64)> = walk_files(path)?
+        .into_iter()
+        .filter_map(|file| {
+            let size = fs::symlink_metadata(&file).ok()?.len();
+            Some((file, size))
+        })
+        .collect();
+    files.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    files.truncate(n);
+    Ok(files)
+}
+
+/// 递归收集目录下的所有普通文件，不跟随符号链接，跳过无法读取的子目录
+fn walk_files(path: &str) -> Result<Vec<PathBuf>, Error> {
+    if is_blank(path) {
+        return Err(Error::other(format!("File {} is blank", path)));
+    }
+    let mut files = Vec::new();
+    let mut pending = vec![fs::read_dir(path)?];
+    while let Some(entries) = pending.pop() {
+        for entry in entries.flatten() {
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_dir() {
+                if let Ok(sub_entries) = fs::read_dir(entry.path()) {
+                    pending.push(sub_entries);
+                }
+            } else if file_type.is_file() {
+                files.push(entry.path());
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// 递归查找目录下指定扩展名的文件
+///
+/// # 参数
+/// * `path` - 目录路径
+/// * `extension` - 扩展名（不含 `.`），匹配时忽略大小写
+///
+/// # 返回值
+/// 返回 `Result<Vec<PathBuf>, Error>`，成功时包含按路径排序的匹配文件，目录本身无法读取时包含错误信息
+///
+/// # 注意
+/// 扫描过程中无法访问的子目录会被跳过，符号链接不会被跟随
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::file;
+///
+/// match file::search_files_by_extension("./src", "rs") {
+///     Ok(files) => println!("找到 {} 个 Rust 源文件", files.len()),
+///     Err(e) => eprintln!("查找失败: {}", e),
+/// }
+/// ```
+pub fn search_files_by_extension(path: &str, extension: &str) -> Result<Vec<PathBuf>, Error> {
+    let mut files: Vec<PathBuf> = walk_files(path)?
+        .into_iter()
+        .filter(|file| {
+            file.extension()
+                .is_some_and(|ext| ext.to_string_lossy().eq_ignore_ascii_case(extension))
+        })
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// 递归查找目录下文件名包含指定子串的文件
+///
+/// # 参数
+/// * `path` - 目录路径
+/// * `name_pattern` - 要匹配的子串（区分大小写，不支持通配符）
+///
+/// # 返回值
+/// 返回 `Result<Vec<PathBuf>, Error>`，成功时包含按路径排序的匹配文件，目录本身无法读取时包含错误信息
+///
+/// # 注意
+/// 只匹配文件名而非完整路径；扫描过程中无法访问的子目录会被跳过，符号链接不会被跟随
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::file;
+///
+/// match file::search_files_by_name("./src", "date") {
+///     Ok(files) => println!("找到 {} 个文件", files.len()),
+///     Err(e) => eprintln!("查找失败: {}", e),
+/// }
+/// ```
+pub fn search_files_by_name(path: &str, name_pattern: &str) -> Result<Vec<PathBuf>, Error> {
+    let mut files: Vec<PathBuf> = walk_files(path)?
+        .into_iter()
+        .filter(|file| {
+            file.file_name()
+                .is_some_and(|name| name.to_string_lossy().contains(name_pattern))
+        })
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// 统计文件的行数
+///
+/// # 参数
+/// * `path` - 文件路径
+///
+/// # 返回值
+/// 返回 `Result<usize, Error>`，成功时包含文件行数，失败时包含错误信息
+///
+/// # 注意
+/// 按块读取并统计换行符 `\n` 的数量，不会将整个文件加载到内存中；
+/// 最后一行没有换行符结尾时同样计为一行，空文件返回 0，与 `str::lines().count()` 的结果一致
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::file;
+///
+/// match file::file_line_count("./Cargo.toml") {
+///     Ok(count) => println!("共 {} 行", count),
+///     Err(e) => eprintln!("统计失败: {}", e),
+/// }
+/// ```
+pub fn file_line_count(path: &str) -> Result<usize, Error> {
+    if is_blank(path) {
+        return Err(Error::other(format!("File {} is blank", path)));
+    }
+    let mut reader = BufReader::new(fs::File::open(path)?);
+    let mut count = 0;
+    let mut last_byte = None;
+    loop {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            break;
+        }
+        count += buffer.iter().filter(|&&b| b == b'\n').count();
+        last_byte = buffer.last().copied();
+        let length = buffer.len();
+        reader.consume(length);
+    }
+    if last_byte.is_some_and(|b| b != b'\n') {
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// 统计文件中以空白字符分隔的单词数量
+///
+/// # 参数
+/// * `path` - 文件路径
+///
+/// # 返回值
+/// 返回 `Result<usize, Error>`，成功时包含单词数量，失败时包含错误信息；
+/// 文件不是合法的 UTF-8 文本时返回 `ErrorKind::InvalidData` 错误
+///
+/// # 注意
+/// 逐行读取统计，不会将整个文件加载到内存中
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::file;
+///
+/// match file::file_word_count("./Cargo.toml") {
+///     Ok(count) => println!("共 {} 个单词", count),
+///     Err(e) => eprintln!("统计失败: {}", e),
+/// }
+/// ```
+pub fn file_word_count(path: &str) -> Result<usize, Error> {
+    if is_blank(path) {
+        return Err(Error::other(format!("File {} is blank", path)));
+    }
+    let reader = BufReader::new(fs::File::open(path)?);
+    let mut count = 0;
+    for line in reader.lines() {
+        count += line?.split_whitespace().count();
+    }
+    Ok(count)
+}
+
+/// 文件差异中的一行
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine {
+    /// 两个文件中都存在的行
+    Same(String),
+    /// 只在第二个文件中存在的行
+    Added(String),
+    /// 只在第一个文件中存在的行
+    Removed(String),
+}
+
+/// 逐行比较两个文本文件的差异
+///
+/// # 参数
+/// * `path1` - 原文件路径
+/// * `path2` - 新文件路径
+///
+/// # 返回值
+/// 返回 `Result<Vec<DiffLine>, Error>`，成功时包含按顺序排列的差异行，失败时包含错误信息。
+/// 取出所有 `Same` 和 `Removed` 行可以还原第一个文件，取出所有 `Same` 和 `Added` 行可以还原第二个文件
+///
+/// # 注意
+/// 基于最长公共子序列（LCS）算法，时间和空间复杂度均为 O(m * n)（m、n 为两个文件的行数），
+/// 适合比较配置文件等中小型文件。比较时忽略行尾的 `\n` / `\r\n` 差异
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::file::{self, DiffLine};
+///
+/// let dir = std::env::temp_dir();
+/// let old = dir.join("huturs_diff_old.txt");
+/// let new = dir.join("huturs_diff_new.txt");
+/// let (old, new) = (old.to_str().unwrap(), new.to_str().unwrap());
+/// file::write_file(old, "a\nb\nc\n").unwrap();
+/// file::write_file(new, "a\nc\nd\n").unwrap();
+///
+/// let diff = file::diff_files(old, new).unwrap();
+/// assert_eq!(
+///     diff,
+///     vec![
+///         DiffLine::Same("a".to_string()),
+///         DiffLine::Removed("b".to_string()),
+///         DiffLine::Same("c".to_string()),
+///         DiffLine::Added("d".to_string()),
+///     ]
+/// );
+/// # let _ = file::delete_file(old);
+/// # let _ = file::delete_file(new);
+/// ```
+pub fn diff_files(path1: &str, path2: &str) -> Result<Vec<DiffLine>, Error> {
+    for path in [path1, path2] {
+        if is_blank(path) {
+            return Err(Error::other(format!("File {} is blank", path)));
+        }
+    }
+    let old = fs::read_to_string(path1)?;
+    let new = fs::read_to_string(path2)?;
+    Ok(diff_lines(
+        &old.lines().collect::<Vec<&str>>(),
+        &new.lines().collect::<Vec<&str>>(),
+    ))
+}
+
+/// 以 unified diff 格式输出两个文本文件的差异
+///
+/// # 参数
+/// * `path1` - 原文件路径
+/// * `path2` - 新文件路径
+/// * `context` - 每处改动前后保留的上下文行数，常用 3
+///
+/// # 返回值
+/// 返回 `Result<String, Error>`，成功时包含 `---` / `+++` 文件头和若干 `@@ -l,s +l,s @@` 块；
+/// 两个文件内容相同时返回空字符串
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::file;
+///
+/// let dir = std::env::temp_dir();
+/// let old = dir.join("huturs_unified_old.txt");
+/// let new = dir.join("huturs_unified_new.txt");
+/// let (old, new) = (old.to_str().unwrap(), new.to_str().unwrap());
+/// file::write_file(old, "a\nb\nc\n").unwrap();
+/// file::write_file(new, "a\nB\nc\n").unwrap();
+///
+/// let diff = file::diff_files_unified(old, new, 1).unwrap();
+/// assert!(diff.ends_with("@@ -1,3 +1,3 @@\n a\n-b\n+B\n c\n"));
+/// # let _ = file::delete_file(old);
+/// # let _ = file::delete_file(new);
+/// ```
+pub fn diff_files_unified(path1: &str, path2: &str, context: usize) -> Result<String, Error> {
+    let diff = diff_files(path1, path2)?;
+    let changes: Vec<usize> = diff
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, DiffLine::Same(_)))
+        .map(|(index, _)| index)
+        .collect();
+    if changes.is_empty() {
+        return Ok(String::new());
+    }
+
+    // 每个位置之前已经出现的原文件行数和新文件行数
+    let mut positions = Vec::with_capacity(diff.len() + 1);
+    let (mut old_line, mut new_line) = (0, 0);
+    for line in &diff {
+        positions.push((old_line, new_line));
+        match line {
+            DiffLine::Same(_) => {
+                old_line += 1;
+                new_line += 1;
+            }
+            DiffLine::Removed(_) => old_line += 1,
+            DiffLine::Added(_) => new_line += 1,
+        }
+    }
+    positions.push((old_line, new_line));
+
+    // 将改动按上下文范围合并为若干块
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for &index in &changes {
+        let start = index.saturating_sub(context);
+        let end = (index + context + 1).min(diff.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let mut output = format!("--- {}\n+++ {}\n", path1, path2);
+    for (start, end) in hunks {
+        let old_count = positions[end].0 - positions[start].0;
+        let new_count = positions[end].1 - positions[start].1;
+        let range_start = |line: usize, count: usize| if count == 0 { line } else { line + 1 };
+        output.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            range_start(positions[start].0, old_count),
+            old_count,
+            range_start(positions[start].1, new_count),
+            new_count
+        ));
+        for line in &diff[start..end] {
+            let (prefix, text) = match line {
+                DiffLine::Same(text) => (' ', text),
+                DiffLine::Removed(text) => ('-', text),
+                DiffLine::Added(text) => ('+', text),
+            };
+            output.push(prefix);
+            output.push_str(text);
+            output.push('\n');
+        }
+    }
+    Ok(output)
+}
+
+/// 基于最长公共子序列计算两组行之间的差异
+fn diff_lines(old: &[&str], new: &[&str]) -> Vec<DiffLine> {
+    // lcs[i][j] 为 old[i..] 与 new[j..] 的最长公共子序列长度
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut result = Vec::with_capacity(old.len().max(new.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            result.push(DiffLine::Same(old[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            result.push(DiffLine::Removed(old[i].to_string()));
+            i += 1;
+        } else {
+            result.push(DiffLine::Added(new[j].to_string()));
+            j += 1;
+        }
+    }
+    result.extend(
+        old[i..]
+            .iter()
+            .map(|line| DiffLine::Removed(line.to_string())),
+    );
+    result.extend(
+        new[j..]
+            .iter()
+            .map(|line| DiffLine::Added(line.to_string())),
+    );
+    result
+}
+
+/// 目录监听事件
+#[cfg(feature = "file-watch")]
+#[cfg_attr(docsrs, doc(cfg(feature = "file-watch")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchEvent {
+    /// 新建了文件或子目录
+    Created,
+    /// 文件的修改时间或大小发生了变化
+    Modified,
+    /// 文件或子目录被删除
+    Deleted,
+}
+
+/// 目录监听句柄
+///
+/// 由 [`watch_directory`] 返回，句柄被丢弃时停止监听并等待后台线程退出
+#[cfg(feature = "file-watch")]
+#[cfg_attr(docsrs, doc(cfg(feature = "file-watch")))]
+#[derive(Debug)]
+pub struct WatchHandle {
+    stopped: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+#[cfg(feature = "file-watch")]
+impl WatchHandle {
+    /// 停止监听，等价于丢弃句柄
+    pub fn stop(self) {}
+}
+
+#[cfg(feature = "file-watch")]
+impl Drop for WatchHandle {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+            let _ = thread.join();
+        }
+    }
+}
+
+/// 监听目录中文件的新建、修改和删除，默认每 500 毫秒检查一次
+///
+/// # 参数
+/// * `path` - 要监听的目录路径
+/// * `callback` - 检测到变化时在后台线程中调用，参数为发生变化的路径和事件类型
+///
+/// # 返回值
+/// 返回 `Result<WatchHandle, Error>`，成功时包含监听句柄，目录无法读取时包含错误信息
+///
+/// # 注意
+/// 详见 [`watch_directory_with_interval`]
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::file;
+///
+/// let dir = std::env::temp_dir();
+/// let handle = file::watch_directory(dir.to_str().unwrap(), |path, event| {
+///     println!("{:?}: {}", event, path.display());
+/// })
+/// .unwrap();
+/// // 丢弃句柄即停止监听
+/// drop(handle);
+/// ```
+#[cfg(feature = "file-watch")]
+#[cfg_attr(docsrs, doc(cfg(feature = "file-watch")))]
+pub fn watch_directory<F>(path: &str, callback: F) -> Result<WatchHandle, Error>
+where
+    F: Fn(PathBuf, WatchEvent) + Send + 'static,
+{
+    watch_directory_with_interval(path, Duration::from_millis(500), callback)
+}
+
+/// 以指定的间隔轮询监听目录中文件的新建、修改和删除
+///
+/// # 参数
+/// * `path` - 要监听的目录路径
+/// * `interval` - 两次检查之间的间隔
+/// * `callback` - 检测到变化时在后台线程中调用，参数为发生变化的路径和事件类型
+///
+/// # 返回值
+/// 返回 `Result<WatchHandle, Error>`，成功时包含监听句柄，目录无法读取时包含错误信息
+///
+/// # 注意
+/// 通过后台线程定期比较目录项的修改时间和大小实现，不依赖操作系统的文件通知机制，
+/// 因此同一检查间隔内的多次修改只会触发一次事件。只监听目录的直接子项，不会递归监听子目录的内容。
+/// 初始状态在函数返回前记录，返回之后发生的变化都会被检测到
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::file;
+/// use std::time::Duration;
+///
+/// let dir = std::env::temp_dir();
+/// let handle = file::watch_directory_with_interval(
+///     dir.to_str().unwrap(),
+///     Duration::from_millis(100),
+///     |path, event| println!("{:?}: {}", event, path.display()),
+/// )
+/// .unwrap();
+/// handle.stop();
+/// ```
+#[cfg(feature = "file-watch")]
+#[cfg_attr(docsrs, doc(cfg(feature = "file-watch")))]
+pub fn watch_directory_with_interval<F>(
+    path: &str,
+    interval: Duration,
+    callback: F,
+) -> Result<WatchHandle, Error>
+where
+    F: Fn(PathBuf, WatchEvent) + Send + 'static,
+{
+    let path = path.to_string();
+    let mut previous = directory_snapshot(&path)?;
+    let stopped = Arc::new(AtomicBool::new(false));
+    let thread_stopped = Arc::clone(&stopped);
+    let thread = std::thread::spawn(move || loop {
+        std::thread::park_timeout(interval);
+        if thread_stopped.load(Ordering::SeqCst) {
+            break;
+        }
+        // 目录本身被删除或暂时无法读取时，视为所有子项都已被删除
+        let current = directory_snapshot(&path).unwrap_or_default();
+        let mut events: Vec<(&PathBuf, WatchEvent)> = Vec::new();
+        for (entry, state) in &current {
+            match previous.get(entry) {
+                None => events.push((entry, WatchEvent::Created)),
+                Some(old) if old != state => events.push((entry, WatchEvent::Modified)),
+                _ => {}
+            }
+        }
+        for entry in previous.keys() {
+            if !current.contains_key(entry) {
+                events.push((entry, WatchEvent::Deleted));
+            }
+        }
+        events.sort_by(|a, b| a.0.cmp(b.0));
+        for (entry, event) in events {
+            callback(entry.clone(), event);
+        }
+        previous = current;
+    });
+    Ok(WatchHandle {
+        stopped,
+        thread: Some(thread),
+    })
+}
+
+/// 记录目录中每个直接子项的修改时间和大小
+#[cfg(feature = "file-watch")]
+fn directory_snapshot(path: &str) -> Result<HashMap<PathBuf, (Option<SystemTime>, u64)>, Error> {
+    let mut snapshot = HashMap::new();
+    for entry in read_dirs(path)? {
+        // 读取元数据前文件可能已被删除，此时跳过，留到下一次检查
+        if let Ok(metadata) = fs::metadata(&entry) {
+            snapshot.insert(entry, (metadata.modified().ok(), metadata.len()));
+        }
+    }
+    Ok(snapshot)
+}
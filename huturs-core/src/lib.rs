@@ -55,4 +55,11 @@ pub use stopwatch::*;
 #[cfg_attr(docsrs, doc(cfg(feature = "util")))]
 pub mod util;
 #[cfg(feature = "util")]
-pub use util::*;
\ No newline at end of file
+pub use util::*;
+
+// 输入校验模块
+#[cfg(feature = "validation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "validation")))]
+pub mod validation;
+#[cfg(feature = "validation")]
+pub use validation::*;
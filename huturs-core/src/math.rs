@@ -538,4 +538,2633 @@ where
     T: std::ops::Add<Output = T> + Copy + Into<f64>,
 {
     sample_variance(numbers).sqrt()
-}
\ No newline at end of file
+}
+
+/// 使用埃拉托斯特尼筛法获取不超过指定上限的所有素数
+///
+/// # 参数
+/// * `limit` - 上限（包含）
+///
+/// # 返回值
+/// 返回 `[2, limit]` 范围内的所有素数，按升序排列
+///
+/// # 注意
+/// 筛表使用 `Vec<bool>`，每个数占用 1 字节，内存占用约为 `limit` 字节。
+/// 当 `limit` 超过 10^7 时需要 10MB 以上的内存（按位压缩的实现约为 `limit / 8` 字节）。
+/// 返回值为 `u32`，因此 `limit` 不应超过 `u32::MAX`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::sieve_of_eratosthenes(20), vec![2, 3, 5, 7, 11, 13, 17, 19]);
+/// assert!(math::sieve_of_eratosthenes(1).is_empty());
+/// ```
+pub fn sieve_of_eratosthenes(limit: usize) -> Vec<u32> {
+    if limit < 2 {
+        return Vec::new();
+    }
+    let mut is_prime = vec![true; limit + 1];
+    is_prime[0] = false;
+    is_prime[1] = false;
+
+    let mut i = 2;
+    while i * i <= limit {
+        if is_prime[i] {
+            // 小于 i * i 的合数已经被更小的素因子标记过
+            for multiple in (i * i..=limit).step_by(i) {
+                is_prime[multiple] = false;
+            }
+        }
+        i += 1;
+    }
+
+    is_prime
+        .iter()
+        .enumerate()
+        .filter(|(_, &prime)| prime)
+        .map(|(n, _)| n as u32)
+        .collect()
+}
+
+/// 获取第 n 个素数
+///
+/// # 参数
+/// * `n` - 素数的序号，从 1 开始
+///
+/// # 返回值
+/// 返回第 n 个素数，例如第 1 个素数为 2
+///
+/// # 注意
+/// 如果 `n` 为 0，此函数会 panic。内部使用 `n * (ln n + ln ln n)` 估算筛法上限
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::nth_prime(1), 2);
+/// assert_eq!(math::nth_prime(10), 29);
+/// assert_eq!(math::nth_prime(1000), 7919);
+/// ```
+pub fn nth_prime(n: usize) -> u32 {
+    assert!(n > 0, "n must be greater than 0");
+    // 当 n >= 6 时，第 n 个素数小于 n * (ln n + ln ln n)
+    let limit = if n < 6 {
+        15
+    } else {
+        let n = n as f64;
+        (n * (n.ln() + n.ln().ln())) as usize + 1
+    };
+    sieve_of_eratosthenes(limit)[n - 1]
+}
+
+/// 计算非负整数的十进制位数
+///
+/// # 参数
+/// * `n` - 输入的整数
+///
+/// # 返回值
+/// 返回十进制位数，`0` 的位数为 1
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::count_digits(0), 1);
+/// assert_eq!(math::count_digits(12345), 5);
+/// ```
+pub fn count_digits(n: u64) -> u32 {
+    match n.checked_ilog10() {
+        Some(log) => log + 1,
+        None => 1,
+    }
+}
+
+/// 计算非负整数各位数字之和
+///
+/// # 参数
+/// * `n` - 输入的整数
+///
+/// # 返回值
+/// 返回十进制下各位数字之和
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::digit_sum(12345), 15);
+/// assert_eq!(math::digit_sum(0), 0);
+/// ```
+pub fn digit_sum(n: u64) -> u32 {
+    let mut n = n;
+    let mut sum = 0;
+    while n > 0 {
+        sum += (n % 10) as u32;
+        n /= 10;
+    }
+    sum
+}
+
+/// 计算非负整数的数根
+///
+/// 数根是反复对各位数字求和，直到结果只剩一位数时的值
+///
+/// # 参数
+/// * `n` - 输入的整数
+///
+/// # 返回值
+/// 返回数根，`0` 的数根为 0，其余为 `1 + (n - 1) % 9`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::digital_root(12345), 6); // 1+2+3+4+5=15, 1+5=6
+/// assert_eq!(math::digital_root(0), 0);
+/// ```
+pub fn digital_root(n: u64) -> u32 {
+    if n == 0 {
+        0
+    } else {
+        (1 + (n - 1) % 9) as u32
+    }
+}
+
+/// 反转非负整数的十进制数字
+///
+/// # 参数
+/// * `n` - 输入的整数
+///
+/// # 返回值
+/// 返回数字反转后的整数，末尾的 0 反转后会被舍弃（如 `120` 反转为 `21`）
+///
+/// # 注意
+/// 如果反转结果超出 `u64` 的范围（如 `u64::MAX`），此函数会 panic
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::reverse_number(12345), 54321);
+/// assert_eq!(math::reverse_number(120), 21);
+/// ```
+pub fn reverse_number(n: u64) -> u64 {
+    let mut n = n;
+    let mut reversed: u64 = 0;
+    while n > 0 {
+        reversed = reversed
+            .checked_mul(10)
+            .and_then(|r| r.checked_add(n % 10))
+            .expect("reversed number overflows u64");
+        n /= 10;
+    }
+    reversed
+}
+
+/// 检查整数是否为阿姆斯特朗数（自幂数）
+///
+/// 阿姆斯特朗数是指每一位数字的 k 次幂之和等于其自身的 k 位数，如 `153 = 1³ + 5³ + 3³`
+///
+/// # 参数
+/// * `n` - 要检查的整数
+///
+/// # 返回值
+/// 如果该数是阿姆斯特朗数，返回 `true`；否则返回 `false`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert!(math::is_armstrong_number(153));
+/// assert!(!math::is_armstrong_number(154));
+/// ```
+pub fn is_armstrong_number(n: u32) -> bool {
+    let digits = count_digits(n as u64);
+    let mut rest = n;
+    let mut sum: u64 = 0;
+    while rest > 0 {
+        sum += ((rest % 10) as u64).pow(digits);
+        rest /= 10;
+    }
+    sum == n as u64
+}
+
+/// 整数向上取整除法
+///
+/// 与 `(a + b - 1) / b` 的写法不同，此函数不会在被除数较大时溢出，且能正确处理负数
+///
+/// # 参数
+/// * `dividend` - 被除数
+/// * `divisor` - 除数
+///
+/// # 返回值
+/// 返回向正无穷方向取整的商，如果除数为 0 或结果溢出（`i64::MIN / -1`）则返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::ceil_div(10, 3), Some(4));
+/// assert_eq!(math::ceil_div(-10, 3), Some(-3));
+/// assert_eq!(math::ceil_div(10, 0), None);
+/// ```
+pub fn ceil_div(dividend: i64, divisor: i64) -> Option<i64> {
+    let quotient = dividend.checked_div(divisor)?;
+    let remainder = dividend % divisor;
+    // 余数与除数同号时，精确商为正数，截断后需要加 1
+    if remainder != 0 && (remainder > 0) == (divisor > 0) {
+        Some(quotient + 1)
+    } else {
+        Some(quotient)
+    }
+}
+
+/// 整数向下取整除法
+///
+/// Rust 的 `/` 运算符向零截断，此函数始终向负无穷方向取整（如 `-7 / 2` 得到 `-4`）
+///
+/// # 参数
+/// * `dividend` - 被除数
+/// * `divisor` - 除数
+///
+/// # 返回值
+/// 返回向负无穷方向取整的商，如果除数为 0 或结果溢出（`i64::MIN / -1`）则返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::floor_div(7, 2), Some(3));
+/// assert_eq!(math::floor_div(-7, 2), Some(-4));
+/// assert_eq!(math::floor_div(7, 0), None);
+/// ```
+pub fn floor_div(dividend: i64, divisor: i64) -> Option<i64> {
+    let quotient = dividend.checked_div(divisor)?;
+    let remainder = dividend % divisor;
+    // 余数与除数异号时，精确商为负数，截断后需要减 1
+    if remainder != 0 && (remainder > 0) != (divisor > 0) {
+        Some(quotient - 1)
+    } else {
+        Some(quotient)
+    }
+}
+
+/// 获取大于等于 n 的最小的 2 的幂
+///
+/// # 参数
+/// * `n` - 输入的整数
+///
+/// # 返回值
+/// 返回大于等于 `n` 的最小的 2 的幂，`n` 为 0 时返回 `Some(1)`，
+/// 如果结果超出 `u64` 的范围则返回 `None`（`u64::next_power_of_two` 在这种情况下会 panic 或溢出）
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::next_power_of_two(0), Some(1));
+/// assert_eq!(math::next_power_of_two(5), Some(8));
+/// assert_eq!(math::next_power_of_two(8), Some(8));
+/// assert_eq!(math::next_power_of_two(u64::MAX), None);
+/// ```
+pub fn next_power_of_two(n: u64) -> Option<u64> {
+    n.checked_next_power_of_two()
+}
+
+/// 检查整数是否为 2 的幂
+///
+/// # 参数
+/// * `n` - 要检查的整数
+///
+/// # 返回值
+/// 如果 `n > 0` 且 `n & (n - 1) == 0`，返回 `true`；否则返回 `false`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert!(math::is_power_of_two(1));
+/// assert!(math::is_power_of_two(1024));
+/// assert!(!math::is_power_of_two(0));
+/// assert!(!math::is_power_of_two(6));
+/// ```
+pub fn is_power_of_two(n: u64) -> bool {
+    n > 0 && n & (n - 1) == 0
+}
+
+/// 计算以 2 为底的对数并向下取整
+///
+/// # 参数
+/// * `n` - 输入的整数
+///
+/// # 返回值
+/// 返回 `floor(log₂(n))`，`n` 为 0 时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::log2_floor(1), Some(0));
+/// assert_eq!(math::log2_floor(1000), Some(9));
+/// assert_eq!(math::log2_floor(0), None);
+/// ```
+pub fn log2_floor(n: u64) -> Option<u32> {
+    n.checked_ilog2()
+}
+
+/// 计算 softmax 函数
+///
+/// 使用数值稳定的写法 `exp(x - max) / sum(exp(x - max))`，避免输入较大时 `exp` 溢出
+///
+/// # 参数
+/// * `values` - 输入的数值数组
+///
+/// # 返回值
+/// 返回与输入等长、元素之和为 1.0 的概率分布，如果数组为空则返回空数组
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// let probs = math::softmax(&[1.0, 2.0, 3.0]);
+/// assert!((probs.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+/// assert!(probs[2] > probs[1] && probs[1] > probs[0]);
+/// ```
+pub fn softmax(values: &[f64]) -> Vec<f64> {
+    let max = match values.iter().copied().reduce(f64::max) {
+        Some(max) => max,
+        None => return Vec::new(),
+    };
+    let exps: Vec<f64> = values.iter().map(|&x| (x - max).exp()).collect();
+    let sum: f64 = exps.iter().sum();
+    exps.iter().map(|&e| e / sum).collect()
+}
+
+/// 计算概率分布的香农熵（单位：比特）
+///
+/// 按 `H = -Σ p * log₂(p)` 计算，其中 `0 * log₂(0)` 视为 0
+///
+/// # 参数
+/// * `probs` - 概率分布
+///
+/// # 返回值
+/// 返回香农熵，如果存在负概率或概率之和与 1.0 的差超过 1e-9 则返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::entropy(&[0.5, 0.5]), Some(1.0));
+/// assert_eq!(math::entropy(&[1.0, 0.0]), Some(0.0));
+/// assert_eq!(math::entropy(&[0.5, 0.6]), None);
+/// ```
+pub fn entropy(probs: &[f64]) -> Option<f64> {
+    if probs.iter().any(|&p| p < 0.0) {
+        return None;
+    }
+    let sum: f64 = probs.iter().sum();
+    if (sum - 1.0).abs() > 1e-9 {
+        return None;
+    }
+    let h: f64 = probs
+        .iter()
+        .filter(|&&p| p > 0.0)
+        .map(|&p| p * p.log2())
+        .sum();
+    // 避免返回 -0.0
+    Some(if h == 0.0 { 0.0 } else { -h })
+}
+
+/// 计算考拉兹序列（3n+1 序列）
+///
+/// 从 `n` 开始，偶数除以 2，奇数乘以 3 再加 1，直到得到 1 为止
+///
+/// # 参数
+/// * `n` - 起始值
+///
+/// # 返回值
+/// 返回包含起始值和结尾 1 在内的完整序列，`n` 为 0 时返回空数组
+///
+/// # 注意
+/// 如果计算过程中的值超出 `u64` 的范围，此函数会 panic
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::collatz_sequence(6), vec![6, 3, 10, 5, 16, 8, 4, 2, 1]);
+/// assert_eq!(math::collatz_sequence(1), vec![1]);
+/// assert!(math::collatz_sequence(0).is_empty());
+/// ```
+pub fn collatz_sequence(n: u64) -> Vec<u64> {
+    collatz_iter(n).collect()
+}
+
+/// 计算考拉兹序列的长度
+///
+/// 与 `collatz_sequence(n).len()` 的结果相同，但不会分配内存保存整个序列
+///
+/// # 参数
+/// * `n` - 起始值
+///
+/// # 返回值
+/// 返回序列中元素的个数（步数加 1），`n` 为 0 时返回 0
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::collatz_length(6), 9);
+/// assert_eq!(math::collatz_length(1), 1);
+/// ```
+pub fn collatz_length(n: u64) -> usize {
+    collatz_iter(n).count()
+}
+
+/// 计算考拉兹序列中出现的最大值
+///
+/// # 参数
+/// * `n` - 起始值
+///
+/// # 返回值
+/// 返回序列中的最大值，`n` 为 0 时返回 0
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::max_collatz_value(6), 16);
+/// assert_eq!(math::max_collatz_value(27), 9232);
+/// ```
+pub fn max_collatz_value(n: u64) -> u64 {
+    collatz_iter(n).max().unwrap_or(0)
+}
+
+/// 按需生成考拉兹序列的迭代器
+fn collatz_iter(n: u64) -> impl Iterator<Item = u64> {
+    let first = if n == 0 { None } else { Some(n) };
+    std::iter::successors(first, |&x| match x {
+        1 => None,
+        x if x % 2 == 0 => Some(x / 2),
+        x => Some(
+            x.checked_mul(3)
+                .and_then(|x| x.checked_add(1))
+                .expect("collatz sequence overflows u64"),
+        ),
+    })
+}
+
+/// 一组数值的汇总统计结果
+#[derive(Debug, Clone)]
+pub struct RangeStats {
+    /// 最小值
+    pub min: f64,
+    /// 最大值
+    pub max: f64,
+    /// 平均值
+    pub mean: f64,
+    /// 中位数，元素个数为偶数时取中间两个数的平均值
+    pub median: f64,
+    /// 总体方差（除以 n），与 [`variance`] 一致
+    pub variance: f64,
+    /// 总体标准差，与 [`standard_deviation`] 一致
+    pub std_dev: f64,
+    /// 元素个数
+    pub count: usize,
+    /// 元素总和
+    pub sum: f64,
+}
+
+impl RangeStats {
+    /// 从迭代器中计算汇总统计结果
+    ///
+    /// 最小值、最大值、总和、平均值和方差在一次遍历中计算（方差使用 Welford 算法），
+    /// 中位数需要对收集到的元素排序
+    ///
+    /// # 参数
+    /// * `iter` - 数值迭代器
+    ///
+    /// # 返回值
+    /// 返回 `Some(RangeStats)`，迭代器为空时返回 `None`
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::math::RangeStats;
+    ///
+    /// let stats = RangeStats::from_iter((1..=5).map(|x| x as f64)).unwrap();
+    /// assert_eq!(stats.median, 3.0);
+    /// assert_eq!(stats.sum, 15.0);
+    /// ```
+    // 返回值为 Option，无法实现 FromIterator，故沿用约定俗成的名称
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_iter(iter: impl Iterator<Item = f64>) -> Option<RangeStats> {
+        let mut values = Vec::new();
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+        let mut sum = 0.0;
+        let mut mean = 0.0;
+        let mut squared_diff_sum = 0.0;
+
+        for value in iter {
+            values.push(value);
+            min = min.min(value);
+            max = max.max(value);
+            sum += value;
+            let delta = value - mean;
+            mean += delta / values.len() as f64;
+            squared_diff_sum += delta * (value - mean);
+        }
+        if values.is_empty() {
+            return None;
+        }
+
+        let count = values.len();
+        values.sort_by(f64::total_cmp);
+        let median = sorted_median(&values);
+        let variance = squared_diff_sum / count as f64;
+
+        Some(RangeStats {
+            min,
+            max,
+            mean,
+            median,
+            variance,
+            std_dev: variance.sqrt(),
+            count,
+            sum,
+        })
+    }
+}
+
+/// 一次性计算数组的最小值、最大值、平均值、中位数、方差、标准差、个数和总和
+///
+/// # 参数
+/// * `numbers` - 浮点数数组
+///
+/// # 返回值
+/// 返回 `Some(RangeStats)`，数组为空时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// let stats = math::range_statistics(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).unwrap();
+/// assert_eq!(stats.mean, 5.0);
+/// assert_eq!(stats.median, 4.5);
+/// assert_eq!(stats.std_dev, 2.0);
+/// assert!(math::range_statistics(&[]).is_none());
+/// ```
+pub fn range_statistics(numbers: &[f64]) -> Option<RangeStats> {
+    RangeStats::from_iter(numbers.iter().copied())
+}
+
+/// 计算中位数绝对偏差（MAD）
+///
+/// 先求数组的中位数，再求每个元素与中位数之差的绝对值，最后返回这些绝对偏差的中位数。
+/// 相比标准差，MAD 对离群值不敏感
+///
+/// # 参数
+/// * `numbers` - 浮点数数组
+///
+/// # 返回值
+/// 返回中位数绝对偏差，数组为空时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::median_absolute_deviation(&[1.0, 1.0, 2.0, 2.0, 4.0, 6.0, 9.0]), Some(1.0));
+/// assert_eq!(math::median_absolute_deviation(&[]), None);
+/// ```
+pub fn median_absolute_deviation(numbers: &[f64]) -> Option<f64> {
+    if numbers.is_empty() {
+        return None;
+    }
+    let mut values = numbers.to_vec();
+    values.sort_by(f64::total_cmp);
+    let median = sorted_median(&values);
+
+    let mut deviations: Vec<f64> = values.iter().map(|x| (x - median).abs()).collect();
+    deviations.sort_by(f64::total_cmp);
+    Some(sorted_median(&deviations))
+}
+
+/// 计算截尾平均值
+///
+/// 排序后从两端各去掉 `trim_fraction` 比例（向下取整）的元素，再对剩余元素求平均值
+///
+/// # 参数
+/// * `numbers` - 浮点数数组
+/// * `trim_fraction` - 每一端去掉的比例，取值范围为 `[0.0, 0.5)`
+///
+/// # 返回值
+/// 返回截尾平均值，数组为空或 `trim_fraction` 超出取值范围时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// let nums = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 100.0];
+/// assert_eq!(math::trimmed_mean(&nums, 0.1), Some(5.5));
+/// assert_eq!(math::trimmed_mean(&nums, 0.5), None);
+/// ```
+pub fn trimmed_mean(numbers: &[f64], trim_fraction: f64) -> Option<f64> {
+    if numbers.is_empty() || !(0.0..0.5).contains(&trim_fraction) {
+        return None;
+    }
+    let mut values = numbers.to_vec();
+    values.sort_by(f64::total_cmp);
+    let trim = (values.len() as f64 * trim_fraction).floor() as usize;
+    let kept = &values[trim..values.len() - trim];
+    Some(kept.iter().sum::<f64>() / kept.len() as f64)
+}
+
+/// 计算已排序的非空数组的中位数，元素个数为偶数时取中间两个数的平均值
+fn sorted_median(sorted: &[f64]) -> f64 {
+    let count = sorted.len();
+    if count.is_multiple_of(2) {
+        (sorted[count / 2 - 1] + sorted[count / 2]) / 2.0
+    } else {
+        sorted[count / 2]
+    }
+}
+
+/// 一组数值的描述性统计结果，由 [`statistics_summary`] 计算
+///
+/// 实现了 `Display`，以两列表格的形式输出各项统计量
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatisticsSummary {
+    /// 元素个数
+    pub count: usize,
+    /// 平均值
+    pub mean: f64,
+    /// 中位数，元素个数为偶数时取中间两个数的平均值
+    pub median: f64,
+    /// 出现次数最多的数值（众数）的出现次数
+    pub mode_count: usize,
+    /// 总体标准差，与 [`standard_deviation`] 一致
+    pub std_dev: f64,
+    /// 总体方差（除以 n），与 [`variance`] 一致
+    pub variance: f64,
+    /// 最小值
+    pub min: f64,
+    /// 最大值
+    pub max: f64,
+    /// 极差（最大值减最小值）
+    pub range: f64,
+    /// 第一四分位数（25% 分位数，线性插值）
+    pub q1: f64,
+    /// 第三四分位数（75% 分位数，线性插值）
+    pub q3: f64,
+    /// 四分位距（`q3 - q1`）
+    pub iqr: f64,
+}
+
+impl std::fmt::Display for StatisticsSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:<10} {:>14}", "count", self.count)?;
+        let rows = [
+            ("mean", self.mean),
+            ("median", self.median),
+            ("std_dev", self.std_dev),
+            ("variance", self.variance),
+            ("min", self.min),
+            ("q1", self.q1),
+            ("q3", self.q3),
+            ("max", self.max),
+            ("range", self.range),
+            ("iqr", self.iqr),
+        ];
+        for (name, value) in rows {
+            writeln!(f, "{:<10} {:>14.4}", name, value)?;
+        }
+        write!(f, "{:<10} {:>14}", "mode_count", self.mode_count)
+    }
+}
+
+/// 一次性计算数组的描述性统计量
+///
+/// 只对数据排序一次，中位数、四分位数和众数出现次数都基于同一份排序结果计算
+///
+/// # 参数
+/// * `numbers` - 浮点数数组
+///
+/// # 返回值
+/// 返回 `Some(StatisticsSummary)`，数组为空时返回 `None`
+///
+/// # 注意
+/// 四分位数使用线性插值法计算（与 NumPy 的默认方法一致）
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// let summary = math::statistics_summary(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).unwrap();
+/// assert_eq!(summary.mean, 5.0);
+/// assert_eq!(summary.median, 4.5);
+/// assert_eq!(summary.mode_count, 3);
+/// assert_eq!(summary.q1, 4.0);
+/// assert_eq!(summary.q3, 5.5);
+/// assert!(math::statistics_summary(&[]).is_none());
+/// ```
+pub fn statistics_summary(numbers: &[f64]) -> Option<StatisticsSummary> {
+    if numbers.is_empty() {
+        return None;
+    }
+    let mut values = numbers.to_vec();
+    values.sort_by(f64::total_cmp);
+
+    let count = values.len();
+    let mean = values.iter().sum::<f64>() / count as f64;
+    let variance = values.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / count as f64;
+    let mode_count = values
+        .chunk_by(|a, b| a == b)
+        .map(|run| run.len())
+        .max()
+        .unwrap_or(0);
+    let min = values[0];
+    let max = values[count - 1];
+    let q1 = sorted_quantile(&values, 0.25);
+    let q3 = sorted_quantile(&values, 0.75);
+
+    Some(StatisticsSummary {
+        count,
+        mean,
+        median: sorted_median(&values),
+        mode_count,
+        std_dev: variance.sqrt(),
+        variance,
+        min,
+        max,
+        range: max - min,
+        q1,
+        q3,
+        iqr: q3 - q1,
+    })
+}
+
+/// 使用线性插值计算已排序数组的分位数，`sorted` 不能为空
+fn sorted_quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = (sorted.len() - 1) as f64 * q;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+/// 计算数组的前缀最大值
+///
+/// # 参数
+/// * `numbers` - 浮点数数组
+///
+/// # 返回值
+/// 返回与输入等长的数组，第 `i` 个元素为 `numbers[0..=i]` 中的最大值；输入为空时返回空数组
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::running_max(&[1.0, 3.0, 2.0, 5.0, 4.0]), vec![1.0, 3.0, 3.0, 5.0, 5.0]);
+/// ```
+pub fn running_max(numbers: &[f64]) -> Vec<f64> {
+    numbers
+        .iter()
+        .scan(f64::NEG_INFINITY, |acc, &x| {
+            *acc = acc.max(x);
+            Some(*acc)
+        })
+        .collect()
+}
+
+/// 计算数组的前缀最小值
+///
+/// # 参数
+/// * `numbers` - 浮点数数组
+///
+/// # 返回值
+/// 返回与输入等长的数组，第 `i` 个元素为 `numbers[0..=i]` 中的最小值；输入为空时返回空数组
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::running_min(&[3.0, 1.0, 2.0, 0.5, 4.0]), vec![3.0, 1.0, 1.0, 0.5, 0.5]);
+/// ```
+pub fn running_min(numbers: &[f64]) -> Vec<f64> {
+    numbers
+        .iter()
+        .scan(f64::INFINITY, |acc, &x| {
+            *acc = acc.min(x);
+            Some(*acc)
+        })
+        .collect()
+}
+
+/// 计算数组的前缀和
+///
+/// # 参数
+/// * `numbers` - 浮点数数组
+///
+/// # 返回值
+/// 返回与输入等长的数组，第 `i` 个元素为 `numbers[0..=i]` 的总和；输入为空时返回空数组
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::running_sum(&[1.0, 2.0, 3.0, 4.0]), vec![1.0, 3.0, 6.0, 10.0]);
+/// ```
+pub fn running_sum(numbers: &[f64]) -> Vec<f64> {
+    numbers
+        .iter()
+        .scan(0.0, |acc, &x| {
+            *acc += x;
+            Some(*acc)
+        })
+        .collect()
+}
+
+/// 将数字字符串从一种进制转换为另一种进制
+///
+/// # 参数
+/// * `n` - 以 `from_base` 进制表示的非负整数字符串，字母不区分大小写
+/// * `from_base` - 源进制，取值范围为 `2..=36`
+/// * `to_base` - 目标进制，取值范围为 `2..=36`
+///
+/// # 返回值
+/// 返回以 `to_base` 进制表示的字符串（使用 `0-9` 和小写 `a-z`）；
+/// 进制超出范围、数字超出源进制、字符串为空或数值超出 `u64` 范围时返回 `Err`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::number_base_convert("255", 10, 16), Ok("ff".to_string()));
+/// assert_eq!(math::number_base_convert("1010", 2, 10), Ok("10".to_string()));
+/// assert!(math::number_base_convert("12", 2, 10).is_err());
+/// ```
+pub fn number_base_convert(n: &str, from_base: u32, to_base: u32) -> Result<String, String> {
+    for base in [from_base, to_base] {
+        if !(2..=36).contains(&base) {
+            return Err(format!("Base {} is out of range 2..=36", base));
+        }
+    }
+    if let Some(c) = n.chars().find(|c| !c.is_digit(from_base)) {
+        return Err(format!("Invalid digit '{}' for base {}", c, from_base));
+    }
+    let value = u64::from_str_radix(n, from_base)
+        .map_err(|e| format!("Cannot parse '{}' in base {}: {}", n, from_base, e))?;
+    Ok(format_radix(value, to_base))
+}
+
+/// 将整数转换为二进制字符串
+///
+/// # 参数
+/// * `n` - 非负整数
+///
+/// # 返回值
+/// 返回不带前缀的二进制字符串
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::to_binary(10), "1010");
+/// ```
+pub fn to_binary(n: u64) -> String {
+    format!("{:b}", n)
+}
+
+/// 将整数转换为八进制字符串
+///
+/// # 参数
+/// * `n` - 非负整数
+///
+/// # 返回值
+/// 返回不带前缀的八进制字符串
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::to_octal(64), "100");
+/// ```
+pub fn to_octal(n: u64) -> String {
+    format!("{:o}", n)
+}
+
+/// 将整数转换为十六进制字符串
+///
+/// # 参数
+/// * `n` - 非负整数
+///
+/// # 返回值
+/// 返回不带前缀的小写十六进制字符串
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::to_hex(255), "ff");
+/// ```
+pub fn to_hex(n: u64) -> String {
+    format!("{:x}", n)
+}
+
+/// 将整数按指定进制（`2..=36`）格式化为小写字符串
+fn format_radix(mut n: u64, base: u32) -> String {
+    if n == 0 {
+        return String::from("0");
+    }
+    let mut digits = Vec::new();
+    while n > 0 {
+        let digit = (n % base as u64) as u32;
+        digits.push(std::char::from_digit(digit, base).unwrap_or('0'));
+        n /= base as u64;
+    }
+    digits.iter().rev().collect()
+}
+
+/// 分段线性插值
+///
+/// 根据一组有序的采样点（查找表）计算任意 `x` 对应的 `y` 值，
+/// 结果由 `x` 两侧相邻的两个采样点线性插值得到
+///
+/// # 参数
+/// * `x_vals` - 严格递增的 x 坐标
+/// * `y_vals` - 与 `x_vals` 一一对应的 y 坐标
+/// * `x` - 要查询的 x 坐标
+///
+/// # 返回值
+/// 返回插值结果；以下情况返回 `None`：
+/// * 两个切片长度不同，或采样点少于 2 个
+/// * `x_vals` 不是严格递增的
+/// * `x` 超出 `x_vals` 的范围（不做外推）或为 `NaN`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// let xs = [0.0, 10.0, 20.0];
+/// let ys = [0.0, 100.0, 50.0];
+/// assert_eq!(math::interpolate_slices(&xs, &ys, 5.0), Some(50.0));
+/// assert_eq!(math::interpolate_slices(&xs, &ys, 15.0), Some(75.0));
+/// assert_eq!(math::interpolate_slices(&xs, &ys, 25.0), None);
+/// ```
+pub fn interpolate_slices(x_vals: &[f64], y_vals: &[f64], x: f64) -> Option<f64> {
+    if !is_valid_lookup_table(x_vals, y_vals) {
+        return None;
+    }
+    if !(x >= x_vals[0] && x <= x_vals[x_vals.len() - 1]) {
+        return None;
+    }
+    Some(interpolate_segment(x_vals, y_vals, x))
+}
+
+/// 分段线性插值，超出范围时按首尾线段外推
+///
+/// 与 [`interpolate_slices`] 相同，但当 `x` 小于最小采样点时沿第一段线段延伸，
+/// 大于最大采样点时沿最后一段线段延伸
+///
+/// # 参数
+/// * `x_vals` - 严格递增的 x 坐标
+/// * `y_vals` - 与 `x_vals` 一一对应的 y 坐标
+/// * `x` - 要查询的 x 坐标
+///
+/// # 返回值
+/// 返回插值或外推结果；切片无效或 `x` 为 `NaN` 时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// let xs = [0.0, 10.0, 20.0];
+/// let ys = [0.0, 100.0, 50.0];
+/// assert_eq!(math::interpolate_extrapolate(&xs, &ys, -5.0), Some(-50.0));
+/// assert_eq!(math::interpolate_extrapolate(&xs, &ys, 30.0), Some(0.0));
+/// ```
+pub fn interpolate_extrapolate(x_vals: &[f64], y_vals: &[f64], x: f64) -> Option<f64> {
+    if !is_valid_lookup_table(x_vals, y_vals) || x.is_nan() {
+        return None;
+    }
+    Some(interpolate_segment(x_vals, y_vals, x))
+}
+
+/// 检查查找表长度一致、至少两个点且 x 坐标严格递增
+fn is_valid_lookup_table(x_vals: &[f64], y_vals: &[f64]) -> bool {
+    x_vals.len() == y_vals.len() && x_vals.len() >= 2 && x_vals.windows(2).all(|w| w[0] < w[1])
+}
+
+/// 在 `x` 所在（或最近）的线段上做线性插值，调用前需保证查找表有效
+fn interpolate_segment(x_vals: &[f64], y_vals: &[f64], x: f64) -> f64 {
+    let upper = x_vals
+        .partition_point(|&v| v < x)
+        .clamp(1, x_vals.len() - 1);
+    let (x0, x1) = (x_vals[upper - 1], x_vals[upper]);
+    let (y0, y1) = (y_vals[upper - 1], y_vals[upper]);
+    if x == x1 {
+        return y1;
+    }
+    y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+}
+
+/// 带溢出检查的 `i64` 加法
+///
+/// # 参数
+/// * `a` - 第一个加数
+/// * `b` - 第二个加数
+///
+/// # 返回值
+/// 返回 两个数的和；结果溢出时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::safe_add_i64(1, 2), Some(3));
+/// assert_eq!(math::safe_add_i64(i64::MAX, 1), None);
+/// ```
+pub fn safe_add_i64(a: i64, b: i64) -> Option<i64> {
+    a.checked_add(b)
+}
+
+/// 带溢出检查的 `i64` 减法
+///
+/// # 参数
+/// * `a` - 被减数
+/// * `b` - 减数
+///
+/// # 返回值
+/// 返回 `a` 减去 `b` 的差；结果溢出时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::safe_sub_i64(5, 8), Some(-3));
+/// assert_eq!(math::safe_sub_i64(i64::MIN, 1), None);
+/// ```
+pub fn safe_sub_i64(a: i64, b: i64) -> Option<i64> {
+    a.checked_sub(b)
+}
+
+/// 带溢出检查的 `i64` 乘法
+///
+/// # 参数
+/// * `a` - 第一个乘数
+/// * `b` - 第二个乘数
+///
+/// # 返回值
+/// 返回 两个数的积；结果溢出时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::safe_mul_i64(-4, 5), Some(-20));
+/// assert_eq!(math::safe_mul_i64(i64::MAX, 2), None);
+/// ```
+pub fn safe_mul_i64(a: i64, b: i64) -> Option<i64> {
+    a.checked_mul(b)
+}
+
+/// 带溢出检查的 `u64` 加法
+///
+/// # 参数
+/// * `a` - 第一个加数
+/// * `b` - 第二个加数
+///
+/// # 返回值
+/// 返回 两个数的和；结果溢出时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::safe_add_u64(1, 2), Some(3));
+/// assert_eq!(math::safe_add_u64(u64::MAX, 1), None);
+/// ```
+pub fn safe_add_u64(a: u64, b: u64) -> Option<u64> {
+    a.checked_add(b)
+}
+
+/// 带溢出检查的 `u64` 减法
+///
+/// # 参数
+/// * `a` - 被减数
+/// * `b` - 减数
+///
+/// # 返回值
+/// 返回 `a` 减去 `b` 的差；结果为负数（下溢）时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::safe_sub_u64(8, 5), Some(3));
+/// assert_eq!(math::safe_sub_u64(3, 5), None);
+/// ```
+pub fn safe_sub_u64(a: u64, b: u64) -> Option<u64> {
+    a.checked_sub(b)
+}
+
+/// 带溢出检查的 `u64` 乘法
+///
+/// # 参数
+/// * `a` - 第一个乘数
+/// * `b` - 第二个乘数
+///
+/// # 返回值
+/// 返回 两个数的积；结果溢出时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::safe_mul_u64(4, 5), Some(20));
+/// assert_eq!(math::safe_mul_u64(u64::MAX, 2), None);
+/// ```
+pub fn safe_mul_u64(a: u64, b: u64) -> Option<u64> {
+    a.checked_mul(b)
+}
+
+/// 饱和 `i64` 加法
+///
+/// 溢出时不回绕，而是停留在 `i64::MAX` 或 `i64::MIN`
+///
+/// # 参数
+/// * `a` - 第一个加数
+/// * `b` - 第二个加数
+///
+/// # 返回值
+/// 返回两个数的和，超出范围时返回对应的边界值
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::saturating_add_i64(1, 2), 3);
+/// assert_eq!(math::saturating_add_i64(i64::MAX, 1), i64::MAX);
+/// assert_eq!(math::saturating_add_i64(i64::MIN, -1), i64::MIN);
+/// ```
+pub fn saturating_add_i64(a: i64, b: i64) -> i64 {
+    a.saturating_add(b)
+}
+
+/// 饱和 `i64` 乘法
+///
+/// 溢出时不回绕，而是停留在 `i64::MAX` 或 `i64::MIN`
+///
+/// # 参数
+/// * `a` - 第一个乘数
+/// * `b` - 第二个乘数
+///
+/// # 返回值
+/// 返回两个数的积，超出范围时返回对应的边界值
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::saturating_mul_i64(-4, 5), -20);
+/// assert_eq!(math::saturating_mul_i64(i64::MAX, 2), i64::MAX);
+/// assert_eq!(math::saturating_mul_i64(i64::MIN, 2), i64::MIN);
+/// ```
+pub fn saturating_mul_i64(a: i64, b: i64) -> i64 {
+    a.saturating_mul(b)
+}
+
+/// 安全地将 `f64` 转换为 `i64`
+///
+/// `value as i64` 在越界时会静默饱和到边界值、NaN 会变为 0，此函数改为返回 `None`
+///
+/// # 参数
+/// * `value` - 要转换的浮点数
+///
+/// # 返回值
+/// 返回向零截断后的整数；`value` 为 NaN、无穷大或截断后超出 `i64` 范围时返回 `None`
+///
+/// # 注意
+/// `i64::MAX as f64` 会舍入为 2^63，已超出 `i64` 范围，因此返回 `None`；
+/// 可以精确转换的最大浮点数为 `9223372036854774784.0`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::safe_cast_f64_to_i64(-3.9), Some(-3));
+/// assert_eq!(math::safe_cast_f64_to_i64(i64::MIN as f64), Some(i64::MIN));
+/// assert_eq!(math::safe_cast_f64_to_i64(i64::MAX as f64), None);
+/// assert_eq!(math::safe_cast_f64_to_i64(f64::NAN), None);
+/// ```
+pub fn safe_cast_f64_to_i64(value: f64) -> Option<i64> {
+    // 2^63，i64 的取值范围为 [-2^63, 2^63)
+    const LIMIT: f64 = 9_223_372_036_854_775_808.0;
+    // NaN 不在任何范围内
+    if !(-LIMIT..LIMIT).contains(&value) {
+        return None;
+    }
+    Some(value as i64)
+}
+
+/// 安全地将 `f64` 转换为 `u64`
+///
+/// # 参数
+/// * `value` - 要转换的浮点数
+///
+/// # 返回值
+/// 返回向零截断后的整数；`value` 为 NaN、无穷大或截断后超出 `u64` 范围时返回 `None`
+///
+/// # 注意
+/// 介于 -1 和 0 之间的数截断后为 0，因此返回 `Some(0)`；`u64::MAX as f64` 会舍入为 2^64，返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::safe_cast_f64_to_u64(42.7), Some(42));
+/// assert_eq!(math::safe_cast_f64_to_u64(-0.5), Some(0));
+/// assert_eq!(math::safe_cast_f64_to_u64(-1.0), None);
+/// assert_eq!(math::safe_cast_f64_to_u64(f64::INFINITY), None);
+/// ```
+pub fn safe_cast_f64_to_u64(value: f64) -> Option<u64> {
+    // 2^64，u64 的取值范围为 [0, 2^64)
+    const LIMIT: f64 = 18_446_744_073_709_551_616.0;
+    if value.is_nan() || value <= -1.0 || value >= LIMIT {
+        return None;
+    }
+    Some(value as u64)
+}
+
+/// 安全地将 `i64` 转换为 `u64`
+///
+/// # 参数
+/// * `value` - 要转换的整数
+///
+/// # 返回值
+/// 返回转换后的值；`value` 为负数时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::safe_cast_i64_to_u64(42), Some(42));
+/// assert_eq!(math::safe_cast_i64_to_u64(-1), None);
+/// ```
+pub fn safe_cast_i64_to_u64(value: i64) -> Option<u64> {
+    u64::try_from(value).ok()
+}
+
+/// 安全地将 `u64` 转换为 `i64`
+///
+/// # 参数
+/// * `value` - 要转换的整数
+///
+/// # 返回值
+/// 返回转换后的值；`value` 大于 `i64::MAX` 时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::safe_cast_u64_to_i64(42), Some(42));
+/// assert_eq!(math::safe_cast_u64_to_i64(u64::MAX), None);
+/// ```
+pub fn safe_cast_u64_to_i64(value: u64) -> Option<i64> {
+    i64::try_from(value).ok()
+}
+
+/// 计算两个数的最大公约数
+///
+/// 使用欧几里得算法计算
+///
+/// # 参数
+/// * `a` - 第一个数
+/// * `b` - 第二个数
+///
+/// # 返回值
+/// 返回 `a` 和 `b` 的最大公约数；`gcd(a, 0) = a`，`gcd(0, 0) = 0`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::gcd(12, 18), 6);
+/// assert_eq!(math::gcd(7, 13), 1);
+/// assert_eq!(math::gcd(5, 0), 5);
+/// ```
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// 计算两个数的最小公倍数
+///
+/// # 参数
+/// * `a` - 第一个数
+/// * `b` - 第二个数
+///
+/// # 返回值
+/// 返回 `a` 和 `b` 的最小公倍数；任一参数为 0 时返回 `Some(0)`，结果溢出 `u64` 时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::lcm(4, 6), Some(12));
+/// assert_eq!(math::lcm(0, 6), Some(0));
+/// assert_eq!(math::lcm(u64::MAX, 2), None);
+/// ```
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// 计算一组数的最大公约数
+///
+/// 利用性质 `gcd(a, b, c) = gcd(gcd(a, b), c)` 依次折叠，时间复杂度为 O(n)
+///
+/// # 参数
+/// * `numbers` - 整数切片
+///
+/// # 返回值
+/// 返回所有元素的最大公约数；空切片返回 `None`，单个元素返回该元素本身
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::gcd_of_slice(&[12, 18, 30]), Some(6));
+/// assert_eq!(math::gcd_of_slice(&[7]), Some(7));
+/// assert_eq!(math::gcd_of_slice(&[]), None);
+/// ```
+pub fn gcd_of_slice(numbers: &[u64]) -> Option<u64> {
+    let (first, rest) = numbers.split_first()?;
+    Some(rest.iter().fold(*first, |acc, &n| gcd(acc, n)))
+}
+
+/// 计算一组数的最小公倍数
+///
+/// 利用性质 `lcm(a, b, c) = lcm(lcm(a, b), c)` 依次折叠，时间复杂度为 O(n)，
+/// 常用于求多个周期性事件的公共时间基准
+///
+/// # 参数
+/// * `numbers` - 整数切片
+///
+/// # 返回值
+/// 返回所有元素的最小公倍数；空切片或任一中间结果溢出 `u64` 时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::lcm_of_slice(&[4, 6, 10]), Some(60));
+/// assert_eq!(math::lcm_of_slice(&[7]), Some(7));
+/// assert_eq!(math::lcm_of_slice(&[]), None);
+/// assert_eq!(math::lcm_of_slice(&[u64::MAX, 2]), None);
+/// ```
+pub fn lcm_of_slice(numbers: &[u64]) -> Option<u64> {
+    let (first, rest) = numbers.split_first()?;
+    rest.iter().try_fold(*first, |acc, &n| lcm(acc, n))
+}
+
+/// 将数据按等宽区间分组，用于计算直方图
+///
+/// 把 `[min, max]` 等分为 `bin_count` 个区间，统计落在每个区间内的值的数量。
+/// 区间为左闭右开，最后一个区间为闭区间，因此等于最大值的元素会落在最后一个区间
+///
+/// # 参数
+/// * `values` - 数据
+/// * `bin_count` - 区间数量
+///
+/// # 返回值
+/// 返回 `(区间起点, 区间终点, 数量)` 组成的数组；输入为空或 `bin_count` 为 0 时返回空数组
+///
+/// # 注意
+/// * `NaN` 和无穷大会被忽略
+/// * 所有值都相等时，区间范围扩展为 `[value - 0.5, value + 0.5]`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// let bins = math::bin_values(&[1.0, 2.0, 2.5, 4.0, 5.0], 2);
+/// assert_eq!(bins, vec![(1.0, 3.0, 3), (3.0, 5.0, 2)]);
+/// ```
+pub fn bin_values(values: &[f64], bin_count: usize) -> Vec<(f64, f64, usize)> {
+    let finite: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    if finite.is_empty() || bin_count == 0 {
+        return Vec::new();
+    }
+
+    let mut min = finite.iter().copied().fold(f64::INFINITY, f64::min);
+    let mut max = finite.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if min == max {
+        min -= 0.5;
+        max += 0.5;
+    }
+    let width = (max - min) / bin_count as f64;
+
+    let mut counts = vec![0usize; bin_count];
+    for value in finite {
+        let index = (((value - min) / width) as usize).min(bin_count - 1);
+        counts[index] += 1;
+    }
+
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| {
+            let start = min + width * i as f64;
+            let end = if i + 1 == bin_count {
+                max
+            } else {
+                min + width * (i + 1) as f64
+            };
+            (start, end, count)
+        })
+        .collect()
+}
+
+/// 绘制文本形式的直方图
+///
+/// 每个区间占一行，格式为 `起点 - 终点 | ### 数量`，
+/// 数量最多的区间的条形长度为 `width`，其余按比例缩放，非零数量至少显示一个 `#`
+///
+/// # 参数
+/// * `values` - 数据
+/// * `bin_count` - 区间数量
+/// * `width` - 最长条形的字符数
+///
+/// # 返回值
+/// 返回多行字符串；没有可统计的数据时返回空字符串
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// let chart = math::histogram_ascii(&[1.0, 2.0, 2.5, 4.0, 5.0], 2, 6);
+/// assert_eq!(chart, "1.00 - 3.00 | ###### 3\n3.00 - 5.00 | #### 2");
+/// ```
+pub fn histogram_ascii(values: &[f64], bin_count: usize, width: usize) -> String {
+    let bins = bin_values(values, bin_count);
+    let max_count = bins.iter().map(|(_, _, count)| *count).max().unwrap_or(0);
+
+    let labels: Vec<(String, String)> = bins
+        .iter()
+        .map(|(start, end, _)| (format!("{:.2}", start), format!("{:.2}", end)))
+        .collect();
+    let start_width = labels.iter().map(|(s, _)| s.len()).max().unwrap_or(0);
+    let end_width = labels.iter().map(|(_, e)| e.len()).max().unwrap_or(0);
+
+    bins.iter()
+        .zip(labels.iter())
+        .map(|((_, _, count), (start, end))| {
+            let mut bar_len = (*count as f64 / max_count as f64 * width as f64).round() as usize;
+            if *count > 0 && width > 0 {
+                bar_len = bar_len.max(1);
+            }
+            format!(
+                "{:>sw$} - {:>ew$} | {} {}",
+                start,
+                end,
+                "#".repeat(bar_len),
+                count,
+                sw = start_width,
+                ew = end_width
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// 判断两个浮点数是否足够接近
+///
+/// 与 Python 的 `math.isclose` 语义一致：当
+/// `|a - b| <= max(rel_tol * max(|a|, |b|), abs_tol)` 时认为两者接近
+///
+/// # 参数
+/// * `a` - 第一个数
+/// * `b` - 第二个数
+/// * `rel_tol` - 相对容差，常用 `1e-9`
+/// * `abs_tol` - 绝对容差，与 0 比较时需要设置为正数
+///
+/// # 返回值
+/// 如果两者接近，返回 `true`；否则返回 `false`
+///
+/// # 注意
+/// * `NaN` 与任何值（包括自身）都不接近
+/// * 无穷大只与同号的无穷大接近
+/// * 容差应为非负数
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert!(math::is_close(1.0, 1.0 + 1e-12, 1e-9, 0.0));
+/// assert!(!math::is_close(1.0, 1.1, 1e-9, 0.0));
+/// assert!(!math::is_close(0.0, 1e-12, 1e-9, 0.0));
+/// assert!(math::is_close(0.0, 1e-12, 1e-9, 1e-10));
+/// ```
+pub fn is_close(a: f64, b: f64, rel_tol: f64, abs_tol: f64) -> bool {
+    if a == b {
+        return true;
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return false;
+    }
+    let diff = (a - b).abs();
+    diff <= rel_tol * a.abs().max(b.abs()) || diff <= abs_tol
+}
+
+/// 使用默认容差判断两个浮点数是否足够接近
+///
+/// 等价于 `is_close(a, b, 1e-9, 0.0)`
+///
+/// # 参数
+/// * `a` - 第一个数
+/// * `b` - 第二个数
+///
+/// # 返回值
+/// 如果两者接近，返回 `true`；否则返回 `false`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert!(math::approx_eq(0.1 + 0.2, 0.3));
+/// assert!(!math::approx_eq(0.1, 0.2));
+/// ```
+pub fn approx_eq(a: f64, b: f64) -> bool {
+    is_close(a, b, 1e-9, 0.0)
+}
+
+/// 逐元素判断两个浮点数切片是否足够接近
+///
+/// # 参数
+/// * `a` - 第一个切片
+/// * `b` - 第二个切片
+/// * `rel_tol` - 相对容差
+/// * `abs_tol` - 绝对容差
+///
+/// # 返回值
+/// 如果两个切片长度相同且每对元素都满足 [`is_close`]，返回 `true`；否则返回 `false`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert!(math::is_close_slice(&[0.1 + 0.2, 1.0], &[0.3, 1.0], 1e-9, 0.0));
+/// assert!(!math::is_close_slice(&[1.0], &[1.0, 2.0], 1e-9, 0.0));
+/// ```
+pub fn is_close_slice(a: &[f64], b: &[f64], rel_tol: f64, abs_tol: f64) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .zip(b.iter())
+            .all(|(x, y)| is_close(*x, *y, rel_tol, abs_tol))
+}
+
+/// 基于 Welford 算法的在线方差计算器
+///
+/// 每次只需传入一个新值即可更新平均值和方差，内存占用为 O(1)，
+/// 适用于无法一次性加载到内存的大数据流或实时传感器数据
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math::WelfordVariance;
+///
+/// let mut stats = WelfordVariance::new();
+/// for value in [1.0, 2.0, 3.0, 4.0, 5.0] {
+///     stats.update(value);
+/// }
+/// assert_eq!(stats.count(), 5);
+/// assert_eq!(stats.mean(), 3.0);
+/// assert_eq!(stats.variance(), 2.0);
+/// assert_eq!(stats.sample_variance(), 2.5);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct WelfordVariance {
+    /// 已处理的元素个数
+    count: u64,
+    /// 当前平均值
+    mean: f64,
+    /// 与平均值之差的平方和
+    m2: f64,
+}
+
+impl WelfordVariance {
+    /// 创建一个空的在线方差计算器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 加入一个新值并更新统计结果
+    ///
+    /// # 参数
+    /// * `value` - 新的数值
+    pub fn update(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// 获取已处理的元素个数
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// 获取平均值
+    ///
+    /// # 返回值
+    /// 返回当前平均值，没有元素时返回 0.0
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// 获取总体方差（除以 n）
+    ///
+    /// # 返回值
+    /// 返回总体方差，与 [`variance`] 一致；没有元素时返回 0.0
+    pub fn variance(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        self.m2 / self.count as f64
+    }
+
+    /// 获取样本方差（除以 n-1）
+    ///
+    /// # 返回值
+    /// 返回样本方差，与 [`sample_variance`] 一致；元素少于 2 个时返回 0.0
+    pub fn sample_variance(&self) -> f64 {
+        if self.count < 2 {
+            return 0.0;
+        }
+        self.m2 / (self.count - 1) as f64
+    }
+
+    /// 合并两个计算器的统计结果
+    ///
+    /// 使用 Chan 等人的并行算法，合并结果与把两部分数据依次传入同一个计算器相同，
+    /// 可用于合并多个线程分别处理的数据流
+    ///
+    /// # 参数
+    /// * `a` - 第一个计算器
+    /// * `b` - 第二个计算器
+    ///
+    /// # 返回值
+    /// 返回包含两部分数据的新计算器
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::math::WelfordVariance;
+    ///
+    /// let mut left = WelfordVariance::new();
+    /// let mut right = WelfordVariance::new();
+    /// [1.0, 2.0].iter().for_each(|v| left.update(*v));
+    /// [3.0, 4.0, 5.0].iter().for_each(|v| right.update(*v));
+    ///
+    /// let merged = WelfordVariance::merge(&left, &right);
+    /// assert_eq!(merged.count(), 5);
+    /// assert_eq!(merged.mean(), 3.0);
+    /// assert_eq!(merged.variance(), 2.0);
+    /// ```
+    pub fn merge(a: &WelfordVariance, b: &WelfordVariance) -> WelfordVariance {
+        if a.count == 0 {
+            return *b;
+        }
+        if b.count == 0 {
+            return *a;
+        }
+        let count = a.count + b.count;
+        let delta = b.mean - a.mean;
+        let mean = a.mean + delta * b.count as f64 / count as f64;
+        let m2 = a.m2 + b.m2 + delta * delta * a.count as f64 * b.count as f64 / count as f64;
+        WelfordVariance { count, mean, m2 }
+    }
+}
+
+/// 快速模幂运算
+///
+/// 使用从右到左的二进制平方-乘法计算 `base^exponent mod modulus`，时间复杂度为 O(log exponent)，
+/// 中间结果使用 `u128` 计算，不会溢出
+///
+/// # 参数
+/// * `base` - 底数
+/// * `exponent` - 指数
+/// * `modulus` - 模数
+///
+/// # 返回值
+/// 返回 `base^exponent mod modulus`；`modulus` 为 1 时总是返回 0
+///
+/// # 注意
+/// 如果 `modulus` 为 0，此函数会 panic
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::modular_exponentiation(4, 13, 497), 445);
+/// assert_eq!(math::modular_exponentiation(2, 10, 1000), 24);
+/// assert_eq!(math::modular_exponentiation(7, 0, 13), 1);
+/// ```
+pub fn modular_exponentiation(base: u64, exponent: u64, modulus: u64) -> u64 {
+    assert!(
+        modulus != 0,
+        "modular_exponentiation: modulus must not be zero"
+    );
+    let modulus = modulus as u128;
+    let mut result: u128 = 1 % modulus;
+    let mut base = base as u128 % modulus;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result as u64
+}
+
+/// 计算模逆元
+///
+/// 使用扩展欧几里得算法求 `x`，使得 `a * x ≡ 1 (mod modulus)`
+///
+/// # 参数
+/// * `a` - 要求逆元的数
+/// * `modulus` - 模数
+///
+/// # 返回值
+/// 返回 `[0, modulus)` 范围内的逆元；`gcd(a, modulus) != 1` 或 `modulus` 为 0 时返回 `None`，
+/// `modulus` 为 1 时返回 `Some(0)`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::modular_inverse(3, 11), Some(4)); // 3 * 4 = 12 ≡ 1 (mod 11)
+/// assert_eq!(math::modular_inverse(6, 9), None);     // gcd(6, 9) = 3
+/// ```
+pub fn modular_inverse(a: u64, modulus: u64) -> Option<u64> {
+    if modulus == 0 {
+        return None;
+    }
+    let m = modulus as i128;
+    let (mut old_r, mut r) = (a as i128 % m, m);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+    }
+    if old_r != 1 && modulus != 1 {
+        return None;
+    }
+    Some(old_s.rem_euclid(m) as u64)
+}
+
+/// 矩阵乘法
+///
+/// 使用朴素算法计算 m×n 矩阵 `a` 与 n×p 矩阵 `b` 的乘积，时间复杂度为 O(m * n * p)
+///
+/// # 参数
+/// * `a` - m×n 矩阵，按行存储
+/// * `b` - n×p 矩阵，按行存储
+///
+/// # 返回值
+/// 返回 m×p 的乘积矩阵；以下情况返回 `None`：
+/// * 任一矩阵为空、包含空行或各行长度不一致
+/// * `a` 的列数与 `b` 的行数不相等
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// let a = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+/// let b = vec![vec![5.0, 6.0], vec![7.0, 8.0]];
+/// assert_eq!(
+///     math::matrix_multiply(&a, &b),
+///     Some(vec![vec![19.0, 22.0], vec![43.0, 50.0]])
+/// );
+/// assert_eq!(math::matrix_multiply(&a, &[vec![1.0, 2.0]]), None);
+/// ```
+pub fn matrix_multiply(a: &[Vec<f64>], b: &[Vec<f64>]) -> Option<Vec<Vec<f64>>> {
+    let (_, n) = matrix_dimensions(a)?;
+    let (rows_b, p) = matrix_dimensions(b)?;
+    if n != rows_b {
+        return None;
+    }
+
+    let product = a
+        .iter()
+        .map(|row| {
+            (0..p)
+                .map(|j| {
+                    row.iter()
+                        .zip(b.iter())
+                        .map(|(x, b_row)| x * b_row[j])
+                        .sum()
+                })
+                .collect()
+        })
+        .collect();
+    Some(product)
+}
+
+/// 矩阵转置
+///
+/// # 参数
+/// * `m` - 按行存储的矩阵
+///
+/// # 返回值
+/// 返回转置后的矩阵，原矩阵的第 i 行第 j 列变为第 j 行第 i 列；空矩阵返回空数组
+///
+/// # 注意
+/// 输入应为各行长度相同的矩阵；如果各行长度不一致，以最短行的长度作为列数，多余的元素被忽略
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// let m = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]];
+/// assert_eq!(
+///     math::matrix_transpose(&m),
+///     vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]
+/// );
+/// ```
+pub fn matrix_transpose(m: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let cols = m.iter().map(|row| row.len()).min().unwrap_or(0);
+    (0..cols)
+        .map(|j| m.iter().map(|row| row[j]).collect())
+        .collect()
+}
+
+/// 生成 n 阶单位矩阵
+///
+/// # 参数
+/// * `n` - 矩阵阶数
+///
+/// # 返回值
+/// 返回主对角线为 1、其余元素为 0 的 n×n 矩阵；`n` 为 0 时返回空数组
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(
+///     math::identity_matrix(2),
+///     vec![vec![1.0, 0.0], vec![0.0, 1.0]]
+/// );
+/// ```
+pub fn identity_matrix(n: usize) -> Vec<Vec<f64>> {
+    (0..n)
+        .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect()
+}
+
+/// 获取矩阵的行数和列数，矩阵为空、包含空行或各行长度不一致时返回 `None`
+fn matrix_dimensions(m: &[Vec<f64>]) -> Option<(usize, usize)> {
+    let cols = m.first()?.len();
+    if cols == 0 || m.iter().any(|row| row.len() != cols) {
+        return None;
+    }
+    Some((m.len(), cols))
+}
+
+/// 统计二进制表示中 1 的个数（popcount）
+///
+/// # 参数
+/// * `n` - 非负整数
+///
+/// # 返回值
+/// 返回 `n` 的二进制表示中 1 的个数
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::count_set_bits(0b1011), 3);
+/// assert_eq!(math::count_set_bits(u64::MAX), 64);
+/// ```
+pub fn count_set_bits(n: u64) -> u32 {
+    n.count_ones()
+}
+
+/// 统计二进制表示末尾连续 0 的个数
+///
+/// # 参数
+/// * `n` - 非负整数
+///
+/// # 返回值
+/// 返回最低位起连续 0 的个数；`n` 为 0 时返回 64
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::count_trailing_zeros(0b1000), 3);
+/// assert_eq!(math::count_trailing_zeros(0), 64);
+/// ```
+pub fn count_trailing_zeros(n: u64) -> u32 {
+    n.trailing_zeros()
+}
+
+/// 统计 64 位二进制表示开头连续 0 的个数
+///
+/// # 参数
+/// * `n` - 非负整数
+///
+/// # 返回值
+/// 返回最高位起连续 0 的个数；`n` 为 0 时返回 64
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::count_leading_zeros(1), 63);
+/// assert_eq!(math::count_leading_zeros(u64::MAX), 0);
+/// ```
+pub fn count_leading_zeros(n: u64) -> u32 {
+    n.leading_zeros()
+}
+
+/// 计算表示一个数所需的二进制位数
+///
+/// # 参数
+/// * `n` - 非负整数
+///
+/// # 返回值
+/// 返回 `64 - count_leading_zeros(n)`；`n` 为 0 时返回 0
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::bit_length(255), 8);
+/// assert_eq!(math::bit_length(256), 9);
+/// assert_eq!(math::bit_length(0), 0);
+/// ```
+pub fn bit_length(n: u64) -> u32 {
+    u64::BITS - n.leading_zeros()
+}
+
+/// 反转最低 `bit_width` 位的二进制顺序
+///
+/// 常用于 FFT 的位反转置换、CRC 计算等场景
+///
+/// # 参数
+/// * `n` - 非负整数
+/// * `bit_width` - 参与反转的位数，大于 64 时按 64 处理
+///
+/// # 返回值
+/// 返回最低 `bit_width` 位反转后的结果，高于 `bit_width` 的位被丢弃；`bit_width` 为 0 时返回 0
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::bit_reverse(0b0001, 4), 0b1000);
+/// assert_eq!(math::bit_reverse(0b1101, 4), 0b1011);
+/// assert_eq!(math::bit_reverse(1, 64), 1 << 63);
+/// ```
+pub fn bit_reverse(n: u64, bit_width: u8) -> u64 {
+    let width = u32::from(bit_width).min(u64::BITS);
+    if width == 0 {
+        return 0;
+    }
+    n.reverse_bits() >> (u64::BITS - width)
+}
+
+/// 计算阶乘 n!
+///
+/// # 参数
+/// * `n` - 非负整数
+///
+/// # 返回值
+/// 返回 `n!`；结果超出 `u64` 范围（`n > 20`）时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::factorial(0), Some(1));
+/// assert_eq!(math::factorial(5), Some(120));
+/// assert_eq!(math::factorial(21), None);
+/// ```
+pub fn factorial(n: u32) -> Option<u64> {
+    (1..=u64::from(n)).try_fold(1u64, |acc, i| acc.checked_mul(i))
+}
+
+/// 计算组合数 C(n, k)
+///
+/// # 参数
+/// * `n` - 元素总数
+/// * `k` - 选取的元素个数
+///
+/// # 返回值
+/// 返回从 `n` 个元素中选取 `k` 个的组合数，`k > n` 时返回 `Some(0)`；结果超出 `u64` 范围时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::binomial_coefficient(5, 2), Some(10));
+/// assert_eq!(math::binomial_coefficient(5, 6), Some(0));
+/// assert_eq!(math::binomial_coefficient(100, 50), None);
+/// ```
+pub fn binomial_coefficient(n: u32, k: u32) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    let mut result: u128 = 1;
+    for i in 0..k {
+        // 每一步的结果都是组合数 C(n, i + 1)，因此除法总能整除
+        result = result * u128::from(n - i) / u128::from(i + 1);
+        if result > u128::from(u64::MAX) {
+            return None;
+        }
+    }
+    Some(result as u64)
+}
+
+/// 依次生成 {0, 1, ..., n-1} 的所有 k 元组合
+///
+/// 使用 Gosper's hack 在位掩码上枚举，按余字典序（colexicographic，即按组合中最大元素优先比较）
+/// 生成，第一个组合为 `[0, 1, ..., k-1]`，最后一个为 `[n-k, ..., n-1]`。
+/// 以迭代器形式返回，不会一次性生成全部组合
+///
+/// # 参数
+/// * `n` - 元素个数，不能超过 64
+/// * `k` - 每个组合的元素个数
+///
+/// # 返回值
+/// 返回组合迭代器，每个组合为升序排列的 `Vec<u32>`，共 `C(n, k)` 个；
+/// `k` 为 0 时只生成一个空组合，`k > n` 时不生成任何组合
+///
+/// # 注意
+/// 如果 `n` 大于 64，此函数会 panic
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// let combinations: Vec<Vec<u32>> = math::combination_iter(4, 2).collect();
+/// assert_eq!(
+///     combinations,
+///     vec![
+///         vec![0, 1],
+///         vec![0, 2],
+///         vec![1, 2],
+///         vec![0, 3],
+///         vec![1, 3],
+///         vec![2, 3],
+///     ]
+/// );
+/// ```
+pub fn combination_iter(n: u32, k: u32) -> impl Iterator<Item = Vec<u32>> {
+    assert!(n <= 64, "combination_iter: n must not exceed 64");
+    let limit: u128 = 1 << n;
+    let first = (k <= n).then(|| (1u128 << k) - 1);
+    std::iter::successors(first, move |&mask| {
+        if mask == 0 {
+            return None;
+        }
+        let lowest = mask & mask.wrapping_neg();
+        let ripple = mask + lowest;
+        let next = (((ripple ^ mask) >> 2) / lowest) | ripple;
+        (next < limit).then_some(next)
+    })
+    .map(move |mask| (0..n).filter(|i| (mask >> i) & 1 == 1).collect())
+}
+
+/// 依次生成给定元素的所有排列
+///
+/// 使用 Heap 算法，相邻两个排列之间只交换一对元素，第一个排列为输入本身。
+/// 以迭代器形式返回，不会一次性生成全部排列
+///
+/// # 参数
+/// * `items` - 要排列的元素
+///
+/// # 返回值
+/// 返回排列迭代器，共 `items.len()!` 个排列（元素重复时排列也会重复）；空切片只生成一个空排列
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// let permutations: Vec<Vec<u32>> = math::permutation_iter(&[1, 2, 3]).collect();
+/// assert_eq!(
+///     permutations,
+///     vec![
+///         vec![1, 2, 3],
+///         vec![2, 1, 3],
+///         vec![3, 1, 2],
+///         vec![1, 3, 2],
+///         vec![2, 3, 1],
+///         vec![3, 2, 1],
+///     ]
+/// );
+/// ```
+pub fn permutation_iter(items: &[u32]) -> impl Iterator<Item = Vec<u32>> {
+    HeapPermutations {
+        items: items.to_vec(),
+        counters: vec![0; items.len()],
+        index: 1,
+        started: false,
+    }
+}
+
+/// Heap 算法的迭代实现
+struct HeapPermutations {
+    /// 当前排列
+    items: Vec<u32>,
+    /// 每一层的交换计数
+    counters: Vec<usize>,
+    /// 当前处理的层
+    index: usize,
+    /// 是否已经输出第一个排列
+    started: bool,
+}
+
+impl Iterator for HeapPermutations {
+    type Item = Vec<u32>;
+
+    fn next(&mut self) -> Option<Vec<u32>> {
+        if !self.started {
+            self.started = true;
+            return Some(self.items.clone());
+        }
+        while self.index < self.items.len() {
+            if self.counters[self.index] < self.index {
+                let swap_with = if self.index.is_multiple_of(2) {
+                    0
+                } else {
+                    self.counters[self.index]
+                };
+                self.items.swap(swap_with, self.index);
+                self.counters[self.index] += 1;
+                self.index = 1;
+                return Some(self.items.clone());
+            }
+            self.counters[self.index] = 0;
+            self.index += 1;
+        }
+        None
+    }
+}
+
+/// 圆周率 π，即 `std::f64::consts::PI`
+pub const PI: f64 = std::f64::consts::PI;
+
+/// 使用 Leibniz 级数近似计算圆周率
+///
+/// 计算 `π ≈ 4 * Σ (-1)^k / (2k + 1)`（k 从 0 到 `iterations - 1`）。
+/// 该级数收敛很慢，误差约为 `1 / iterations`，主要用于演示；需要精确值时请使用 [`PI`]
+///
+/// # 参数
+/// * `iterations` - 累加的项数
+///
+/// # 返回值
+/// 返回圆周率的近似值，`iterations` 为 0 时返回 0.0
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::approximate_pi(1), 4.0);
+/// assert!((math::approximate_pi(1_000_000) - math::PI).abs() < 1e-6);
+/// ```
+pub fn approximate_pi(iterations: u64) -> f64 {
+    // 从最小的项开始累加，减少浮点舍入误差
+    let sum: f64 = (0..iterations)
+        .rev()
+        .map(|k| {
+            let term = 1.0 / (2 * k + 1) as f64;
+            if k.is_multiple_of(2) {
+                term
+            } else {
+                -term
+            }
+        })
+        .sum();
+    4.0 * sum
+}
+
+/// 使用 Bailey-Borwein-Plouffe 公式计算圆周率的十六进制展开
+///
+/// BBP 公式可以直接计算出第 n 位十六进制数字而无需先计算前面的数字，这里逐位独立计算后拼接
+///
+/// # 参数
+/// * `digits` - 小数点后的十六进制位数
+///
+/// # 返回值
+/// 返回形如 `"3.243F6A88"` 的字符串（十六进制数字为大写），`digits` 为 0 时返回 `"3"`
+///
+/// # 注意
+/// 计算使用 `f64`，位数在一千万以内时结果可靠；每位的计算复杂度约为 O(n log n)
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::approximate_pi_bbp(0), "3");
+/// assert_eq!(math::approximate_pi_bbp(8), "3.243F6A88");
+/// ```
+pub fn approximate_pi_bbp(digits: usize) -> String {
+    let mut result = String::from("3");
+    if digits > 0 {
+        result.push('.');
+    }
+    for position in 0..digits as u64 {
+        let x = 4.0 * bbp_series(1, position)
+            - 2.0 * bbp_series(4, position)
+            - bbp_series(5, position)
+            - bbp_series(6, position);
+        let digit = (x.rem_euclid(1.0) * 16.0).floor() as u32;
+        result.push(
+            std::char::from_digit(digit.min(15), 16)
+                .unwrap()
+                .to_ascii_uppercase(),
+        );
+    }
+    result
+}
+
+/// 计算 BBP 公式中 `Σ 16^(position - k) / (8k + j)` 的小数部分
+fn bbp_series(j: u64, position: u64) -> f64 {
+    let mut sum = 0.0;
+    for k in 0..=position {
+        let denominator = 8 * k + j;
+        let numerator = modular_exponentiation(16, position - k, denominator);
+        sum = (sum + numerator as f64 / denominator as f64).fract();
+    }
+    let mut k = position + 1;
+    let mut scale = 1.0 / 16.0;
+    loop {
+        let term = scale / (8 * k + j) as f64;
+        if term < f64::EPSILON {
+            break;
+        }
+        sum += term;
+        scale /= 16.0;
+        k += 1;
+    }
+    sum.fract()
+}
+
+/// Sigmoid 激活函数
+///
+/// # 参数
+/// * `x` - 输入值
+///
+/// # 返回值
+/// 返回 `1 / (1 + e^(-x))`，取值范围为 (0, 1)，`x` 很大或很小时分别趋近 1.0 和 0.0
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::sigmoid(0.0), 0.5);
+/// assert!((math::sigmoid(2.0) - 0.8807970779778823).abs() < 1e-12);
+/// ```
+pub fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// ReLU 激活函数
+///
+/// # 参数
+/// * `x` - 输入值
+///
+/// # 返回值
+/// 返回 `max(0, x)`；`x` 为 NaN 时返回 0.0
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::relu(-1.0), 0.0);
+/// assert_eq!(math::relu(1.5), 1.5);
+/// ```
+pub fn relu(x: f64) -> f64 {
+    f64::max(0.0, x)
+}
+
+/// 双曲正切激活函数
+///
+/// # 参数
+/// * `x` - 输入值
+///
+/// # 返回值
+/// 返回 `tanh(x)`，取值范围为 (-1, 1)
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::tanh(0.0), 0.0);
+/// assert!((math::tanh(1.0) - 0.7615941559557649).abs() < 1e-12);
+/// ```
+pub fn tanh(x: f64) -> f64 {
+    x.tanh()
+}
+
+/// Leaky ReLU 激活函数
+///
+/// # 参数
+/// * `x` - 输入值
+/// * `alpha` - 负半轴的斜率，通常取 0.01
+///
+/// # 返回值
+/// `x` 大于等于 0 时返回 `x`，否则返回 `alpha * x`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::leaky_relu(2.0, 0.01), 2.0);
+/// assert_eq!(math::leaky_relu(-2.0, 0.01), -0.02);
+/// ```
+pub fn leaky_relu(x: f64, alpha: f64) -> f64 {
+    if x >= 0.0 {
+        x
+    } else {
+        alpha * x
+    }
+}
+
+/// ELU 激活函数
+///
+/// # 参数
+/// * `x` - 输入值
+/// * `alpha` - 负半轴的饱和值，通常取 1.0
+///
+/// # 返回值
+/// `x` 大于等于 0 时返回 `x`，否则返回 `alpha * (e^x - 1)`，在负半轴趋近 `-alpha`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::elu(2.0, 1.0), 2.0);
+/// assert!((math::elu(-1.0, 1.0) - (-0.6321205588285577)).abs() < 1e-12);
+/// ```
+pub fn elu(x: f64, alpha: f64) -> f64 {
+    if x >= 0.0 {
+        x
+    } else {
+        alpha * x.exp_m1()
+    }
+}
+
+/// 生成等比数列
+///
+/// # 参数
+/// * `first` - 首项
+/// * `ratio` - 公比
+/// * `count` - 项数
+///
+/// # 返回值
+/// 返回 `[first, first * ratio, first * ratio^2, ...]`，共 `count` 项；`count` 为 0 时返回空列表
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::geometric_sequence(1.0, 2.0, 5), vec![1.0, 2.0, 4.0, 8.0, 16.0]);
+/// assert!(math::geometric_sequence(1.0, 2.0, 0).is_empty());
+/// ```
+pub fn geometric_sequence(first: f64, ratio: f64, count: usize) -> Vec<f64> {
+    // 逐项调用 powi 而不是累乘，避免误差累积
+    (0..count).map(|i| first * ratio.powi(i as i32)).collect()
+}
+
+/// 生成等差数列
+///
+/// # 参数
+/// * `first` - 首项
+/// * `step` - 公差
+/// * `count` - 项数
+///
+/// # 返回值
+/// 返回 `[first, first + step, first + 2 * step, ...]`，共 `count` 项；`count` 为 0 时返回空列表
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::arithmetic_sequence(1.0, 3.0, 4), vec![1.0, 4.0, 7.0, 10.0]);
+/// assert!(math::arithmetic_sequence(1.0, 3.0, 0).is_empty());
+/// ```
+pub fn arithmetic_sequence(first: f64, step: f64, count: usize) -> Vec<f64> {
+    // 逐项计算 first + i * step 而不是累加，避免误差累积
+    (0..count).map(|i| first + step * i as f64).collect()
+}
+
+/// 在区间内生成等间距的数值（类似 NumPy 的 `linspace`）
+///
+/// # 参数
+/// * `start` - 起点
+/// * `end` - 终点
+/// * `count` - 数值个数
+///
+/// # 返回值
+/// 返回从 `start` 到 `end`（两端都包含且精确相等）的 `count` 个等间距数值；
+/// `count` 为 0 时返回空列表，为 1 时只返回 `[start]`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::linspace(0.0, 1.0, 5), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+/// assert_eq!(math::linspace(3.0, 1.0, 3), vec![3.0, 2.0, 1.0]);
+/// ```
+pub fn linspace(start: f64, end: f64, count: usize) -> Vec<f64> {
+    match count {
+        0 => Vec::new(),
+        1 => vec![start],
+        _ => {
+            let intervals = (count - 1) as f64;
+            let mut values: Vec<f64> = (0..count)
+                .map(|i| start + (end - start) * (i as f64 / intervals))
+                .collect();
+            values[count - 1] = end;
+            values
+        }
+    }
+}
+
+/// 计算向量的模（欧几里得长度）
+///
+/// # 参数
+/// * `v` - 向量
+///
+/// # 返回值
+/// 返回各分量平方和的平方根，空向量返回 0.0
+///
+/// # 注意
+/// 使用 `hypot` 逐个累计分量，分量极大或极小时中间结果也不会上溢或下溢
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::vector_magnitude(&[3.0, 4.0]), 5.0);
+/// assert_eq!(math::vector_magnitude(&[]), 0.0);
+/// ```
+pub fn vector_magnitude(v: &[f64]) -> f64 {
+    v.iter().fold(0.0, |acc: f64, &x| acc.hypot(x))
+}
+
+/// 将向量归一化为单位向量
+///
+/// # 参数
+/// * `v` - 向量
+///
+/// # 返回值
+/// 返回方向相同、模为 1 的向量；模为 0（包括空向量）或不是有限数时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::vector_normalize(&[3.0, 4.0]), Some(vec![0.6, 0.8]));
+/// assert_eq!(math::vector_normalize(&[0.0, 0.0]), None);
+/// ```
+pub fn vector_normalize(v: &[f64]) -> Option<Vec<f64>> {
+    let magnitude = vector_magnitude(v);
+    if magnitude == 0.0 || !magnitude.is_finite() {
+        return None;
+    }
+    Some(v.iter().map(|x| x / magnitude).collect())
+}
+
+/// 计算两个向量的和
+///
+/// # 参数
+/// * `a` - 第一个向量
+/// * `b` - 第二个向量
+///
+/// # 返回值
+/// 返回逐分量相加的结果；两个向量长度不同时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::vector_add(&[1.0, 2.0], &[3.0, 4.0]), Some(vec![4.0, 6.0]));
+/// assert_eq!(math::vector_add(&[1.0], &[1.0, 2.0]), None);
+/// ```
+pub fn vector_add(a: &[f64], b: &[f64]) -> Option<Vec<f64>> {
+    if a.len() != b.len() {
+        return None;
+    }
+    Some(a.iter().zip(b).map(|(x, y)| x + y).collect())
+}
+
+/// 将向量乘以标量
+///
+/// # 参数
+/// * `v` - 向量
+/// * `scalar` - 标量
+///
+/// # 返回值
+/// 返回每个分量都乘以 `scalar` 后的向量
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::math;
+///
+/// assert_eq!(math::vector_scale(&[1.0, -2.0], 3.0), vec![3.0, -6.0]);
+/// ```
+pub fn vector_scale(v: &[f64], scalar: f64) -> Vec<f64> {
+    v.iter().map(|x| x * scalar).collect()
+}
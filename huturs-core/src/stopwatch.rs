@@ -19,6 +19,8 @@
 //! println!("耗时: {:?}", stopwatch.elapsed());
 //! ```
 
+#[cfg(feature = "stopwatch-serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::time::{Duration, Instant};
 
 /// 秒表结构体，用于测量时间间隔
@@ -49,6 +51,8 @@ pub struct StopWatch {
     elapsed: Duration,
     /// 是否正在运行
     is_running: bool,
+    /// 已记录的每圈耗时
+    laps: Vec<Duration>,
 }
 
 impl StopWatch {
@@ -67,6 +71,7 @@ impl StopWatch {
             start_time: None,
             elapsed: Duration::ZERO,
             is_running: false,
+            laps: Vec::new(),
         }
     }
 
@@ -86,6 +91,30 @@ impl StopWatch {
         sw
     }
 
+    /// 创建一个已停止的秒表，并将累计耗时设置为给定值
+    ///
+    /// 适用于恢复之前记录的计时结果（如从数据库或配置中读取的耗时），之后可以调用 `start` 继续计时；
+    /// 启用 `stopwatch-serde` 特性时，反序列化也通过此函数恢复秒表
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::stopwatch::StopWatch;
+    /// use std::time::Duration;
+    ///
+    /// let sw = StopWatch::from_elapsed(Duration::from_millis(1500));
+    /// assert!(!sw.is_running());
+    /// assert_eq!(sw.elapsed_millis(), 1500);
+    /// ```
+    pub fn from_elapsed(elapsed: Duration) -> Self {
+        StopWatch {
+            start_time: None,
+            elapsed,
+            is_running: false,
+            laps: Vec::new(),
+        }
+    }
+
     /// 启动秒表
     ///
     /// 如果秒表已经在运行，此方法不会产生任何效果
@@ -130,7 +159,7 @@ impl StopWatch {
 
     /// 重置秒表
     ///
-    /// 将秒表恢复到初始状态，清除所有计时数据
+    /// 将秒表恢复到初始状态，清除所有计时数据和已记录的圈数
     ///
     /// # 示例
     ///
@@ -146,6 +175,7 @@ impl StopWatch {
         self.start_time = None;
         self.elapsed = Duration::ZERO;
         self.is_running = false;
+        self.laps.clear();
     }
 
     /// 获取累计耗时
@@ -255,6 +285,240 @@ impl StopWatch {
     pub fn elapsed_secs_f64(&self) -> f64 {
         self.elapsed().as_secs_f64()
     }
+
+    /// 获取易读的耗时描述
+    ///
+    /// 根据耗时长短选择合适的单位组合：不足 1 分钟只显示秒，不足 1 小时显示分和秒，
+    /// 不足 1 天显示时和分，否则显示天和时；只有不足 10 秒时才显示毫秒精度
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::stopwatch::StopWatch;
+    ///
+    /// let sw = StopWatch::new();
+    /// assert_eq!(sw.elapsed_human_readable(), "0.000 seconds");
+    /// ```
+    pub fn elapsed_human_readable(&self) -> String {
+        let elapsed = self.elapsed();
+        let (days, hours, minutes, seconds) = split_duration(elapsed);
+        let unit = |value: u64, name: &str| {
+            if value == 1 {
+                format!("{} {}", value, name)
+            } else {
+                format!("{} {}s", value, name)
+            }
+        };
+        if days > 0 {
+            format!("{} {}", unit(days, "day"), unit(hours, "hour"))
+        } else if hours > 0 {
+            format!("{} {}", unit(hours, "hour"), unit(minutes, "minute"))
+        } else if minutes > 0 {
+            format!("{} {}", unit(minutes, "minute"), unit(seconds, "second"))
+        } else if seconds < 10 {
+            format!("{}.{:03} seconds", seconds, elapsed.subsec_millis())
+        } else {
+            unit(seconds, "second")
+        }
+    }
+
+    /// 获取紧凑格式的耗时描述
+    ///
+    /// 单位组合规则与 [`StopWatch::elapsed_human_readable`] 相同，输出形如 `2m3s`、`1h2m`
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::stopwatch::StopWatch;
+    ///
+    /// let sw = StopWatch::new();
+    /// assert_eq!(sw.elapsed_short_human_readable(), "0.000s");
+    /// ```
+    pub fn elapsed_short_human_readable(&self) -> String {
+        let elapsed = self.elapsed();
+        let (days, hours, minutes, seconds) = split_duration(elapsed);
+        if days > 0 {
+            format!("{}d{}h", days, hours)
+        } else if hours > 0 {
+            format!("{}h{}m", hours, minutes)
+        } else if minutes > 0 {
+            format!("{}m{}s", minutes, seconds)
+        } else if seconds < 10 {
+            format!("{}.{:03}s", seconds, elapsed.subsec_millis())
+        } else {
+            format!("{}s", seconds)
+        }
+    }
+
+    /// 判断累计耗时是否已超过给定阈值
+    ///
+    /// 秒表正在运行时读取当前耗时，不会停止秒表
+    ///
+    /// # 参数
+    /// * `threshold` - 耗时阈值
+    ///
+    /// # 返回值
+    /// 如果累计耗时大于 `threshold`，返回 `true`；否则返回 `false`
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::stopwatch::StopWatch;
+    /// use std::time::Duration;
+    ///
+    /// let sw = StopWatch::from_elapsed(Duration::from_millis(1500));
+    /// assert!(sw.threshold_exceeded(Duration::from_secs(1)));
+    /// assert!(!sw.threshold_exceeded(Duration::from_secs(2)));
+    /// ```
+    pub fn threshold_exceeded(&self, threshold: Duration) -> bool {
+        self.elapsed() > threshold
+    }
+
+    /// 获取距离给定阈值的剩余时间
+    ///
+    /// 秒表正在运行时读取当前耗时，不会停止秒表
+    ///
+    /// # 参数
+    /// * `threshold` - 耗时阈值
+    ///
+    /// # 返回值
+    /// 如果已超过阈值，返回 `None`；否则返回 `Some(剩余时间)`
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::stopwatch::StopWatch;
+    /// use std::time::Duration;
+    ///
+    /// let sw = StopWatch::from_elapsed(Duration::from_millis(1500));
+    /// assert_eq!(sw.remaining(Duration::from_secs(2)), Some(Duration::from_millis(500)));
+    /// assert_eq!(sw.remaining(Duration::from_secs(1)), None);
+    /// ```
+    pub fn remaining(&self, threshold: Duration) -> Option<Duration> {
+        threshold.checked_sub(self.elapsed())
+    }
+
+    /// 记录一圈
+    ///
+    /// 本圈耗时为当前累计耗时减去之前所有圈的耗时之和，秒表正在运行时不会停止。
+    /// 在已停止的秒表上调用时，记录的是上一圈之后到停止时刻的耗时
+    ///
+    /// # 返回值
+    /// 返回本圈耗时
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::stopwatch::StopWatch;
+    ///
+    /// let mut sw = StopWatch::start_new();
+    /// std::thread::sleep(std::time::Duration::from_millis(10));
+    /// let lap = sw.lap();
+    /// assert!(lap >= std::time::Duration::from_millis(10));
+    /// assert_eq!(sw.elapsed_laps(), &[lap]);
+    /// ```
+    pub fn lap(&mut self) -> Duration {
+        let recorded: Duration = self.laps.iter().sum();
+        let lap = self.elapsed().saturating_sub(recorded);
+        self.laps.push(lap);
+        lap
+    }
+
+    /// 获取已记录的每圈耗时
+    ///
+    /// # 返回值
+    /// 返回按记录顺序排列的每圈耗时，每个元素是相对上一圈的耗时
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::stopwatch::StopWatch;
+    ///
+    /// let sw = StopWatch::new();
+    /// assert!(sw.elapsed_laps().is_empty());
+    /// ```
+    pub fn elapsed_laps(&self) -> &[Duration] {
+        &self.laps
+    }
+
+    /// 获取每圈结束时的累计耗时
+    ///
+    /// # 返回值
+    /// 返回与 [`StopWatch::elapsed_laps`] 等长的数组，第 `i` 个元素为前 `i + 1` 圈耗时之和，
+    /// 即第 `i` 圈结束时相对开始时刻的位置
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::stopwatch::StopWatch;
+    ///
+    /// let mut sw = StopWatch::start_new();
+    /// sw.lap();
+    /// sw.lap();
+    /// sw.stop();
+    /// let splits = sw.split_times();
+    /// assert_eq!(splits.len(), 2);
+    /// assert!(splits[0] <= splits[1]);
+    /// assert!(splits[1] <= sw.elapsed());
+    /// ```
+    pub fn split_times(&self) -> Vec<Duration> {
+        self.laps
+            .iter()
+            .scan(Duration::ZERO, |total, &lap| {
+                *total += lap;
+                Some(*total)
+            })
+            .collect()
+    }
+
+    /// 获取耗时最短的一圈
+    ///
+    /// # 返回值
+    /// 返回最短的单圈耗时，尚未记录任何一圈时返回 `None`
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::stopwatch::StopWatch;
+    ///
+    /// let mut sw = StopWatch::start_new();
+    /// assert_eq!(sw.fastest_lap(), None);
+    /// let lap = sw.lap();
+    /// assert_eq!(sw.fastest_lap(), Some(lap));
+    /// ```
+    pub fn fastest_lap(&self) -> Option<Duration> {
+        self.laps.iter().min().copied()
+    }
+
+    /// 获取耗时最长的一圈
+    ///
+    /// # 返回值
+    /// 返回最长的单圈耗时，尚未记录任何一圈时返回 `None`
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use huturs_core::stopwatch::StopWatch;
+    ///
+    /// let mut sw = StopWatch::start_new();
+    /// assert_eq!(sw.slowest_lap(), None);
+    /// let lap = sw.lap();
+    /// assert_eq!(sw.slowest_lap(), Some(lap));
+    /// ```
+    pub fn slowest_lap(&self) -> Option<Duration> {
+        self.laps.iter().max().copied()
+    }
+}
+
+/// 将时长拆分为 `(天, 时, 分, 秒)`，秒数向下取整
+fn split_duration(duration: Duration) -> (u64, u64, u64, u64) {
+    let total = duration.as_secs();
+    (
+        total / 86_400,
+        total % 86_400 / 3_600,
+        total % 3_600 / 60,
+        total % 60,
+    )
 }
 
 impl Default for StopWatch {
@@ -282,4 +546,69 @@ impl std::fmt::Display for StopWatch {
             elapsed.subsec_millis()
         )
     }
-}
\ No newline at end of file
+}
+
+/// 秒表序列化时的状态快照
+#[cfg(feature = "stopwatch-serde")]
+#[derive(Serialize, Deserialize)]
+struct StopWatchState {
+    /// 累计耗时（纳秒）
+    elapsed_nanos: u128,
+    /// 序列化时是否正在运行
+    is_running: bool,
+    /// 已记录的圈数
+    #[serde(default)]
+    lap_count: usize,
+}
+
+/// 序列化为 `{ "elapsed_nanos": u128, "is_running": bool, "lap_count": usize }`
+///
+/// 正在运行的秒表会记录序列化时刻的累计耗时
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::stopwatch::StopWatch;
+///
+/// let sw = StopWatch::new();
+/// let json = serde_json::to_string(&sw).unwrap();
+/// assert_eq!(json, r#"{"elapsed_nanos":0,"is_running":false,"lap_count":0}"#);
+/// ```
+#[cfg(feature = "stopwatch-serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stopwatch-serde")))]
+impl Serialize for StopWatch {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        StopWatchState {
+            elapsed_nanos: self.elapsed_nanos(),
+            is_running: self.is_running,
+            lap_count: self.laps.len(),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// 从序列化数据中恢复秒表
+///
+/// 由于 `Instant` 无法被反序列化，恢复出的秒表总是处于停止状态，累计耗时为记录的 `elapsed_nanos`，
+/// 之后可以调用 `start` 继续计时。序列化数据中只有圈数而没有每圈耗时，因此恢复出的秒表不包含圈记录
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::stopwatch::StopWatch;
+///
+/// let sw: StopWatch = serde_json::from_str(r#"{"elapsed_nanos":1500000000,"is_running":true}"#).unwrap();
+/// assert!(!sw.is_running());
+/// assert_eq!(sw.elapsed_millis(), 1500);
+/// ```
+#[cfg(feature = "stopwatch-serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stopwatch-serde")))]
+impl<'de> Deserialize<'de> for StopWatch {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let state = StopWatchState::deserialize(deserializer)?;
+        let secs = u64::try_from(state.elapsed_nanos / 1_000_000_000)
+            .map_err(|_| serde::de::Error::custom("elapsed_nanos is out of range"))?;
+        let nanos = (state.elapsed_nanos % 1_000_000_000) as u32;
+        Ok(StopWatch::from_elapsed(Duration::new(secs, nanos)))
+    }
+}
@@ -1,6 +1,8 @@
 //! 字符串工具类模块
 //! 提供各种字符串操作的工具函数
 
+use std::collections::HashSet;
+
 /// 检查字符串是否为空
 ///
 /// # 参数
@@ -82,6 +84,43 @@ pub fn to_lowercase(s: &str) -> String {
     s.to_lowercase()
 }
 
+/// 将字符串转换为句子格式：首字母大写，其余全部小写
+///
+/// 先将整个字符串转换为小写，再将第一个字母转换为大写。
+/// 开头的空白字符、数字和标点保持不变，只处理其后的第一个字母
+///
+/// # 参数
+/// * `s` - 要转换的字符串
+///
+/// # 返回值
+/// 返回转换后的字符串；空字符串返回 `""`
+///
+/// # 注意
+/// 只有整个字符串的第一个字母会被大写，后续句子的首字母同样会被转换为小写
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::sentence_case("hELLO WORLD"), "Hello world");
+/// assert_eq!(str::sentence_case("  hello"), "  Hello");
+/// assert_eq!(str::sentence_case("3 LITTLE PIGS"), "3 Little pigs");
+/// ```
+pub fn sentence_case(s: &str) -> String {
+    let lower = s.to_lowercase();
+    match lower.char_indices().find(|(_, c)| c.is_alphabetic()) {
+        Some((index, c)) => {
+            let mut result = String::with_capacity(lower.len());
+            result.push_str(&lower[..index]);
+            result.extend(c.to_uppercase());
+            result.push_str(&lower[index + c.len_utf8()..]);
+            result
+        }
+        None => lower,
+    }
+}
+
 /// 去除字符串首尾空白字符
 ///
 /// # 参数
@@ -225,6 +264,93 @@ pub fn ends_with(s: &str, suffix: &str) -> bool {
     s.ends_with(suffix)
 }
 
+/// 检查字符串是否包含任意一个子串
+///
+/// # 参数
+/// * `s` - 要搜索的字符串
+/// * `patterns` - 要查找的子串列表
+///
+/// # 返回值
+/// 只要包含其中一个子串就返回 `true`，找到后立即返回；`patterns` 为空时返回 `false`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert!(str::contains_any("error: disk full", &["warn", "error"]));
+/// assert!(!str::contains_any("all good", &["warn", "error"]));
+/// ```
+pub fn contains_any(s: &str, patterns: &[&str]) -> bool {
+    patterns.iter().any(|pattern| contains(s, pattern))
+}
+
+/// 检查字符串是否包含所有子串
+///
+/// # 参数
+/// * `s` - 要搜索的字符串
+/// * `patterns` - 要查找的子串列表
+///
+/// # 返回值
+/// 只有包含全部子串时才返回 `true`；`patterns` 为空时返回 `true`
+///
+/// # 注意
+/// 每个子串单独查找，子串之间允许重叠，如 `"abc"` 同时包含 `"ab"` 和 `"bc"`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert!(str::contains_all("hello world", &["hello", "world"]));
+/// assert!(!str::contains_all("hello world", &["hello", "rust"]));
+/// ```
+pub fn contains_all(s: &str, patterns: &[&str]) -> bool {
+    patterns.iter().all(|pattern| contains(s, pattern))
+}
+
+/// 检查字符串是否以任意一个前缀开头
+///
+/// # 参数
+/// * `s` - 要检查的字符串
+/// * `prefixes` - 前缀列表
+///
+/// # 返回值
+/// 只要以其中一个前缀开头就返回 `true`；`prefixes` 为空时返回 `false`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert!(str::starts_with_any("https://example.com", &["http://", "https://"]));
+/// assert!(!str::starts_with_any("ftp://example.com", &["http://", "https://"]));
+/// ```
+pub fn starts_with_any(s: &str, prefixes: &[&str]) -> bool {
+    prefixes.iter().any(|prefix| starts_with(s, prefix))
+}
+
+/// 检查字符串是否以任意一个后缀结尾
+///
+/// # 参数
+/// * `s` - 要检查的字符串
+/// * `suffixes` - 后缀列表
+///
+/// # 返回值
+/// 只要以其中一个后缀结尾就返回 `true`；`suffixes` 为空时返回 `false`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert!(str::ends_with_any("photo.JPG", &[".jpg", ".JPG", ".png"]));
+/// assert!(!str::ends_with_any("notes.txt", &[".jpg", ".png"]));
+/// ```
+pub fn ends_with_any(s: &str, suffixes: &[&str]) -> bool {
+    suffixes.iter().any(|suffix| ends_with(s, suffix))
+}
+
 /// 获取字符串长度（字节数）
 ///
 /// # 参数
@@ -396,4 +522,1378 @@ pub fn repeat(s: &str, count: usize) -> String {
 /// ```
 pub fn substring(s: &str, start: usize, end: usize) -> &str {
     &s[start..end]
-}
\ No newline at end of file
+}
+
+/// 获取字符串中第 `index` 个字符
+///
+/// 与字节索引不同，此函数按 Unicode 标量值（`char`）计数，不会因为落在多字节字符中间而 panic
+///
+/// # 参数
+/// * `s` - 原始字符串
+/// * `index` - 字符序号，从 0 开始
+///
+/// # 返回值
+/// 返回 `Some(字符)`；如果 `index` 大于等于字符数量，返回 `None`
+///
+/// # 注意
+/// * 需要从头遍历字符串，时间复杂度为 O(n)；如果需要频繁按序号访问，
+///   建议先用 `s.chars().collect::<Vec<char>>()` 转换后再索引，或使用 `char_indices` 顺序遍历
+/// * 按 `char` 而非字素簇计数，由多个码点组成的 emoji（如带肤色或 ZWJ 连接的表情）会占用多个序号
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::char_at("hello", 1), Some('e'));
+/// assert_eq!(str::char_at("你好世界", 2), Some('世'));
+/// assert_eq!(str::char_at("hi", 2), None);
+/// ```
+pub fn char_at(s: &str, index: usize) -> Option<char> {
+    s.chars().nth(index)
+}
+
+/// 获取字符串中第 `index` 个字符，越界时返回默认值
+///
+/// # 参数
+/// * `s` - 原始字符串
+/// * `index` - 字符序号，从 0 开始
+/// * `default` - 越界时返回的默认字符
+///
+/// # 返回值
+/// 返回第 `index` 个字符；如果 `index` 大于等于字符数量，返回 `default`
+///
+/// # 注意
+/// 与 [`char_at`] 相同，时间复杂度为 O(n)
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::char_at_or("hello", 0, '?'), 'h');
+/// assert_eq!(str::char_at_or("hello", 10, '?'), '?');
+/// ```
+pub fn char_at_or(s: &str, index: usize, default: char) -> char {
+    char_at(s, index).unwrap_or(default)
+}
+
+/// 在指定的字符位置插入字符串
+///
+/// # 参数
+/// * `s` - 原始字符串
+/// * `index` - 插入位置的字符序号，从 0 开始；等于字符数量时表示追加到末尾
+/// * `insert` - 要插入的字符串
+///
+/// # 返回值
+/// 返回插入后的新字符串；如果 `index` 大于字符数量，返回 `None`
+///
+/// # 注意
+/// 按 Unicode 标量值（`char`）计数，需要从头遍历字符串，时间复杂度为 O(n)
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::insert_at_char("hello", 5, "!"), Some(String::from("hello!")));
+/// assert_eq!(str::insert_at_char("你好", 1, "，"), Some(String::from("你，好")));
+/// assert_eq!(str::insert_at_char("hi", 3, "!"), None);
+/// ```
+pub fn insert_at_char(s: &str, index: usize, insert: &str) -> Option<String> {
+    let offset = char_byte_offset(s, index)?;
+    let mut result = String::with_capacity(s.len() + insert.len());
+    result.push_str(&s[..offset]);
+    result.push_str(insert);
+    result.push_str(&s[offset..]);
+    Some(result)
+}
+
+/// 删除指定字符范围内的字符
+///
+/// # 参数
+/// * `s` - 原始字符串
+/// * `start` - 起始字符序号（包含）
+/// * `end` - 结束字符序号（不包含）
+///
+/// # 返回值
+/// 返回删除后的新字符串；如果 `start > end` 或 `end` 大于字符数量，返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::delete_char_range("hello world", 5, 11), Some(String::from("hello")));
+/// assert_eq!(str::delete_char_range("你好世界", 1, 3), Some(String::from("你界")));
+/// assert_eq!(str::delete_char_range("abc", 2, 4), None);
+/// ```
+pub fn delete_char_range(s: &str, start: usize, end: usize) -> Option<String> {
+    if start > end {
+        return None;
+    }
+    let end_offset = char_byte_offset(s, end)?;
+    let start_offset = char_byte_offset(s, start)?;
+    let mut result = String::with_capacity(s.len() - (end_offset - start_offset));
+    result.push_str(&s[..start_offset]);
+    result.push_str(&s[end_offset..]);
+    Some(result)
+}
+
+/// 将字符序号转换为字节偏移量，序号等于字符数量时返回字符串长度
+fn char_byte_offset(s: &str, index: usize) -> Option<usize> {
+    s.char_indices()
+        .map(|(offset, _)| offset)
+        .chain(std::iter::once(s.len()))
+        .nth(index)
+}
+
+/// 在第 N 个分隔符处将字符串一分为二
+///
+/// # 参数
+/// * `s` - 要分割的字符串
+/// * `delimiter` - 分隔符
+/// * `n` - 第几个分隔符，从 1 开始计数
+///
+/// # 返回值
+/// 返回 `Some((left, right))`，`left` 为第 N 个分隔符之前的部分，`right` 为其之后的部分；
+/// 如果 `n` 为 0、分隔符为空或分隔符出现次数不足 `n` 次，返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::split_at_nth("a:b:c:d", ":", 2), Some(("a:b", "c:d")));
+/// assert_eq!(str::split_at_nth("a:b", ":", 2), None);
+/// ```
+pub fn split_at_nth<'a>(s: &'a str, delimiter: &str, n: usize) -> Option<(&'a str, &'a str)> {
+    if n == 0 || delimiter.is_empty() {
+        return None;
+    }
+    let (index, _) = s.match_indices(delimiter).nth(n - 1)?;
+    Some((&s[..index], &s[index + delimiter.len()..]))
+}
+
+/// 在第一个分隔符处将字符串一分为二
+///
+/// # 参数
+/// * `s` - 要分割的字符串
+/// * `delimiter` - 分隔符
+///
+/// # 返回值
+/// 返回 `Some((left, right))`，分隔符为空或不存在时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::split_first("key=value=1", "="), Some(("key", "value=1")));
+/// assert_eq!(str::split_first("key", "="), None);
+/// ```
+pub fn split_first<'a>(s: &'a str, delimiter: &str) -> Option<(&'a str, &'a str)> {
+    split_at_nth(s, delimiter, 1)
+}
+
+/// 在最后一个分隔符处将字符串一分为二
+///
+/// # 参数
+/// * `s` - 要分割的字符串
+/// * `delimiter` - 分隔符
+///
+/// # 返回值
+/// 返回 `Some((left, right))`，分隔符为空或不存在时返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::split_last("path/to/file.txt", "/"), Some(("path/to", "file.txt")));
+/// assert_eq!(str::split_last("file.txt", "/"), None);
+/// ```
+pub fn split_last<'a>(s: &'a str, delimiter: &str) -> Option<(&'a str, &'a str)> {
+    if delimiter.is_empty() {
+        return None;
+    }
+    s.rsplit_once(delimiter)
+}
+
+/// 获取字符串的前 N 个字符
+///
+/// # 参数
+/// * `s` - 原始字符串
+/// * `n` - 字符个数（按 Unicode 标量值计数，而非字节）
+///
+/// # 返回值
+/// 返回前 `n` 个字符组成的字符串切片，`n` 超过字符串长度时返回整个字符串
+///
+/// # 注意
+/// 需要逐个扫描字符以确定边界，时间复杂度为 O(n)
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::first_n_chars("hello", 3), "hel");
+/// assert_eq!(str::first_n_chars("你好世界", 2), "你好");
+/// assert_eq!(str::first_n_chars("hi", 10), "hi");
+/// ```
+pub fn first_n_chars(s: &str, n: usize) -> &str {
+    match s.char_indices().nth(n) {
+        Some((index, _)) => &s[..index],
+        None => s,
+    }
+}
+
+/// 获取字符串的后 N 个字符
+///
+/// # 参数
+/// * `s` - 原始字符串
+/// * `n` - 字符个数（按 Unicode 标量值计数，而非字节）
+///
+/// # 返回值
+/// 返回后 `n` 个字符组成的字符串切片，`n` 超过字符串长度时返回整个字符串
+///
+/// # 注意
+/// 需要从末尾逐个扫描字符以确定边界，时间复杂度为 O(n)
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::last_n_chars("hello", 3), "llo");
+/// assert_eq!(str::last_n_chars("你好世界", 2), "世界");
+/// assert_eq!(str::last_n_chars("hi", 10), "hi");
+/// ```
+pub fn last_n_chars(s: &str, n: usize) -> &str {
+    if n == 0 {
+        return "";
+    }
+    match s.char_indices().nth_back(n - 1) {
+        Some((index, _)) => &s[index..],
+        None => s,
+    }
+}
+
+/// 根据姓名生成首字母缩写
+///
+/// # 参数
+/// * `name` - 完整姓名，各部分以空白字符分隔
+///
+/// # 返回值
+/// 返回每个部分首字符的大写形式拼接而成的字符串，空字符串返回空字符串
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::abbreviate_initials("John Doe"), "JD");
+/// assert_eq!(str::abbreviate_initials("john william smith"), "JWS");
+/// assert_eq!(str::abbreviate_initials("Madonna"), "M");
+/// ```
+pub fn abbreviate_initials(name: &str) -> String {
+    name.split_whitespace()
+        .filter_map(|word| word.chars().next())
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// 将姓名中间部分缩写为首字母加句点
+///
+/// # 参数
+/// * `name` - 完整姓名，各部分以空白字符分隔
+///
+/// # 返回值
+/// 保留第一个和最后一个部分不变，中间部分缩写为首字符加 `.`，各部分以单个空格连接；
+/// 只有一到两个部分时原样返回（空白被规整为单个空格），空字符串返回空字符串
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::abbreviate_middle_names("John William Smith"), "John W. Smith");
+/// assert_eq!(str::abbreviate_middle_names("John Doe"), "John Doe");
+/// ```
+pub fn abbreviate_middle_names(name: &str) -> String {
+    let words: Vec<&str> = name.split_whitespace().collect();
+    if words.len() <= 2 {
+        return words.join(" ");
+    }
+    let last = words.len() - 1;
+    words
+        .iter()
+        .enumerate()
+        .map(|(index, word)| {
+            if index == 0 || index == last {
+                word.to_string()
+            } else {
+                word.chars()
+                    .next()
+                    .map(|c| format!("{}.", c))
+                    .unwrap_or_default()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// 移除字符串中的所有空白字符
+///
+/// 与只移除首尾空白的 [`trim`] 不同，该函数会移除字符串任意位置上的空白字符
+///
+/// # 参数
+/// * `s` - 原始字符串
+///
+/// # 返回值
+/// 返回移除所有满足 `char::is_whitespace` 的字符后的新字符串
+///
+/// # 注意
+/// 零宽空格（U+200B）不属于 Unicode 空白字符，不会被移除，可使用 [`remove_chars`] 处理
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::compact(" 138 0013\t8000 "), "13800138000");
+/// assert_eq!(str::compact("a\nb\u{00A0}c"), "abc");
+/// ```
+pub fn compact(s: &str) -> String {
+    s.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// 移除字符串中出现在指定字符集合内的所有字符
+///
+/// # 参数
+/// * `s` - 原始字符串
+/// * `chars_to_remove` - 要移除的字符集合，其中的每个字符都会被移除
+///
+/// # 返回值
+/// 返回移除指定字符后的新字符串
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::remove_chars("4111-1111 1111-1111", "- "), "4111111111111111");
+/// assert_eq!(str::remove_chars("hello", ""), "hello");
+/// ```
+pub fn remove_chars(s: &str, chars_to_remove: &str) -> String {
+    s.chars()
+        .filter(|c| !chars_to_remove.contains(*c))
+        .collect()
+}
+
+/// 移除字符串中所有的指定字符
+///
+/// # 参数
+/// * `s` - 原始字符串
+/// * `c` - 要移除的字符
+///
+/// # 返回值
+/// 返回移除所有 `c` 后的新字符串
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::remove_char("1,234,567", ','), "1234567");
+/// assert_eq!(str::remove_char("你好，世界", '，'), "你好世界");
+/// ```
+pub fn remove_char(s: &str, c: char) -> String {
+    s.chars().filter(|&ch| ch != c).collect()
+}
+
+/// 只保留字符串中出现在指定字符集合内的字符
+///
+/// # 参数
+/// * `s` - 原始字符串
+/// * `allowed` - 允许保留的字符集合
+///
+/// # 返回值
+/// 返回只包含 `allowed` 中字符的新字符串，字符的相对顺序保持不变
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::keep_only_chars("+86 138-0013-8000", "0123456789"), "8613800138000");
+/// assert_eq!(str::keep_only_chars("hello", ""), "");
+/// ```
+pub fn keep_only_chars(s: &str, allowed: &str) -> String {
+    s.chars().filter(|&c| allowed.contains(c)).collect()
+}
+
+/// 统计指定字符在字符串中出现的次数
+///
+/// # 参数
+/// * `s` - 原始字符串
+/// * `c` - 要统计的字符
+///
+/// # 返回值
+/// 返回 `c` 出现的次数，按 Unicode 标量值而不是字节匹配
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::count_char_occurrences("banana", 'a'), 3);
+/// assert_eq!(str::count_char_occurrences("好好学习", '好'), 2);
+/// assert_eq!(str::count_char_occurrences("banana", 'z'), 0);
+/// ```
+pub fn count_char_occurrences(s: &str, c: char) -> usize {
+    s.chars().filter(|&ch| ch == c).count()
+}
+
+/// 重复字符串指定次数，并在每两份之间插入分隔符
+///
+/// # 参数
+/// * `s` - 要重复的字符串
+/// * `count` - 重复次数
+/// * `separator` - 分隔符
+///
+/// # 返回值
+/// 返回重复后的新字符串，`count` 为 0 时返回空字符串，为 1 时返回 `s` 本身
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::repeat_with_separator("?", 3, ", "), "?, ?, ?");
+/// assert_eq!(str::repeat_with_separator("a", 1, ","), "a");
+/// assert_eq!(str::repeat_with_separator("a", 0, ","), "");
+/// ```
+pub fn repeat_with_separator(s: &str, count: usize, separator: &str) -> String {
+    if count == 0 {
+        return String::new();
+    }
+    let mut result = String::with_capacity(s.len() * count + separator.len() * (count - 1));
+    result.push_str(s);
+    for _ in 1..count {
+        result.push_str(separator);
+        result.push_str(s);
+    }
+    result
+}
+
+/// 将单个字符重复指定次数
+///
+/// # 参数
+/// * `c` - 要重复的字符
+/// * `count` - 重复次数
+///
+/// # 返回值
+/// 返回由 `count` 个 `c` 组成的字符串，`count` 为 0 时返回空字符串
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::repeat_char('=', 5), "=====");
+/// assert_eq!(str::repeat_char('好', 2), "好好");
+/// assert_eq!(str::repeat_char('x', 0), "");
+/// ```
+pub fn repeat_char(c: char, count: usize) -> String {
+    std::iter::repeat_n(c, count).collect()
+}
+
+/// 将单个字符转换为字符串
+///
+/// # 参数
+/// * `c` - 字符
+///
+/// # 返回值
+/// 返回只包含 `c` 的字符串
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::of_char('a'), "a");
+/// ```
+pub fn of_char(c: char) -> String {
+    c.to_string()
+}
+
+/// 生成由 `-` 组成的分隔线
+///
+/// # 参数
+/// * `width` - 分隔线的字符数
+///
+/// # 返回值
+/// 返回由 `width` 个 `-` 组成的字符串
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::separator(4), "----");
+/// ```
+pub fn separator(width: usize) -> String {
+    repeat_char('-', width)
+}
+
+/// 生成由指定字符组成的水平分隔线
+///
+/// # 参数
+/// * `width` - 分隔线的字符数
+/// * `c` - 组成分隔线的字符
+///
+/// # 返回值
+/// 返回由 `width` 个 `c` 组成的字符串
+///
+/// # 注意
+/// `width` 是字符数而不是显示宽度，全角字符（如 `＝`）在终端中通常占两列
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::horizontal_rule(3, '='), "===");
+/// assert_eq!(str::horizontal_rule(3, '─'), "───");
+/// ```
+pub fn horizontal_rule(width: usize, c: char) -> String {
+    repeat_char(c, width)
+}
+
+/// 统计字符串中以空白字符分隔的单词数量
+///
+/// # 参数
+/// * `s` - 要统计的字符串
+///
+/// # 返回值
+/// 返回单词数量，空字符串或空白字符串返回 0
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::count_words("  hello   rust world "), 3);
+/// assert_eq!(str::count_words("   "), 0);
+/// ```
+pub fn count_words(s: &str) -> usize {
+    s.split_whitespace().count()
+}
+
+/// 获取字符串中以空白字符分隔的所有单词
+///
+/// # 参数
+/// * `s` - 原始字符串
+///
+/// # 返回值
+/// 返回按出现顺序排列的单词切片向量
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::words(" hello\trust\nworld "), vec!["hello", "rust", "world"]);
+/// ```
+pub fn words(s: &str) -> Vec<&str> {
+    s.split_whitespace().collect()
+}
+
+/// 获取字符串中去重后的单词（忽略大小写）
+///
+/// # 参数
+/// * `s` - 原始字符串
+///
+/// # 返回值
+/// 返回按首次出现顺序排列的去重单词，保留每个单词首次出现时的原始写法
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::unique_words("the The cat THE cat"), vec!["the", "cat"]);
+/// ```
+pub fn unique_words(s: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    s.split_whitespace()
+        .filter(|word| seen.insert(word.to_lowercase()))
+        .map(|word| word.to_string())
+        .collect()
+}
+
+/// 为整数添加千位分隔符
+///
+/// # 参数
+/// * `n` - 要格式化的整数
+/// * `thousands_sep` - 千位分隔符
+///
+/// # 返回值
+/// 返回从右往左每三位插入一个分隔符后的字符串，负号位于最前面
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::format_number_string(1234567, ','), "1,234,567");
+/// assert_eq!(str::format_number_string(-1234, '_'), "-1_234");
+/// assert_eq!(str::format_number_string(999, ','), "999");
+/// ```
+pub fn format_number_string(n: i64, thousands_sep: char) -> String {
+    let digits = n.unsigned_abs().to_string();
+    let mut result = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+    if n < 0 {
+        result.push('-');
+    }
+    for (index, c) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            result.push(thousands_sep);
+        }
+        result.push(c);
+    }
+    result
+}
+
+/// 将字节数格式化为易读的大小字符串
+///
+/// # 参数
+/// * `bytes` - 字节数
+///
+/// # 返回值
+/// 不足 1024 字节时返回形如 `512 B` 的字符串，否则按 1024 进制换算为
+/// `KB`、`MB`、`GB`、`TB`、`PB`、`EB` 并保留两位小数，如 `1.50 KB`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::format_bytes(512), "512 B");
+/// assert_eq!(str::format_bytes(1536), "1.50 KB");
+/// assert_eq!(str::format_bytes(5 * 1024 * 1024 * 1024), "5.00 GB");
+/// ```
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 6] = ["KB", "MB", "GB", "TB", "PB", "EB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    // 保留两位小数后达到 1024 时进位到下一个单位，避免出现 "1024.00 KB"
+    while (value * 100.0).round() / 100.0 >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.2} {}", value, UNITS[unit])
+}
+
+/// 检查字符串是否表示一个合法的数字
+///
+/// 支持可选的正负号、小数点以及科学计数法，判断规则与 `str::parse::<f64>` 一致
+///
+/// # 参数
+/// * `s` - 要检查的字符串，首尾空白会被忽略
+///
+/// # 返回值
+/// 如果字符串能被解析为 `f64`，返回 `true`；否则返回 `false`
+///
+/// # 注意
+/// `"inf"`、`"infinity"`、`"NaN"` 以及 `".5"`、`"5."` 这类写法同样会被 `f64` 接受
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert!(str::is_numeric("-1.5e10"));
+/// assert!(str::is_numeric("+0.0"));
+/// assert!(!str::is_numeric("1.2.3"));
+/// assert!(!str::is_numeric(""));
+/// ```
+pub fn is_numeric(s: &str) -> bool {
+    s.trim().parse::<f64>().is_ok()
+}
+
+/// 检查字符串是否表示一个整数
+///
+/// # 参数
+/// * `s` - 要检查的字符串，首尾空白会被忽略
+///
+/// # 返回值
+/// 如果字符串由可选的正负号和至少一位数字组成，返回 `true`；否则返回 `false`
+///
+/// # 注意
+/// 不限制数值范围，超出 `i64` 的长整数同样返回 `true`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert!(str::is_integer_str("-42"));
+/// assert!(!str::is_integer_str("4.2"));
+/// assert!(!str::is_integer_str("1e3"));
+/// ```
+pub fn is_integer_str(s: &str) -> bool {
+    let trimmed = s.trim();
+    let digits = trimmed.strip_prefix(['+', '-']).unwrap_or(trimmed);
+    !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+/// 将字符串转换为 UTF-8 字节数组
+///
+/// # 参数
+/// * `s` - 原始字符串
+///
+/// # 返回值
+/// 返回字符串的 UTF-8 编码字节。由于 Rust 字符串总是合法的 UTF-8，
+/// 返回的字节一定可以通过 [`from_utf8_bytes`] 无错误地转换回字符串
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::to_utf8_bytes("hi"), vec![0x68, 0x69]);
+/// assert_eq!(str::to_utf8_bytes("你").len(), 3);
+/// ```
+pub fn to_utf8_bytes(s: &str) -> Vec<u8> {
+    s.as_bytes().to_vec()
+}
+
+/// 将 UTF-8 字节数组转换为字符串
+///
+/// # 参数
+/// * `bytes` - UTF-8 编码的字节
+///
+/// # 返回值
+/// 返回 `Result<String, String>`，字节不是合法的 UTF-8 时，错误信息中包含出错的位置
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::from_utf8_bytes(&[0xE4, 0xBD, 0xA0]), Ok("你".to_string()));
+/// assert!(str::from_utf8_bytes(&[0xFF]).is_err());
+/// ```
+pub fn from_utf8_bytes(bytes: &[u8]) -> Result<String, String> {
+    std::str::from_utf8(bytes)
+        .map(|s| s.to_string())
+        .map_err(|e| format!("Invalid UTF-8 sequence: {}", e))
+}
+
+/// 检查字节数组是否为合法的 UTF-8 编码
+///
+/// # 参数
+/// * `bytes` - 要检查的字节
+///
+/// # 返回值
+/// 如果是合法的 UTF-8 编码，返回 `true`；否则返回 `false`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert!(str::is_valid_utf8("hello".as_bytes()));
+/// assert!(!str::is_valid_utf8(&[0xC3, 0x28]));
+/// ```
+pub fn is_valid_utf8(bytes: &[u8]) -> bool {
+    std::str::from_utf8(bytes).is_ok()
+}
+
+/// 用指定的引号字符包裹字符串
+///
+/// # 参数
+/// * `s` - 原始字符串
+/// * `quote` - 引号字符，如 `'` 或 `"`
+///
+/// # 返回值
+/// 返回首尾加上引号的新字符串，`s` 中已有的引号字符会被转义为两个连续的引号（SQL/CSV 的转义方式）
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::wrap_in_quotes("it's", '\''), "'it''s'");
+/// assert_eq!(str::wrap_in_quotes("name", '"'), "\"name\"");
+/// ```
+pub fn wrap_in_quotes(s: &str, quote: char) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+    result.push(quote);
+    for c in s.chars() {
+        if c == quote {
+            result.push(quote);
+        }
+        result.push(c);
+    }
+    result.push(quote);
+    result
+}
+
+/// 去掉字符串首尾的引号，并还原内部被转义的引号
+///
+/// # 参数
+/// * `s` - 被引号包裹的字符串
+/// * `quote` - 引号字符，如 `'` 或 `"`
+///
+/// # 返回值
+/// 返回去掉首尾引号、并将内部两个连续引号还原为一个后的字符串；
+/// 如果字符串没有以引号开头和结尾，或内部存在未转义的引号，返回 `None`
+///
+/// # 注意
+/// 还原转义引号需要生成新的字符串，因此返回 `String` 而不是切片
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::unwrap_quotes("'it''s'", '\''), Some("it's".to_string()));
+/// assert_eq!(str::unwrap_quotes("'it's'", '\''), None);
+/// assert_eq!(str::unwrap_quotes("plain", '\''), None);
+/// ```
+pub fn unwrap_quotes(s: &str, quote: char) -> Option<String> {
+    let inner = s.strip_prefix(quote)?.strip_suffix(quote)?;
+    let mut result = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c == quote && chars.next() != Some(quote) {
+            return None;
+        }
+        result.push(c);
+    }
+    Some(result)
+}
+
+/// 对字符串进行 HTML 转义
+///
+/// # 参数
+/// * `s` - 原始字符串
+///
+/// # 返回值
+/// 返回将 `&`、`<`、`>`、`"`、`'` 分别替换为 `&amp;`、`&lt;`、`&gt;`、`&quot;`、`&#39;` 后的字符串，
+/// 可安全用于 HTML 文本内容和属性值
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(
+///     str::html_escape("<a href=\"x\">Tom & Jerry's</a>"),
+///     "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+/// );
+/// ```
+pub fn html_escape(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// 还原 HTML 转义的字符串
+///
+/// # 参数
+/// * `s` - 经过 HTML 转义的字符串
+///
+/// # 返回值
+/// 返回将 `&amp;`、`&lt;`、`&gt;`、`&quot;`、`&apos;` 以及十进制（`&#39;`）和十六进制（`&#x27;`）
+/// 数字字符引用还原后的字符串
+///
+/// # 注意
+/// 无法识别的实体（如 `&nbsp;`）或无效的数字字符引用会原样保留
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::html_unescape("Tom &amp; Jerry&#39;s &lt;b&gt;"), "Tom & Jerry's <b>");
+/// assert_eq!(str::html_unescape("&#x4F60;&#22909;"), "你好");
+/// assert_eq!(str::html_unescape("&nbsp;&"), "&nbsp;&");
+/// ```
+pub fn html_unescape(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').and_then(|end| {
+            let c = match &rest[1..end] {
+                "amp" => '&',
+                "lt" => '<',
+                "gt" => '>',
+                "quot" => '"',
+                "apos" => '\'',
+                entity => {
+                    let number = entity.strip_prefix('#')?;
+                    let code = match number.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => number.parse::<u32>().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                result.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// 用 HTML 标签包裹文本
+///
+/// # 参数
+/// * `s` - 文本内容
+/// * `tag` - 标签名，如 `"span"`
+/// * `attributes` - 属性名和属性值的列表，按顺序输出
+///
+/// # 返回值
+/// 返回形如 `<tag attr1="val1">s</tag>` 的字符串，文本内容和属性值会经过 [`html_escape`] 转义
+///
+/// # 注意
+/// 标签名和属性名不会被转义，需要由调用方保证其合法
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(
+///     str::wrap_text_html("a < b", "span", &[("class", "note"), ("title", "\"x\"")]),
+///     "<span class=\"note\" title=\"&quot;x&quot;\">a &lt; b</span>"
+/// );
+/// assert_eq!(str::wrap_text_html("", "p", &[]), "<p></p>");
+/// ```
+pub fn wrap_text_html(s: &str, tag: &str, attributes: &[(&str, &str)]) -> String {
+    let mut result = format!("<{}", tag);
+    for (name, value) in attributes {
+        result.push_str(&format!(" {}=\"{}\"", name, html_escape(value)));
+    }
+    result.push('>');
+    result.push_str(&html_escape(s));
+    result.push_str(&format!("</{}>", tag));
+    result
+}
+
+/// 从文本中提取所有 `http://` 和 `https://` 链接
+///
+/// # 参数
+/// * `s` - 任意文本
+///
+/// # 返回值
+/// 返回按出现顺序排列的链接切片。链接从协议头开始，到下一个空白字符或文本末尾结束，
+/// 末尾的标点符号（如 `.`、`,`、`!`）以及没有配对的右括号会被去掉
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// let text = "文档见 https://docs.rs/huturs, 源码在 (http://github.com/taoes/huturs).";
+/// assert_eq!(
+///     str::extract_urls(text),
+///     vec!["https://docs.rs/huturs", "http://github.com/taoes/huturs"]
+/// );
+/// ```
+pub fn extract_urls(s: &str) -> Vec<&str> {
+    let mut urls = Vec::new();
+    let mut search_from = 0;
+    while let Some(position) = s[search_from..].find("http") {
+        let start = search_from + position;
+        let rest = &s[start..];
+        let scheme_len = if rest.starts_with("https://") {
+            8
+        } else if rest.starts_with("http://") {
+            7
+        } else {
+            search_from = start + 4;
+            continue;
+        };
+        let preceded_by_word = s[..start]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_ascii_alphanumeric());
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let url = trim_url_end(&rest[..end]);
+        if !preceded_by_word && url.len() > scheme_len {
+            urls.push(url);
+        }
+        search_from = start + end.max(scheme_len);
+    }
+    urls
+}
+
+/// 去掉链接末尾的标点符号和没有配对的右括号
+fn trim_url_end(url: &str) -> &str {
+    let mut url = url;
+    loop {
+        let Some(last) = url.chars().next_back() else {
+            return url;
+        };
+        let open = match last {
+            ')' => '(',
+            ']' => '[',
+            '}' => '{',
+            _ => '\0',
+        };
+        let unbalanced = open != '\0' && url.matches(open).count() < url.matches(last).count();
+        if unbalanced || matches!(last, '.' | ',' | ';' | ':' | '!' | '?' | '\'' | '"' | '>') {
+            url = &url[..url.len() - last.len_utf8()];
+        } else {
+            return url;
+        }
+    }
+}
+
+/// 从文本中提取所有电子邮件地址
+///
+/// # 参数
+/// * `s` - 任意文本
+///
+/// # 返回值
+/// 返回按出现顺序排列的邮件地址切片，地址需满足 `用户名@域名.顶级域名` 的形式，
+/// 用户名可包含字母、数字和 `._%+-`，域名可包含字母、数字和 `.-`，顶级域名至少包含两个字母
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// let text = "联系 admin@example.com 或 (support.team@mail.example.org).";
+/// assert_eq!(
+///     str::extract_emails(text),
+///     vec!["admin@example.com", "support.team@mail.example.org"]
+/// );
+/// ```
+pub fn extract_emails(s: &str) -> Vec<&str> {
+    let bytes = s.as_bytes();
+    let is_local = |b: u8| b.is_ascii_alphanumeric() || b"._%+-".contains(&b);
+    let is_domain = |b: u8| b.is_ascii_alphanumeric() || b == b'.' || b == b'-';
+
+    let mut emails = Vec::new();
+    let mut last_end = 0;
+    for (at, _) in s.match_indices('@') {
+        if at < last_end {
+            continue;
+        }
+        let mut start = at;
+        while start > last_end && is_local(bytes[start - 1]) {
+            start -= 1;
+        }
+        while start < at && bytes[start] == b'.' {
+            start += 1;
+        }
+        let mut end = at + 1;
+        while end < bytes.len() && is_domain(bytes[end]) {
+            end += 1;
+        }
+        while end > at + 1 && matches!(bytes[end - 1], b'.' | b'-') {
+            end -= 1;
+        }
+
+        let domain = &s[at + 1..end];
+        let valid_domain = domain.rsplit_once('.').is_some_and(|(name, tld)| {
+            !name.is_empty()
+                && !name.starts_with(['.', '-'])
+                && !domain.contains("..")
+                && tld.len() >= 2
+                && tld.bytes().all(|b| b.is_ascii_alphabetic())
+        });
+        if start < at && valid_domain {
+            emails.push(&s[start..end]);
+            last_end = end;
+        }
+    }
+    emails
+}
+
+/// Unicode 规范化形式
+#[cfg(feature = "str-unicode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "str-unicode")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalizationForm {
+    /// 标准等价合成
+    NFC,
+    /// 标准等价分解
+    NFD,
+    /// 兼容等价合成
+    NFKC,
+    /// 兼容等价分解
+    NFKD,
+}
+
+/// 将字符串转换为指定的 Unicode 规范化形式
+///
+/// 不同来源的文本可能以不同的规范化形式表示同一个字符（如 `é` 既可以是单个码点 U+00E9，
+/// 也可以是 `e` 加组合重音符 U+0301），规范化后再比较可以避免误判
+///
+/// # 参数
+/// * `s` - 原始字符串
+/// * `form` - 目标规范化形式
+///
+/// # 返回值
+/// 返回规范化后的新字符串
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str::{self, NormalizationForm};
+///
+/// let composed = "caf\u{00E9}";
+/// let decomposed = "cafe\u{0301}";
+/// assert_ne!(composed, decomposed);
+/// assert_eq!(str::normalize_unicode(decomposed, NormalizationForm::NFC), composed);
+/// assert_eq!(str::normalize_unicode("ｈｉ", NormalizationForm::NFKC), "hi");
+/// ```
+#[cfg(feature = "str-unicode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "str-unicode")))]
+pub fn normalize_unicode(s: &str, form: NormalizationForm) -> String {
+    use unicode_normalization::UnicodeNormalization;
+
+    match form {
+        NormalizationForm::NFC => s.nfc().collect(),
+        NormalizationForm::NFD => s.nfd().collect(),
+        NormalizationForm::NFKC => s.nfkc().collect(),
+        NormalizationForm::NFKD => s.nfkd().collect(),
+    }
+}
+
+/// 检查字符串是否已经是指定的 Unicode 规范化形式
+///
+/// # 参数
+/// * `s` - 要检查的字符串
+/// * `form` - 规范化形式
+///
+/// # 返回值
+/// 如果字符串规范化后保持不变，返回 `true`；否则返回 `false`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str::{self, NormalizationForm};
+///
+/// assert!(str::is_normalized("caf\u{00E9}", NormalizationForm::NFC));
+/// assert!(!str::is_normalized("cafe\u{0301}", NormalizationForm::NFC));
+/// ```
+#[cfg(feature = "str-unicode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "str-unicode")))]
+pub fn is_normalized(s: &str, form: NormalizationForm) -> bool {
+    use unicode_normalization::{
+        is_nfc_quick, is_nfd_quick, is_nfkc_quick, is_nfkd_quick, IsNormalized,
+    };
+
+    let quick = match form {
+        NormalizationForm::NFC => is_nfc_quick(s.chars()),
+        NormalizationForm::NFD => is_nfd_quick(s.chars()),
+        NormalizationForm::NFKC => is_nfkc_quick(s.chars()),
+        NormalizationForm::NFKD => is_nfkd_quick(s.chars()),
+    };
+    match quick {
+        IsNormalized::Yes => true,
+        IsNormalized::No => false,
+        IsNormalized::Maybe => normalize_unicode(s, form) == s,
+    }
+}
+
+/// 检查字符串是否只包含 ASCII 字符
+///
+/// # 参数
+/// * `s` - 要检查的字符串
+///
+/// # 返回值
+/// 如果所有字符均为 ASCII 字符（码点 0-127），返回 `true`；否则返回 `false`。空字符串返回 `true`
+///
+/// # 注意
+/// 与 `s.is_ascii()` 等价。`validation::is_ascii_str` 用于输入校验，对空字符串返回 `false`，
+/// 两者只在空字符串上结果不同
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert!(str::is_all_ascii("hello"));
+/// assert!(str::is_all_ascii(""));
+/// assert!(!str::is_all_ascii("café"));
+/// ```
+pub fn is_all_ascii(s: &str) -> bool {
+    s.is_ascii()
+}
+
+/// 查找字符串中第一个非 ASCII 字符的位置
+///
+/// # 参数
+/// * `s` - 要检查的字符串
+///
+/// # 返回值
+/// 返回第一个非 ASCII 字符（码点大于 127）的字节偏移量；纯 ASCII 字符串返回 `None`
+///
+/// # 注意
+/// 返回的是字节偏移量而非字符序号，可以直接用于切片，如 `&s[..index]`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::find_first_non_ascii("hello"), None);
+/// assert_eq!(str::find_first_non_ascii("abc你好"), Some(3));
+/// assert_eq!(str::find_first_non_ascii("é"), Some(0));
+/// ```
+pub fn find_first_non_ascii(s: &str) -> Option<usize> {
+    s.bytes().position(|b| !b.is_ascii())
+}
+
+/// 将字符串中的非 ASCII 字符替换为 `?`
+///
+/// # 参数
+/// * `s` - 原始字符串
+///
+/// # 返回值
+/// 返回只包含 ASCII 字符的新字符串，每个非 ASCII 字符替换为一个 `?`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::to_ascii_lossy("café"), "caf?");
+/// assert_eq!(str::to_ascii_lossy("你好 world"), "?? world");
+/// ```
+pub fn to_ascii_lossy(s: &str) -> String {
+    s.chars()
+        .map(|c| if c.is_ascii() { c } else { '?' })
+        .collect()
+}
+
+/// 移除字符串中的所有非 ASCII 字符
+///
+/// # 参数
+/// * `s` - 原始字符串
+///
+/// # 返回值
+/// 返回只保留 ASCII 字符的新字符串
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::strip_non_ascii("café"), "caf");
+/// assert_eq!(str::strip_non_ascii("你好 world"), " world");
+/// ```
+pub fn strip_non_ascii(s: &str) -> String {
+    s.chars().filter(|c| c.is_ascii()).collect()
+}
+
+/// 检查字符串是否为合法的 JSON
+///
+/// # 参数
+/// * `s` - 要检查的字符串
+///
+/// # 返回值
+/// 如果字符串是合法的 JSON（包括对象、数组以及字符串、数字等标量），返回 `true`；否则返回 `false`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert!(str::is_valid_json(r#"{"name": "huturs", "tags": [1, 2]}"#));
+/// assert!(!str::is_valid_json("{name: huturs}"));
+/// ```
+#[cfg(feature = "str-json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "str-json")))]
+pub fn is_valid_json(s: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(s).is_ok()
+}
+
+/// 将 JSON 字符串格式化为缩进两个空格的多行形式
+///
+/// # 参数
+/// * `s` - JSON 字符串
+///
+/// # 返回值
+/// 返回 `Result<String, String>`，成功时包含格式化后的 JSON，JSON 不合法时包含错误信息
+///
+/// # 注意
+/// 除非启用了 serde_json 的 `preserve_order` 特性，对象中的键会按键名重新排序
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(
+///     str::format_json(r#"{"a":[1,2]}"#).unwrap(),
+///     "{\n  \"a\": [\n    1,\n    2\n  ]\n}"
+/// );
+/// assert!(str::format_json("[1,").is_err());
+/// ```
+#[cfg(feature = "str-json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "str-json")))]
+pub fn format_json(s: &str) -> Result<String, String> {
+    let value: serde_json::Value = serde_json::from_str(s).map_err(|e| e.to_string())?;
+    serde_json::to_string_pretty(&value).map_err(|e| e.to_string())
+}
+
+/// 将 JSON 字符串压缩为不含多余空白的单行形式
+///
+/// # 参数
+/// * `s` - JSON 字符串
+///
+/// # 返回值
+/// 返回 `Result<String, String>`，成功时包含压缩后的 JSON，JSON 不合法时包含错误信息
+///
+/// # 注意
+/// 除非启用了 serde_json 的 `preserve_order` 特性，对象中的键会按键名重新排序
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::str;
+///
+/// assert_eq!(str::minify_json("{\n  \"a\": [1, 2]\n}").unwrap(), r#"{"a":[1,2]}"#);
+/// assert!(str::minify_json("{\"a\":}").is_err());
+/// ```
+#[cfg(feature = "str-json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "str-json")))]
+pub fn minify_json(s: &str) -> Result<String, String> {
+    let value: serde_json::Value = serde_json::from_str(s).map_err(|e| e.to_string())?;
+    serde_json::to_string(&value).map_err(|e| e.to_string())
+}
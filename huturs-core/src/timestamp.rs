@@ -1,6 +1,7 @@
 //! 日期工具类模块
 //! 提供日期时间处理相关的工具函数
 
+use chrono::Datelike;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// 获取当前时间戳（秒）
@@ -43,22 +44,105 @@ pub fn current_timestamp_millis() -> u128 {
         .as_millis()
 }
 
-/// 格式化时间戳为日期字符串
+/// 获取当前时间戳（秒），[`current_timestamp`] 的简写
+///
+/// # 返回值
+/// 返回从 Unix 纪元（1970-01-01 00:00:00 UTC）到当前时间的秒数
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::timestamp;
+///
+/// assert!(timestamp::now_secs() > 0);
+/// ```
+pub fn now_secs() -> u64 {
+    current_timestamp()
+}
+
+/// 获取当前时间戳（毫秒），返回 `u64` 以便直接用于大多数接口
+///
+/// # 返回值
+/// 返回从 Unix 纪元（1970-01-01 00:00:00 UTC）到当前时间的毫秒数
+///
+/// # 注意
+/// 毫秒数要到约 2.92 亿年后才会超出 `u64` 范围，届时结果会饱和为 `u64::MAX`；
+/// 需要完整精度时请使用 [`current_timestamp_millis`]
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::timestamp;
+///
+/// let ms = timestamp::now_millis();
+/// assert!(ms / 1000 <= timestamp::now_secs());
+/// ```
+pub fn now_millis() -> u64 {
+    u64::try_from(current_timestamp_millis()).unwrap_or(u64::MAX)
+}
+
+/// 获取当前时间戳（纳秒）
+///
+/// # 返回值
+/// 返回从 Unix 纪元（1970-01-01 00:00:00 UTC）到当前时间的纳秒数
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::timestamp;
+///
+/// assert!(timestamp::now_nanos() > 0);
+/// ```
+pub fn now_nanos() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos()
+}
+
+/// 将时间戳格式化为日期时间字符串
 ///
 /// # 参数
-/// * `timestamp` - 要格式化的时间戳（秒）
+/// * `ts` - 时间戳（秒），按 UTC 时区解释
+/// * `fmt` - chrono 格式字符串，如 `"%Y-%m-%d %H:%M:%S"`、`"%+"`（ISO 8601）
 ///
 /// # 返回值
-/// 返回时间戳的字符串表示
+/// 返回格式化后的字符串；时间戳超出可表示的日期范围或格式字符串无效时返回 `None`
 ///
 /// # 示例
 ///
 /// ```
 /// use huturs_core::timestamp;
 ///
-/// let formatted = timestamp::format_timestamp(1234567890);
-/// assert_eq!(formatted, "1234567890");
+/// assert_eq!(
+///     timestamp::format_timestamp_as_datetime(1234567890, "%Y-%m-%d %H:%M:%S"),
+///     Some(String::from("2009-02-13 23:31:30"))
+/// );
+/// assert_eq!(
+///     timestamp::format_timestamp_as_datetime(0, "%+"),
+///     Some(String::from("1970-01-01T00:00:00+00:00"))
+/// );
+/// assert_eq!(timestamp::format_timestamp_as_datetime(u64::MAX, "%Y"), None);
 /// ```
+pub fn format_timestamp_as_datetime(ts: u64, fmt: &str) -> Option<String> {
+    use chrono::TimeZone;
+    use std::fmt::Write;
+
+    let seconds = i64::try_from(ts).ok()?;
+    let date_time = chrono::Utc.timestamp_opt(seconds, 0).single()?;
+    let mut result = String::new();
+    write!(result, "{}", date_time.format(fmt)).ok()?;
+    Some(result)
+}
+
+/// 将时间戳转换为字符串
+///
+/// 已废弃：此函数只是返回时间戳的十进制字符串，与 `timestamp.to_string()` 相同。
+/// 如需转换为可读的日期时间，请使用 [`format_timestamp_as_datetime`]
+#[deprecated(
+    since = "0.1.0",
+    note = "只返回 `timestamp.to_string()`，请使用 `format_timestamp_as_datetime`"
+)]
 pub fn format_timestamp(timestamp: u64) -> String {
     format!("{}", timestamp)
 }
@@ -241,4 +325,228 @@ pub fn get_hours(timestamp: u64) -> u64 {
 /// ```
 pub fn get_days(timestamp: u64) -> u64 {
     timestamp / 86400
-}
\ No newline at end of file
+}
+
+/// 检查时间戳是否位于指定的时间范围内
+///
+/// # 参数
+/// * `ts` - 要检查的时间戳
+/// * `start` - 范围起点（包含）
+/// * `end` - 范围终点（包含）
+///
+/// # 返回值
+/// 如果 `start <= ts <= end`，返回 `true`；否则返回 `false`
+///
+/// # 注意
+/// 当 `start > end` 时范围为空，总是返回 `false`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::timestamp;
+///
+/// assert!(timestamp::is_within_range(1500, 1000, 2000));
+/// assert!(timestamp::is_within_range(2000, 1000, 2000));
+/// assert!(!timestamp::is_within_range(2001, 1000, 2000));
+/// ```
+pub fn is_within_range(ts: u64, start: u64, end: u64) -> bool {
+    start <= ts && ts <= end
+}
+
+/// 将时间戳限制在指定的时间范围内
+///
+/// # 参数
+/// * `ts` - 原始时间戳
+/// * `start` - 范围起点
+/// * `end` - 范围终点
+///
+/// # 返回值
+/// 早于 `start` 时返回 `start`，晚于 `end` 时返回 `end`，否则原样返回
+///
+/// # 注意
+/// 当 `start > end` 时范围无效，此时总是返回 `start`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::timestamp;
+///
+/// assert_eq!(timestamp::clamp_to_range(500, 1000, 2000), 1000);
+/// assert_eq!(timestamp::clamp_to_range(1500, 1000, 2000), 1500);
+/// assert_eq!(timestamp::clamp_to_range(2500, 1000, 2000), 2000);
+/// ```
+pub fn clamp_to_range(ts: u64, start: u64, end: u64) -> u64 {
+    ts.min(end).max(start)
+}
+
+/// 检查两个时间范围是否有重叠
+///
+/// # 参数
+/// * `s1` - 第一个范围的起点（包含）
+/// * `e1` - 第一个范围的终点（包含）
+/// * `s2` - 第二个范围的起点（包含）
+/// * `e2` - 第二个范围的终点（包含）
+///
+/// # 返回值
+/// 如果两个范围至少共享一个时刻（包括端点相接），返回 `true`；否则返回 `false`
+///
+/// # 注意
+/// 起点大于终点的范围视为空范围，与任何范围都不重叠
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::timestamp;
+///
+/// assert!(timestamp::ranges_overlap(1000, 2000, 1500, 2500));
+/// assert!(timestamp::ranges_overlap(1000, 2000, 2000, 3000));
+/// assert!(!timestamp::ranges_overlap(1000, 2000, 2001, 3000));
+/// ```
+pub fn ranges_overlap(s1: u64, e1: u64, s2: u64, e2: u64) -> bool {
+    s1 <= e1 && s2 <= e2 && s1 <= e2 && s2 <= e1
+}
+
+/// 获取时间戳对应的 ISO 周数（UTC）
+///
+/// # 参数
+/// * `ts` - 时间戳（秒），按 UTC 时区解释
+///
+/// # 返回值
+/// 返回 ISO 8601 周数（1-53），每周从周一开始，每年的第一周是包含该年第一个周四的那一周
+///
+/// # 注意
+/// 时间戳超出可表示的日期范围（约公元 262000 年之后）时，此函数会 panic
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::timestamp;
+///
+/// // 2024-01-15 00:00:00 UTC
+/// assert_eq!(timestamp::timestamp_to_week_number(1705276800), 3);
+/// // 2021-01-01 属于 2020 年的第 53 周
+/// assert_eq!(timestamp::timestamp_to_week_number(1609459200), 53);
+/// ```
+pub fn timestamp_to_week_number(ts: u64) -> u32 {
+    to_datetime_utc(ts).iso_week().week()
+}
+
+/// 获取时间戳对应的星期（UTC）
+///
+/// # 参数
+/// * `ts` - 时间戳（秒），按 UTC 时区解释
+///
+/// # 返回值
+/// 返回星期几，0 表示周一，6 表示周日
+///
+/// # 注意
+/// 时间戳超出可表示的日期范围（约公元 262000 年之后）时，此函数会 panic
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::timestamp;
+///
+/// // 1970-01-01 是周四
+/// assert_eq!(timestamp::timestamp_to_day_of_week(0), 3);
+/// ```
+pub fn timestamp_to_day_of_week(ts: u64) -> u32 {
+    to_datetime_utc(ts).weekday().num_days_from_monday()
+}
+
+/// 获取时间戳对应的一年中的第几天（UTC）
+///
+/// # 参数
+/// * `ts` - 时间戳（秒），按 UTC 时区解释
+///
+/// # 返回值
+/// 返回一年中的第几天（1-366）
+///
+/// # 注意
+/// 时间戳超出可表示的日期范围（约公元 262000 年之后）时，此函数会 panic
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::timestamp;
+///
+/// // 2024-12-31 00:00:00 UTC（闰年）
+/// assert_eq!(timestamp::timestamp_to_day_of_year(1735603200), 366);
+/// ```
+pub fn timestamp_to_day_of_year(ts: u64) -> u32 {
+    to_datetime_utc(ts).ordinal()
+}
+
+/// 获取时间戳对应的月份（UTC）
+///
+/// # 参数
+/// * `ts` - 时间戳（秒），按 UTC 时区解释
+///
+/// # 返回值
+/// 返回月份（1-12）
+///
+/// # 注意
+/// 时间戳超出可表示的日期范围（约公元 262000 年之后）时，此函数会 panic
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::timestamp;
+///
+/// assert_eq!(timestamp::timestamp_to_month(1705276800), 1);
+/// ```
+pub fn timestamp_to_month(ts: u64) -> u32 {
+    to_datetime_utc(ts).month()
+}
+
+/// 获取时间戳对应的年份（UTC）
+///
+/// # 参数
+/// * `ts` - 时间戳（秒），按 UTC 时区解释
+///
+/// # 返回值
+/// 返回年份
+///
+/// # 注意
+/// 时间戳超出可表示的日期范围（约公元 262000 年之后）时，此函数会 panic
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::timestamp;
+///
+/// assert_eq!(timestamp::timestamp_to_year(1705276800), 2024);
+/// ```
+pub fn timestamp_to_year(ts: u64) -> i32 {
+    to_datetime_utc(ts).year()
+}
+
+/// 获取时间戳对应的日（UTC）
+///
+/// # 参数
+/// * `ts` - 时间戳（秒），按 UTC 时区解释
+///
+/// # 返回值
+/// 返回当月的第几天（1-31）
+///
+/// # 注意
+/// 时间戳超出可表示的日期范围（约公元 262000 年之后）时，此函数会 panic
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::timestamp;
+///
+/// assert_eq!(timestamp::timestamp_to_day(1705276800), 15);
+/// ```
+pub fn timestamp_to_day(ts: u64) -> u32 {
+    to_datetime_utc(ts).day()
+}
+
+/// 将时间戳转换为 UTC 日期时间，超出可表示的日期范围时 panic
+fn to_datetime_utc(ts: u64) -> chrono::DateTime<chrono::Utc> {
+    i64::try_from(ts)
+        .ok()
+        .and_then(|seconds| chrono::DateTime::from_timestamp(seconds, 0))
+        .expect("timestamp is out of the representable date range")
+}
@@ -1,3 +1,10 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::time::{SystemTime, UNIX_EPOCH};
+
 pub fn hex_encoding(str: &str) -> String {
     str.chars()
         .map(|c| format!("{:x}", c as u8))
@@ -24,16 +31,20 @@ pub fn hex_decoding(str: &str) -> String {
 /// * `size` - 每页大小
 ///
 /// # 返回值
-/// 返回元组 (起始索引, 结束索引)，索引从0开始
+/// 返回元组 (起始索引, 结束索引)，索引从0开始，区间为左闭右开 `[start, end)`，
+/// 可直接用于切片 `&items[start as usize..end as usize]`
+///
+/// # 注意
+/// 结束索引不会根据总记录数截断，最后一页需要调用者自行与总数取较小值
 ///
 /// # 示例
 /// ```
-/// use huturs_core::util::page_transToStartEnd;
-/// let (start, end) = page_transToStartEnd(2, 10);
+/// use huturs_core::util::page_to_range;
+/// let (start, end) = page_to_range(2, 10);
 /// assert_eq!(start, 10);  // 第2页的起始索引
 /// assert_eq!(end, 20);    // 第2页的结束索引
 /// ```
-pub fn page_transToStartEnd(page: i32, size: i32) -> (i32, i32) {
+pub fn page_to_range(page: i32, size: i32) -> (i32, i32) {
     ((page - 1) * size, page * size)
 }
 
@@ -41,62 +52,74 @@ pub fn page_transToStartEnd(page: i32, size: i32) -> (i32, i32) {
 ///
 /// # 参数
 /// * `total` - 总记录数
-/// * `size` - 每页大小
+/// * `size` - 每页大小，必须大于0
 ///
 /// # 返回值
-/// 返回总页数，向上取整
+/// 返回总页数，向上取整；没有记录时返回0
 ///
 /// # 示例
 /// ```
-/// use huturs_core::util::page_totalPage;
-/// assert_eq!(page_totalPage(10, 3), 4);   // 10条记录，每页3条，共4页
-/// assert_eq!(page_totalPage(9, 3), 3);    // 9条记录，每页3条，共3页
-/// assert_eq!(page_totalPage(20, 3), 7);   // 20条记录，每页3条，共7页
+/// use huturs_core::util::total_pages;
+/// assert_eq!(total_pages(10, 3), 4);   // 10条记录，每页3条，共4页
+/// assert_eq!(total_pages(9, 3), 3);    // 9条记录，每页3条，共3页
+/// assert_eq!(total_pages(0, 3), 0);    // 没有记录
+/// assert_eq!(total_pages(i32::MAX, 10), 214748365);
 /// ```
-pub fn page_totalPage(total: i32, size: i32) -> i32 {
-    (total + size - 1) / size
+pub fn total_pages(total: i32, size: i32) -> i32 {
+    total / size + i32::from(total % size != 0)
 }
 
-/// 生成分页彩虹条页码数组
+/// 生成分页导航中要显示的页码窗口
+///
+/// 窗口尽量以当前页为中心；当前页靠近首页或末页时窗口贴边，
+/// 保证始终显示 `window_size` 个页码（总页数不足时显示全部页码）。
+/// 窗口大小为偶数时，当前页右侧比左侧多显示一页
 ///
 /// # 参数
 /// * `page_no` - 当前页码（从1开始）
-/// * `total_page` - 总页数
-/// * `display_count` - 显示的页码数量
+/// * `total_pages` - 总页数
+/// * `window_size` - 显示的页码数量
 ///
 /// # 返回值
-/// 返回要显示的页码数组
+/// 返回要显示的页码数组，按升序排列；`total_pages` 或 `window_size` 不大于0时返回空数组
 ///
 /// # 示例
 /// ```
-/// use huturs_core::util::page_rainbow;
-/// let result = page_rainbow(5, 20, 6);
-/// assert_eq!(result, vec![3, 4, 5, 6, 7, 8]);
+/// use huturs_core::util::pagination_window;
+/// assert_eq!(pagination_window(5, 20, 6), vec![3, 4, 5, 6, 7, 8]);
+/// assert_eq!(pagination_window(1, 20, 5), vec![1, 2, 3, 4, 5]);
+/// assert_eq!(pagination_window(20, 20, 5), vec![16, 17, 18, 19, 20]);
+/// assert_eq!(pagination_window(2, 3, 5), vec![1, 2, 3]);
+/// assert!(pagination_window(1, -1, 5).is_empty());
 /// ```
-pub fn page_rainbow(page_no: i32, total_page: i32, display_count: i32) -> Vec<i32> {
-    let is_even = (display_count & 1) == 0;
-    let left = display_count >> 1;
-    let mut right = display_count >> 1;
-    let mut length = display_count;
+pub fn pagination_window(page_no: i32, total_pages: i32, window_size: i32) -> Vec<i32> {
+    if total_pages <= 0 || window_size <= 0 {
+        return Vec::new();
+    }
+
+    let is_even = (window_size & 1) == 0;
+    let left = window_size >> 1;
+    let mut right = window_size >> 1;
+    let mut length = window_size;
 
     if is_even {
         right += 1;
     }
 
-    if total_page < display_count {
-        length = total_page;
+    if total_pages < window_size {
+        length = total_pages;
     }
 
     let mut result = Vec::with_capacity(length as usize);
 
-    if total_page >= display_count {
+    if total_pages >= window_size {
         if page_no <= left {
             for i in 0..length {
                 result.push(i + 1);
             }
-        } else if page_no > total_page - right {
+        } else if page_no > total_pages - right {
             for i in 0..length {
-                result.push(i + total_page - display_count + 1);
+                result.push(i + total_pages - window_size + 1);
             }
         } else {
             for i in 0..length {
@@ -111,3 +134,1326 @@ pub fn page_rainbow(page_no: i32, total_page: i32, display_count: i32) -> Vec<i3
 
     result
 }
+
+/// 将页码转换为起始和结束索引
+///
+/// 已废弃，请使用 [`page_to_range`]
+#[deprecated(since = "0.1.0", note = "请使用 `page_to_range`")]
+pub fn page_transToStartEnd(page: i32, size: i32) -> (i32, i32) {
+    page_to_range(page, size)
+}
+
+/// 计算总页数
+///
+/// 已废弃，请使用 [`total_pages`]
+#[deprecated(since = "0.1.0", note = "请使用 `total_pages`")]
+pub fn page_totalPage(total: i32, size: i32) -> i32 {
+    total_pages(total, size)
+}
+
+/// 生成分页彩虹条页码数组
+///
+/// 已废弃，请使用 [`pagination_window`]
+#[deprecated(since = "0.1.0", note = "请使用 `pagination_window`")]
+pub fn page_rainbow(page_no: i32, total_page: i32, display_count: i32) -> Vec<i32> {
+    pagination_window(page_no, total_page, display_count)
+}
+
+// 身份证工具
+
+/// 身份证号码中每一位本体码对应的加权因子
+const CHINESE_ID_WEIGHTS: [u32; 17] = [7, 9, 10, 5, 8, 4, 2, 1, 6, 3, 7, 9, 10, 5, 8, 4, 2];
+
+/// 加权和对 11 取模后对应的校验码
+const CHINESE_ID_CHECK_CODES: [char; 11] = ['1', '0', 'X', '9', '8', '7', '6', '5', '4', '3', '2'];
+
+/// 性别
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gender {
+    /// 男
+    Male,
+    /// 女
+    Female,
+}
+
+/// 从居民身份证号码中解析出的信息
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChineseIdInfo {
+    /// 行政区划代码（前 6 位）
+    pub region_code: String,
+    /// 出生日期 (年, 月, 日)
+    pub birthdate: (u32, u32, u32),
+    /// 顺序码（第 15 ~ 17 位）
+    pub sequence: String,
+    /// 性别，由顺序码最后一位的奇偶性决定（奇数为男，偶数为女）
+    pub gender: Gender,
+}
+
+/// 校验中国居民身份证号码是否合法
+///
+/// 依次校验号码格式（18 位，前 17 位为数字，最后一位为数字或 `X`）、
+/// 出生日期是否真实存在（包括闰年的 2 月 29 日）以及校验码是否正确
+///
+/// # 参数
+/// * `id` - 身份证号码，末位的 `X` 不区分大小写
+///
+/// # 返回值
+/// 如果身份证号码合法，返回 `true`；否则返回 `false`
+///
+/// # 示例
+/// ```
+/// use huturs_core::util::is_valid_chinese_id;
+/// assert!(is_valid_chinese_id("11010519491231002X"));
+/// assert!(!is_valid_chinese_id("110105194912310021")); // 校验码错误
+/// assert!(!is_valid_chinese_id("110105194902300029")); // 2月30日不存在
+/// ```
+pub fn is_valid_chinese_id(id: &str) -> bool {
+    parse_chinese_id(id).is_some()
+}
+
+/// 解析中国居民身份证号码
+///
+/// # 参数
+/// * `id` - 身份证号码，末位的 `X` 不区分大小写
+///
+/// # 返回值
+/// 号码合法时返回解析出的 `ChineseIdInfo`，否则返回 `None`
+///
+/// # 示例
+/// ```
+/// use huturs_core::util::{parse_chinese_id, Gender};
+/// let info = parse_chinese_id("11010519491231002X").unwrap();
+/// assert_eq!(info.region_code, "110105");
+/// assert_eq!(info.birthdate, (1949, 12, 31));
+/// assert_eq!(info.sequence, "002");
+/// assert_eq!(info.gender, Gender::Female);
+/// ```
+pub fn parse_chinese_id(id: &str) -> Option<ChineseIdInfo> {
+    let chars: Vec<char> = id.chars().collect();
+    if chars.len() != 18 {
+        return None;
+    }
+
+    // 前 17 位必须全部为数字
+    let mut digits = [0u32; 17];
+    for (i, c) in chars[..17].iter().enumerate() {
+        digits[i] = c.to_digit(10)?;
+    }
+
+    // 校验出生日期
+    let year = digits[6..10].iter().fold(0, |acc, d| acc * 10 + d);
+    let month = digits[10] * 10 + digits[11];
+    let day = digits[12] * 10 + digits[13];
+    if month == 0 || month > 12 || day == 0 || day > days_of_month(year, month) {
+        return None;
+    }
+
+    // 校验码
+    let sum: u32 = digits
+        .iter()
+        .zip(CHINESE_ID_WEIGHTS.iter())
+        .map(|(d, w)| d * w)
+        .sum();
+    if CHINESE_ID_CHECK_CODES[(sum % 11) as usize] != chars[17].to_ascii_uppercase() {
+        return None;
+    }
+
+    Some(ChineseIdInfo {
+        region_code: id[0..6].to_string(),
+        birthdate: (year, month, day),
+        sequence: id[14..17].to_string(),
+        gender: if digits[16] % 2 == 1 {
+            Gender::Male
+        } else {
+            Gender::Female
+        },
+    })
+}
+
+/// 计算指定年月的天数
+fn days_of_month(year: u32, month: u32) -> u32 {
+    let is_leap_year =
+        (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400);
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        _ if is_leap_year => 29,
+        _ => 28,
+    }
+}
+
+// 电话号码工具
+
+/// 校验电话号码是否符合 E.164 国际格式
+///
+/// E.164 格式为 `+` 加 7 ~ 15 位数字（包含国家代码），国家代码不以 0 开头，
+/// 中间不允许出现空格、横线等分隔符。只校验格式，不检查号码是否真实存在
+///
+/// # 参数
+/// * `s` - 电话号码
+///
+/// # 返回值
+/// 如果符合 E.164 格式，返回 `true`；否则返回 `false`
+///
+/// # 示例
+/// ```
+/// use huturs_core::util::is_valid_phone_e164;
+/// assert!(is_valid_phone_e164("+14155552671"));
+/// assert!(is_valid_phone_e164("+8613800138000"));
+/// assert!(!is_valid_phone_e164("14155552671"));    // 缺少 +
+/// assert!(!is_valid_phone_e164("+1 415 555 2671")); // 包含空格
+/// ```
+pub fn is_valid_phone_e164(s: &str) -> bool {
+    match s.strip_prefix('+') {
+        Some(digits) => {
+            (7..=15).contains(&digits.len())
+                && digits.bytes().all(|b| b.is_ascii_digit())
+                && !digits.starts_with('0')
+        }
+        None => false,
+    }
+}
+
+/// 将电话号码规范化为 E.164 格式
+///
+/// 先去除空格、横线和括号（英国号码中常见的 `(0)` 整体去除）；如果号码以 `+` 或国际冠码 `00` 开头，视为已包含国家代码，
+/// 否则去掉国内长途前缀 `0`（如有）后在前面加上 `+国家代码`，最后校验结果是否符合 E.164 格式
+///
+/// # 参数
+/// * `s` - 电话号码，如 `"(415) 555-2671"`、`"138 0013 8000"`、`"+44 20 7946 0958"`
+/// * `country_code` - 号码未包含国家代码时使用的国家代码，如 `"1"`（美国）、`"86"`（中国）、`"44"`（英国）
+///
+/// # 返回值
+/// 返回规范化后的号码；包含其他字符、国家代码无效或结果不符合 E.164 格式时返回 `None`
+///
+/// # 注意
+/// 不带 `+` 或 `00` 的号码总会被加上 `country_code`，因此已包含国家代码的号码必须带上前缀
+///
+/// # 示例
+/// ```
+/// use huturs_core::util::normalize_phone_e164;
+/// assert_eq!(normalize_phone_e164("(415) 555-2671", "1"), Some(String::from("+14155552671")));
+/// assert_eq!(normalize_phone_e164("138-0013-8000", "86"), Some(String::from("+8613800138000")));
+/// assert_eq!(normalize_phone_e164("020 7946 0958", "44"), Some(String::from("+442079460958")));
+/// assert_eq!(normalize_phone_e164("+1 415 555 2671", "86"), Some(String::from("+14155552671")));
+/// assert_eq!(normalize_phone_e164("call me", "1"), None);
+/// ```
+pub fn normalize_phone_e164(s: &str, country_code: &str) -> Option<String> {
+    let stripped: String = s
+        .replace("(0)", "")
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '(' | ')'))
+        .collect();
+
+    let normalized = if let Some(rest) = stripped.strip_prefix('+') {
+        format!("+{}", rest)
+    } else if let Some(rest) = stripped.strip_prefix("00") {
+        format!("+{}", rest)
+    } else {
+        let valid_country_code = (1..=3).contains(&country_code.len())
+            && country_code.bytes().all(|b| b.is_ascii_digit())
+            && !country_code.starts_with('0');
+        if !valid_country_code {
+            return None;
+        }
+        let national = stripped.strip_prefix('0').unwrap_or(&stripped);
+        format!("+{}{}", country_code, national)
+    };
+
+    is_valid_phone_e164(&normalized).then_some(normalized)
+}
+
+// 随机数工具
+
+/// 生成指定范围内的随机整数
+///
+/// # 参数
+/// * `min` - 最小值（包含）
+/// * `max` - 最大值（包含）
+///
+/// # 返回值
+/// 返回 `[min, max]` 范围内均匀分布的随机整数
+///
+/// # 注意
+/// 如果 `min > max`，此函数会 panic
+///
+/// # 示例
+/// ```
+/// use huturs_core::util::random_between;
+/// let n = random_between(1, 6);
+/// assert!((1..=6).contains(&n));
+/// assert_eq!(random_between(3, 3), 3);
+/// ```
+pub fn random_between(min: i64, max: i64) -> i64 {
+    rand::thread_rng().gen_range(min..=max)
+}
+
+/// 生成指定范围内的随机浮点数
+///
+/// # 参数
+/// * `min` - 最小值（包含）
+/// * `max` - 最大值（不包含）
+///
+/// # 返回值
+/// 返回 `[min, max)` 范围内均匀分布的随机浮点数
+///
+/// # 注意
+/// 如果 `min >= max`，此函数会 panic
+///
+/// # 示例
+/// ```
+/// use huturs_core::util::random_float;
+/// let f = random_float(0.0, 1.0);
+/// assert!((0.0..1.0).contains(&f));
+/// ```
+pub fn random_float(min: f64, max: f64) -> f64 {
+    rand::thread_rng().gen_range(min..max)
+}
+
+/// 使用 Fisher-Yates 算法原地打乱切片中元素的顺序
+///
+/// # 参数
+/// * `items` - 要打乱的切片，`Vec` 可通过 `&mut vec` 直接传入
+///
+/// # 示例
+/// ```
+/// use huturs_core::util::shuffle_slice;
+/// let mut items = vec![1, 2, 3, 4, 5];
+/// shuffle_slice(&mut items);
+/// items.sort();
+/// assert_eq!(items, vec![1, 2, 3, 4, 5]);
+/// ```
+pub fn shuffle_slice<T>(items: &mut [T]) {
+    let mut rng = rand::thread_rng();
+    for i in (1..items.len()).rev() {
+        let j = rng.gen_range(0..=i);
+        items.swap(i, j);
+    }
+}
+
+/// 从切片中随机抽取指定数量的元素（不放回）
+///
+/// # 参数
+/// * `slice` - 源切片
+/// * `n` - 要抽取的元素数量
+///
+/// # 返回值
+/// 返回随机抽取的元素，如果 `n` 大于切片长度，则返回全部元素（顺序随机）
+///
+/// # 示例
+/// ```
+/// use huturs_core::util::sample;
+/// let items = vec![1, 2, 3, 4, 5];
+/// assert_eq!(sample(&items, 3).len(), 3);
+/// assert_eq!(sample(&items, 10).len(), 5);
+/// ```
+pub fn sample<T: Clone>(slice: &[T], n: usize) -> Vec<T> {
+    slice
+        .choose_multiple(&mut rand::thread_rng(), n)
+        .cloned()
+        .collect()
+}
+
+/// 从切片中随机选择一个元素
+///
+/// # 参数
+/// * `slice` - 源切片
+///
+/// # 返回值
+/// 返回随机选中元素的引用，如果切片为空则返回 `None`
+///
+/// # 示例
+/// ```
+/// use huturs_core::util::choose;
+/// let items = vec!["a", "b", "c"];
+/// assert!(items.contains(choose(&items).unwrap()));
+/// assert_eq!(choose::<i32>(&[]), None);
+/// ```
+pub fn choose<T>(slice: &[T]) -> Option<&T> {
+    slice.choose(&mut rand::thread_rng())
+}
+
+// 雪花算法 ID 生成器
+
+/// 雪花算法 ID 生成器
+///
+/// 生成的 64 位 ID 由高到低依次为：1 位符号位（恒为 0）、41 位毫秒时间戳（相对自定义纪元）、
+/// 10 位机器 ID 和 12 位序列号，同一毫秒内最多生成 4096 个 ID，生成的 ID 按时间递增
+///
+/// # 示例
+/// ```
+/// use huturs_core::util::Snowflake;
+/// let mut snowflake = Snowflake::new(1);
+/// let first = snowflake.next_id();
+/// let second = snowflake.next_id();
+/// assert!(second > first);
+/// ```
+#[derive(Debug)]
+pub struct Snowflake {
+    machine_id: u16,
+    epoch_millis: u64,
+    last_timestamp: u64,
+    sequence: u64,
+}
+
+impl Snowflake {
+    /// 默认纪元：2024-01-01 00:00:00 UTC 对应的 Unix 毫秒时间戳
+    pub const DEFAULT_EPOCH_MILLIS: u64 = 1_704_067_200_000;
+
+    /// 机器 ID 占用的位数
+    const MACHINE_ID_BITS: u32 = 10;
+
+    /// 序列号占用的位数
+    const SEQUENCE_BITS: u32 = 12;
+
+    /// 机器 ID 的最大值
+    pub const MAX_MACHINE_ID: u16 = (1 << Self::MACHINE_ID_BITS) - 1;
+
+    /// 使用默认纪元创建生成器
+    ///
+    /// # 参数
+    /// * `machine_id` - 机器 ID，取值范围为 `0..=1023`
+    ///
+    /// # 注意
+    /// 如果 `machine_id` 超过 10 位所能表示的范围，将会 panic
+    pub fn new(machine_id: u16) -> Self {
+        Self::with_epoch(machine_id, Self::DEFAULT_EPOCH_MILLIS)
+    }
+
+    /// 使用自定义纪元创建生成器
+    ///
+    /// # 参数
+    /// * `machine_id` - 机器 ID，取值范围为 `0..=1023`
+    /// * `epoch_millis` - 纪元对应的 Unix 毫秒时间戳，不能晚于当前时间
+    ///
+    /// # 注意
+    /// 如果 `machine_id` 超过 10 位所能表示的范围，将会 panic
+    ///
+    /// # 示例
+    /// ```
+    /// use huturs_core::util::Snowflake;
+    /// // 以 2020-01-01 00:00:00 UTC 为纪元
+    /// let mut snowflake = Snowflake::with_epoch(7, 1_577_836_800_000);
+    /// let id = snowflake.next_id();
+    /// assert!(snowflake.timestamp_from_id(id) > 1_577_836_800_000);
+    /// ```
+    pub fn with_epoch(machine_id: u16, epoch_millis: u64) -> Self {
+        assert!(
+            machine_id <= Self::MAX_MACHINE_ID,
+            "machine_id must be at most {}",
+            Self::MAX_MACHINE_ID
+        );
+        Snowflake {
+            machine_id,
+            epoch_millis,
+            last_timestamp: 0,
+            sequence: 0,
+        }
+    }
+
+    /// 生成下一个 ID
+    ///
+    /// # 返回值
+    /// 返回比之前生成的所有 ID 都大的 64 位 ID
+    ///
+    /// # 注意
+    /// 同一毫秒内的序列号用尽时会等待到下一毫秒；系统时钟回拨时沿用上一次的时间戳继续递增序列号
+    pub fn next_id(&mut self) -> u64 {
+        let max_sequence = (1 << Self::SEQUENCE_BITS) - 1;
+        let mut timestamp = self.current_timestamp().max(self.last_timestamp);
+        if timestamp == self.last_timestamp {
+            self.sequence = (self.sequence + 1) & max_sequence;
+            if self.sequence == 0 {
+                while timestamp <= self.last_timestamp {
+                    std::hint::spin_loop();
+                    timestamp = self.current_timestamp();
+                }
+            }
+        } else {
+            self.sequence = 0;
+        }
+        self.last_timestamp = timestamp;
+
+        (timestamp << (Self::MACHINE_ID_BITS + Self::SEQUENCE_BITS))
+            | ((self.machine_id as u64) << Self::SEQUENCE_BITS)
+            | self.sequence
+    }
+
+    /// 从 ID 中解析出生成时的 Unix 毫秒时间戳
+    ///
+    /// # 参数
+    /// * `id` - 由当前生成器（或使用相同纪元的生成器）生成的 ID
+    ///
+    /// # 返回值
+    /// 返回 ID 生成时的 Unix 毫秒时间戳
+    pub fn timestamp_from_id(&self, id: u64) -> u64 {
+        (id >> (Self::MACHINE_ID_BITS + Self::SEQUENCE_BITS)) + self.epoch_millis
+    }
+
+    /// 当前时间相对纪元的毫秒数
+    fn current_timestamp(&self) -> u64 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+        now.saturating_sub(self.epoch_millis)
+    }
+}
+
+// 版本号工具
+
+/// 比较两个以 `.` 分隔的版本号
+///
+/// 逐段比较：每段先比较开头的数字部分（按数值大小），再按字典序比较数字之后的后缀（如 `-beta`）；
+/// 所有段都相同时，段数较少的版本号更小（`1.0 < 1.0.1`）
+///
+/// # 参数
+/// * `v1` - 第一个版本号
+/// * `v2` - 第二个版本号
+///
+/// # 返回值
+/// 返回 `v1` 相对 `v2` 的大小关系。无法解析的版本号（为空或某一段不以数字开头）
+/// 视为小于任何合法版本号，两者都无法解析时按字典序比较
+///
+/// # 示例
+/// ```
+/// use huturs_core::util::version_compare;
+/// use std::cmp::Ordering;
+/// assert_eq!(version_compare("1.10.2", "1.9.3"), Ordering::Greater);
+/// assert_eq!(version_compare("1.0", "1.0.1"), Ordering::Less);
+/// assert_eq!(version_compare("2.0.0", "2.0.0"), Ordering::Equal);
+/// ```
+pub fn version_compare(v1: &str, v2: &str) -> Ordering {
+    match (parse_version(v1), parse_version(v2)) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Greater,
+        (None, Some(_)) => Ordering::Less,
+        (None, None) => v1.cmp(v2),
+    }
+}
+
+/// 判断版本号 `v1` 是否大于或等于 `v2`
+///
+/// # 参数
+/// * `v1` - 第一个版本号
+/// * `v2` - 第二个版本号
+///
+/// # 返回值
+/// 如果 `v1 >= v2`，返回 `true`；否则返回 `false`，比较规则与 [`version_compare`] 相同
+///
+/// # 示例
+/// ```
+/// use huturs_core::util::is_version_gte;
+/// assert!(is_version_gte("1.10", "1.9"));
+/// assert!(is_version_gte("1.2.0", "1.2.0"));
+/// assert!(!is_version_gte("1.2", "1.2.1"));
+/// ```
+pub fn is_version_gte(v1: &str, v2: &str) -> bool {
+    version_compare(v1, v2) != Ordering::Less
+}
+
+/// 将版本号解析为 `(数字部分, 后缀)` 的列表，任意一段不以数字开头时返回 `None`
+fn parse_version(version: &str) -> Option<Vec<(u64, &str)>> {
+    let version = version.trim();
+    if version.is_empty() {
+        return None;
+    }
+    version
+        .split('.')
+        .map(|segment| {
+            let digits_end = segment
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(segment.len());
+            let number = segment[..digits_end].parse::<u64>().ok()?;
+            Some((number, &segment[digits_end..]))
+        })
+        .collect()
+}
+
+// 异或加密工具
+
+/// 使用异或运算对数据进行加密或解密
+///
+/// 将 `data` 的每个字节与 `key` 中对应位置的字节做异或运算，`key` 较短时循环使用，
+/// 即 `data[i] ^ key[i % key.len()]`。由于异或运算的逆运算是其本身，同一个函数既可加密也可解密
+///
+/// # 参数
+/// * `data` - 要加密或解密的数据
+/// * `key` - 密钥，不能为空
+///
+/// # 返回值
+/// 返回与 `data` 等长的结果，`data` 为空时返回空数组
+///
+/// # 注意
+/// 异或加密只能用于简单的混淆，不能提供真正的安全性。如果 `key` 为空，将会 panic
+///
+/// # 示例
+/// ```
+/// use huturs_core::util::xor_cipher;
+/// let encrypted = xor_cipher(b"hello", b"key");
+/// assert_ne!(encrypted, b"hello");
+/// assert_eq!(xor_cipher(&encrypted, b"key"), b"hello");
+/// ```
+pub fn xor_cipher(data: &[u8], key: &[u8]) -> Vec<u8> {
+    assert!(!key.is_empty(), "xor_cipher key must not be empty");
+    data.iter()
+        .zip(key.iter().cycle())
+        .map(|(byte, k)| byte ^ k)
+        .collect()
+}
+
+/// 使用异或运算对字符串进行加密
+///
+/// # 参数
+/// * `data` - 要加密的字符串
+/// * `key` - 密钥字符串，不能为空
+///
+/// # 返回值
+/// 返回加密后的字节，结果不一定是合法的 UTF-8，可以再次传给 [`xor_cipher`] 解密
+///
+/// # 注意
+/// 如果 `key` 为空，将会 panic
+///
+/// # 示例
+/// ```
+/// use huturs_core::util::{xor_cipher, xor_cipher_str};
+/// let encrypted = xor_cipher_str("你好", "secret");
+/// assert_eq!(xor_cipher(&encrypted, b"secret"), "你好".as_bytes());
+/// ```
+pub fn xor_cipher_str(data: &str, key: &str) -> Vec<u8> {
+    xor_cipher(data.as_bytes(), key.as_bytes())
+}
+
+// 一次性密码工具
+
+/// 按照 TOTP 算法（RFC 6238）生成 6 位一次性密码
+///
+/// 计数器为 `timestamp / period`，以 8 字节大端序与密钥做 HMAC-SHA1，
+/// 再按 HOTP 算法（RFC 4226）动态截断并对 10^6 取模
+///
+/// # 参数
+/// * `secret` - 共享密钥（原始字节，而非 Base32 编码后的字符串）
+/// * `timestamp` - Unix 时间戳（秒）
+/// * `period` - 密码的有效周期（秒），通常为 30
+///
+/// # 返回值
+/// 返回左侧补零的 6 位数字字符串
+///
+/// # 注意
+/// 此实现仅用于学习和简单场景，生产环境请使用经过安全审计的专用库。如果 `period` 为 0，将会 panic
+///
+/// # 示例
+/// ```
+/// use huturs_core::util::generate_otp;
+/// // RFC 6238 附录 B 的测试向量（取后 6 位）
+/// assert_eq!(generate_otp(b"12345678901234567890", 59, 30), "287082");
+/// ```
+#[cfg(feature = "util-otp")]
+#[cfg_attr(docsrs, doc(cfg(feature = "util-otp")))]
+pub fn generate_otp(secret: &[u8], timestamp: u64, period: u64) -> String {
+    use hmac::{Hmac, Mac};
+
+    assert!(period > 0, "period must be greater than 0");
+    let counter = timestamp / period;
+    let mut mac =
+        Hmac::<sha1::Sha1>::new_from_slice(secret).expect("HMAC accepts keys of any size");
+    mac.update(&counter.to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+
+    // 动态截断：取最后一个字节的低 4 位作为偏移量，读取 4 个字节并去掉最高位
+    let offset = (hash[hash.len() - 1] & 0x0F) as usize;
+    let code = u32::from_be_bytes([
+        hash[offset] & 0x7F,
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]);
+    format!("{:06}", code % 1_000_000)
+}
+
+// 时长字符串工具
+
+/// 解析形如 `"1h30m"` 的时长字符串
+///
+/// 字符串由若干个"整数 + 单位"组成，各部分可以任意顺序、重复出现，之间允许有空白，
+/// 单位不区分大小写。支持的单位如下：
+///
+/// | 单位 | 写法 |
+/// |------|------|
+/// | 天   | `d`、`day`、`days` |
+/// | 小时 | `h`、`hr`、`hrs`、`hour`、`hours` |
+/// | 分钟 | `m`、`min`、`mins`、`minute`、`minutes` |
+/// | 秒   | `s`、`sec`、`secs`、`second`、`seconds` |
+/// | 毫秒 | `ms`、`millisecond`、`milliseconds` |
+///
+/// # 参数
+/// * `s` - 时长字符串
+///
+/// # 返回值
+/// 返回各部分之和；字符串为空、包含无法识别的内容、数字缺少单位或结果溢出时返回 `None`
+///
+/// # 示例
+/// ```
+/// use std::time::Duration;
+/// use huturs_core::util::parse_duration_str;
+/// assert_eq!(parse_duration_str("1h30m"), Some(Duration::from_secs(5400)));
+/// assert_eq!(parse_duration_str("500ms"), Some(Duration::from_millis(500)));
+/// assert_eq!(parse_duration_str("2 hours 15 min"), Some(Duration::from_secs(8100)));
+/// assert_eq!(parse_duration_str("1x"), None);
+/// ```
+pub fn parse_duration_str(s: &str) -> Option<std::time::Duration> {
+    let mut chars = s.trim().chars().peekable();
+    chars.peek()?;
+
+    let mut total_millis: u64 = 0;
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let mut number = String::new();
+        while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+            number.push(c);
+        }
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let mut unit = String::new();
+        while let Some(c) = chars.next_if(|c| c.is_ascii_alphabetic()) {
+            unit.push(c.to_ascii_lowercase());
+        }
+        if number.is_empty() || unit.is_empty() {
+            return None;
+        }
+
+        let unit_millis: u64 = match unit.as_str() {
+            "d" | "day" | "days" => 86_400_000,
+            "h" | "hr" | "hrs" | "hour" | "hours" => 3_600_000,
+            "m" | "min" | "mins" | "minute" | "minutes" => 60_000,
+            "s" | "sec" | "secs" | "second" | "seconds" => 1_000,
+            "ms" | "millisecond" | "milliseconds" => 1,
+            _ => return None,
+        };
+        let value: u64 = number.parse().ok()?;
+        total_millis = total_millis.checked_add(value.checked_mul(unit_millis)?)?;
+    }
+    Some(std::time::Duration::from_millis(total_millis))
+}
+
+/// 将时长格式化为紧凑的时长字符串
+///
+/// 按天、小时、分钟、秒、毫秒的顺序输出不为零的部分，如 `"1d2h15m30s"`，
+/// 输出结果可以被 [`parse_duration_str`] 重新解析
+///
+/// # 参数
+/// * `d` - 时长
+///
+/// # 返回值
+/// 返回紧凑格式的字符串；时长不足 1 毫秒时返回 `"0s"`
+///
+/// # 注意
+/// 精度为毫秒，不足一毫秒的部分会被舍去
+///
+/// # 示例
+/// ```
+/// use std::time::Duration;
+/// use huturs_core::util::format_duration_str;
+/// assert_eq!(format_duration_str(Duration::from_secs(5400)), "1h30m");
+/// assert_eq!(format_duration_str(Duration::from_millis(90_500)), "1m30s500ms");
+/// assert_eq!(format_duration_str(Duration::ZERO), "0s");
+/// ```
+pub fn format_duration_str(d: std::time::Duration) -> String {
+    let total_millis = d.as_millis();
+    let parts = [
+        (total_millis / 86_400_000, "d"),
+        (total_millis % 86_400_000 / 3_600_000, "h"),
+        (total_millis % 3_600_000 / 60_000, "m"),
+        (total_millis % 60_000 / 1_000, "s"),
+        (total_millis % 1_000, "ms"),
+    ];
+
+    let result: String = parts
+        .iter()
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, unit)| format!("{}{}", value, unit))
+        .collect();
+    if result.is_empty() {
+        String::from("0s")
+    } else {
+        result
+    }
+}
+
+// 模板工具
+
+/// 简单的字符串模板渲染
+///
+/// 将模板中的 `{{变量名}}` 替换为 `vars` 中对应的值，变量名两侧的空白会被忽略，
+/// 如 `{{ name }}` 与 `{{name}}` 等价。变量名只能包含字母、数字、`_`、`-` 和 `.`。
+/// `{{{{` 会输出字面量 `{{`
+///
+/// # 参数
+/// * `template` - 模板字符串
+/// * `vars` - 变量名到值的映射
+///
+/// # 返回值
+/// 返回渲染后的字符串
+///
+/// # 注意
+/// `vars` 中不存在的变量会原样保留（如 `{{unknown}}`），便于发现遗漏；
+/// 如需在缺少变量时报错，请使用 [`simple_template_strict`]
+///
+/// # 示例
+/// ```
+/// use std::collections::HashMap;
+/// use huturs_core::util::simple_template;
+/// let vars = HashMap::from([("name", "Tom"), ("count", "3")]);
+/// assert_eq!(
+///     simple_template("Hi {{name}}, you have {{ count }} messages", &vars),
+///     "Hi Tom, you have 3 messages"
+/// );
+/// assert_eq!(simple_template("Hi {{nickname}}", &vars), "Hi {{nickname}}");
+/// assert_eq!(simple_template("{{{{name}}", &vars), "{{name}}");
+/// ```
+pub fn simple_template(template: &str, vars: &HashMap<&str, &str>) -> String {
+    render_template(template, vars).0
+}
+
+/// 严格模式的字符串模板渲染
+///
+/// 语法与 [`simple_template`] 相同，但模板中的变量必须全部存在于 `vars` 中
+///
+/// # 参数
+/// * `template` - 模板字符串
+/// * `vars` - 变量名到值的映射
+///
+/// # 返回值
+/// 所有变量都存在时返回 `Ok(渲染结果)`；否则返回 `Err`，错误信息按首次出现的顺序列出所有缺少的变量
+///
+/// # 示例
+/// ```
+/// use std::collections::HashMap;
+/// use huturs_core::util::simple_template_strict;
+/// let vars = HashMap::from([("name", "Tom")]);
+/// assert_eq!(simple_template_strict("Hi {{name}}", &vars), Ok(String::from("Hi Tom")));
+/// assert_eq!(
+///     simple_template_strict("{{greeting}} {{name}}, {{date}}", &vars),
+///     Err(String::from("Missing template variables: greeting, date"))
+/// );
+/// ```
+pub fn simple_template_strict(
+    template: &str,
+    vars: &HashMap<&str, &str>,
+) -> Result<String, String> {
+    let (rendered, missing) = render_template(template, vars);
+    if missing.is_empty() {
+        Ok(rendered)
+    } else {
+        Err(format!(
+            "Missing template variables: {}",
+            missing.join(", ")
+        ))
+    }
+}
+
+/// 渲染模板，返回渲染结果和按首次出现顺序排列的缺失变量名
+fn render_template(template: &str, vars: &HashMap<&str, &str>) -> (String, Vec<String>) {
+    let mut result = String::with_capacity(template.len());
+    let mut missing: Vec<String> = Vec::new();
+    let mut rest = template;
+
+    while let Some(open) = rest.find("{{") {
+        result.push_str(&rest[..open]);
+        rest = &rest[open..];
+
+        if let Some(after) = rest.strip_prefix("{{{{") {
+            result.push_str("{{");
+            rest = after;
+            continue;
+        }
+
+        let placeholder = rest[2..].find("}}").and_then(|close| {
+            let name = rest[2..2 + close].trim();
+            let is_valid_name = !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'));
+            is_valid_name.then_some((name, close + 4))
+        });
+        match placeholder {
+            Some((name, length)) => {
+                match vars.get(name) {
+                    Some(value) => result.push_str(value),
+                    None => {
+                        result.push_str(&rest[..length]);
+                        if !missing.iter().any(|m| m == name) {
+                            missing.push(name.to_string());
+                        }
+                    }
+                }
+                rest = &rest[length..];
+            }
+            None => {
+                result.push_str("{{");
+                rest = &rest[2..];
+            }
+        }
+    }
+    result.push_str(rest);
+    (result, missing)
+}
+
+// LRU 缓存
+
+/// 固定容量的 LRU（最近最少使用）缓存
+///
+/// 缓存满时插入新键会淘汰最久未被访问的条目，`get` 和 `insert` 都会把对应的键标记为最近使用。
+/// 使用 `HashMap` 存储数据，`VecDeque` 维护访问顺序（队首为最久未使用）
+///
+/// # 注意
+/// 访问顺序的调整需要在 `VecDeque` 中查找键，时间复杂度为 O(容量)，适用于容量较小的场景
+///
+/// # 示例
+/// ```
+/// use huturs_core::util::LruCache;
+/// let mut cache = LruCache::new(2);
+/// cache.insert("a", 1);
+/// cache.insert("b", 2);
+/// assert_eq!(cache.get(&"a"), Some(&1));
+/// // "b" 最久未使用，插入 "c" 时被淘汰
+/// assert_eq!(cache.insert("c", 3), Some(2));
+/// assert!(!cache.contains_key(&"b"));
+/// assert_eq!(cache.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LruCache<K: Eq + Hash, V> {
+    capacity: usize,
+    entries: HashMap<K, V>,
+    order: VecDeque<K>,
+}
+
+impl<K: Eq + Hash + Clone, V> LruCache<K, V> {
+    /// 创建指定容量的缓存
+    ///
+    /// # 参数
+    /// * `capacity` - 最多保存的条目数
+    ///
+    /// # 注意
+    /// 如果 `capacity` 为 0，此函数会 panic
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be greater than 0");
+        LruCache {
+            capacity,
+            entries: HashMap::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// 获取键对应的值，并将该键标记为最近使用
+    ///
+    /// # 参数
+    /// * `key` - 要查找的键
+    ///
+    /// # 返回值
+    /// 键存在时返回值的引用，否则返回 `None`
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        if self.entries.contains_key(key) {
+            self.touch(key);
+        }
+        self.entries.get(key)
+    }
+
+    /// 插入键值对，并将该键标记为最近使用
+    ///
+    /// # 参数
+    /// * `key` - 键
+    /// * `value` - 值
+    ///
+    /// # 返回值
+    /// 返回因此次插入而离开缓存的值：缓存已满时为被淘汰的最久未使用条目的值，
+    /// 键已存在时为被替换的旧值；否则返回 `None`
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if self.entries.contains_key(&key) {
+            self.touch(&key);
+            return self.entries.insert(key, value);
+        }
+        let evicted = if self.entries.len() >= self.capacity {
+            self.order
+                .pop_front()
+                .and_then(|oldest| self.entries.remove(&oldest))
+        } else {
+            None
+        };
+        self.order.push_back(key.clone());
+        self.entries.insert(key, value);
+        evicted
+    }
+
+    /// 检查缓存中是否存在指定的键，不会改变访问顺序
+    ///
+    /// # 参数
+    /// * `key` - 要检查的键
+    ///
+    /// # 返回值
+    /// 如果键存在返回 `true`，否则返回 `false`
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// 获取缓存中的条目数
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 检查缓存是否为空
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 获取缓存的容量
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// 将键移动到访问顺序的队尾（最近使用）
+    fn touch(&mut self, key: &K) {
+        if let Some(position) = self.order.iter().position(|k| k == key) {
+            if let Some(k) = self.order.remove(position) {
+                self.order.push_back(k);
+            }
+        }
+    }
+}
+
+// 显示名称工具
+
+/// 将 snake_case 名称转换为便于展示的标题形式
+///
+/// 按 `_` 和空白拆分单词，每个单词首字母大写后以空格连接，常用于将数据库列名或字段名转换为表单标签
+///
+/// # 参数
+/// * `s` - snake_case 字符串，如 `"user_first_name"`
+///
+/// # 返回值
+/// 返回转换后的字符串，如 `"User First Name"`
+///
+/// # 注意
+/// 全大写的单词（如 `ID`、`URL`）保持不变；连续或首尾的 `_` 会被忽略
+///
+/// # 示例
+/// ```
+/// use huturs_core::util;
+/// assert_eq!(util::snake_to_display_name("user_first_name"), "User First Name");
+/// assert_eq!(util::snake_to_display_name("user_ID"), "User ID");
+/// assert_eq!(util::snake_to_display_name("__created__at_"), "Created At");
+/// ```
+pub fn snake_to_display_name(s: &str) -> String {
+    join_display_words(s.split(|c: char| c == '_' || c.is_whitespace()))
+}
+
+/// 将 camelCase 名称转换为便于展示的标题形式
+///
+/// 在大写字母处拆分单词，每个单词首字母大写后以空格连接。连续的大写字母视为一个缩写词，
+/// 缩写词后紧跟小写字母时，最后一个大写字母归入下一个单词
+///
+/// # 参数
+/// * `s` - camelCase 或 PascalCase 字符串，如 `"userFirstName"`
+///
+/// # 返回值
+/// 返回转换后的字符串，如 `"User First Name"`
+///
+/// # 注意
+/// 全大写的单词（如 `ID`、`HTTP`）保持不变；数字归入前一个单词
+///
+/// # 示例
+/// ```
+/// use huturs_core::util;
+/// assert_eq!(util::camel_to_display_name("userFirstName"), "User First Name");
+/// assert_eq!(util::camel_to_display_name("parseHTTPResponse"), "Parse HTTP Response");
+/// assert_eq!(util::camel_to_display_name("userID"), "User ID");
+/// ```
+pub fn camel_to_display_name(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut words: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' || c.is_whitespace() {
+            words.push(std::mem::take(&mut current));
+            continue;
+        }
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lowercase = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_is_lowercase)
+            {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.push(c);
+    }
+    words.push(current);
+    join_display_words(words.iter().map(String::as_str))
+}
+
+/// 将单词首字母大写（全大写的单词保持不变）后以空格连接，忽略空单词
+fn join_display_words<'a>(words: impl Iterator<Item = &'a str>) -> String {
+    words
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+// 分批工具
+
+/// 将切片按固定大小分块
+///
+/// # 参数
+/// * `slice` - 原始切片
+/// * `chunk_size` - 每块的最大元素数
+///
+/// # 返回值
+/// 返回互不重叠的子切片列表，除最后一块外每块都有 `chunk_size` 个元素；空切片返回空列表
+///
+/// # 注意
+/// 如果 `chunk_size` 为 0，此函数会 panic
+///
+/// # 示例
+/// ```
+/// use huturs_core::util;
+/// let chunks = util::chunk_slice(&[1, 2, 3, 4, 5], 2);
+/// assert_eq!(chunks, vec![&[1, 2][..], &[3, 4][..], &[5][..]]);
+/// ```
+pub fn chunk_slice<T>(slice: &[T], chunk_size: usize) -> Vec<&[T]> {
+    assert!(chunk_size > 0, "chunk_size must be greater than 0");
+    slice.chunks(chunk_size).collect()
+}
+
+/// 按批次依次处理元素
+///
+/// 常用于分批写入数据库、按速率限制调用接口等场景
+///
+/// # 参数
+/// * `items` - 要处理的元素
+/// * `batch_size` - 每批的最大元素数
+/// * `f` - 处理函数，按顺序以每一批元素调用一次
+///
+/// # 注意
+/// 如果 `batch_size` 为 0，此函数会 panic
+///
+/// # 示例
+/// ```
+/// use huturs_core::util;
+/// let mut sums = Vec::new();
+/// util::batch_process(&[1, 2, 3, 4, 5], 2, |batch| sums.push(batch.iter().sum::<i32>()));
+/// assert_eq!(sums, vec![3, 7, 5]);
+/// ```
+pub fn batch_process<T, F: FnMut(&[T])>(items: &[T], batch_size: usize, mut f: F) {
+    assert!(batch_size > 0, "batch_size must be greater than 0");
+    for batch in items.chunks(batch_size) {
+        f(batch);
+    }
+}
+
+// 位标志工具
+
+/// 基于 `u64` 的位标志集合
+///
+/// 常用于权限、功能开关等场景，每个标志对应一个二进制位，也可以一次操作多个位的组合
+///
+/// # 示例
+/// ```
+/// use huturs_core::util::BitFlags;
+/// const READ: u64 = 1 << 0;
+/// const WRITE: u64 = 1 << 1;
+/// const EXECUTE: u64 = 1 << 2;
+///
+/// let mut permissions = BitFlags::new(0);
+/// permissions.set(READ).set(WRITE);
+/// assert!(permissions.has_all(READ | WRITE));
+/// assert!(!permissions.has(EXECUTE));
+/// assert_eq!(permissions.to_string(), "0b11");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct BitFlags {
+    value: u64,
+}
+
+impl BitFlags {
+    /// 使用初始值创建位标志集合
+    ///
+    /// # 参数
+    /// * `value` - 初始的位值
+    pub fn new(value: u64) -> Self {
+        BitFlags { value }
+    }
+
+    /// 获取当前的位值
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// 设置标志位
+    ///
+    /// # 参数
+    /// * `flag` - 要设置的位，可以是多个位的组合
+    pub fn set(&mut self, flag: u64) -> &mut Self {
+        self.value |= flag;
+        self
+    }
+
+    /// 清除标志位
+    ///
+    /// # 参数
+    /// * `flag` - 要清除的位，可以是多个位的组合
+    pub fn unset(&mut self, flag: u64) -> &mut Self {
+        self.value &= !flag;
+        self
+    }
+
+    /// 翻转标志位
+    ///
+    /// # 参数
+    /// * `flag` - 要翻转的位，可以是多个位的组合
+    pub fn toggle(&mut self, flag: u64) -> &mut Self {
+        self.value ^= flag;
+        self
+    }
+
+    /// 检查是否设置了指定的标志
+    ///
+    /// # 参数
+    /// * `flag` - 要检查的位
+    ///
+    /// # 返回值
+    /// 如果 `flag` 中的所有位都已设置，返回 `true`；`flag` 为 0 时总是返回 `true`
+    pub fn has(&self, flag: u64) -> bool {
+        self.has_all(flag)
+    }
+
+    /// 检查是否设置了所有指定的标志
+    ///
+    /// # 参数
+    /// * `flags` - 要检查的位的组合
+    ///
+    /// # 返回值
+    /// 如果 `flags` 中的所有位都已设置，返回 `true`；`flags` 为 0 时总是返回 `true`
+    pub fn has_all(&self, flags: u64) -> bool {
+        self.value & flags == flags
+    }
+
+    /// 检查是否设置了任一指定的标志
+    ///
+    /// # 参数
+    /// * `flags` - 要检查的位的组合
+    ///
+    /// # 返回值
+    /// 如果 `flags` 中至少有一位已设置，返回 `true`；`flags` 为 0 时总是返回 `false`
+    pub fn has_any(&self, flags: u64) -> bool {
+        self.value & flags != 0
+    }
+}
+
+impl From<u64> for BitFlags {
+    fn from(value: u64) -> Self {
+        BitFlags::new(value)
+    }
+}
+
+impl std::fmt::Display for BitFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#b}", self.value)
+    }
+}
+
+// JSON 工具
+
+/// 递归合并两个 JSON 值
+///
+/// 两者都是对象时逐个键合并：`override_` 中的键覆盖 `base` 中的同名键，同名键的值也都是对象时继续递归合并，
+/// `base` 中独有的键保留不变。其它情况（包括数组和 `null`）下直接使用 `override_` 的值
+///
+/// # 参数
+/// * `base` - 基础值，如默认配置
+/// * `override_` - 覆盖值，如用户配置
+///
+/// # 返回值
+/// 返回合并后的 JSON 值
+///
+/// # 注意
+/// 数组不会逐项合并，而是整体替换；`override_` 中值为 `null` 的键会将 `base` 中的值替换为 `null`，而不是删除该键
+///
+/// # 示例
+/// ```
+/// use huturs_core::util;
+/// use serde_json::json;
+/// let base = json!({"server": {"host": "localhost", "port": 80}, "debug": false});
+/// let override_ = json!({"server": {"port": 8080}, "debug": true});
+/// assert_eq!(
+///     util::deep_merge_json(base, override_),
+///     json!({"server": {"host": "localhost", "port": 8080}, "debug": true})
+/// );
+/// ```
+#[cfg(feature = "util-json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "util-json")))]
+pub fn deep_merge_json(base: serde_json::Value, override_: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    match (base, override_) {
+        (Value::Object(mut base), Value::Object(override_)) => {
+            for (key, value) in override_ {
+                let merged = match base.remove(&key) {
+                    Some(existing) => deep_merge_json(existing, value),
+                    None => value,
+                };
+                base.insert(key, merged);
+            }
+            Value::Object(base)
+        }
+        (_, override_) => override_,
+    }
+}
+
+/// 按点分隔的路径获取 JSON 中的值
+///
+/// # 参数
+/// * `value` - JSON 值
+/// * `path` - 以 `.` 分隔的路径，如 `"server.host"`；数组元素使用数字下标，如 `"servers.0.host"`
+///
+/// # 返回值
+/// 返回路径对应值的引用，路径不存在时返回 `None`；`path` 为空字符串时返回 `value` 本身
+///
+/// # 示例
+/// ```
+/// use huturs_core::util;
+/// use serde_json::json;
+/// let config = json!({"server": {"host": "localhost", "ports": [80, 443]}});
+/// assert_eq!(util::json_get_path(&config, "server.host"), Some(&json!("localhost")));
+/// assert_eq!(util::json_get_path(&config, "server.ports.1"), Some(&json!(443)));
+/// assert_eq!(util::json_get_path(&config, "server.user"), None);
+/// ```
+#[cfg(feature = "util-json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "util-json")))]
+pub fn json_get_path<'a>(
+    value: &'a serde_json::Value,
+    path: &str,
+) -> Option<&'a serde_json::Value> {
+    use serde_json::Value;
+
+    if path.is_empty() {
+        return Some(value);
+    }
+    path.split('.')
+        .try_fold(value, |current, segment| match current {
+            Value::Object(map) => map.get(segment),
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => None,
+        })
+}
@@ -0,0 +1,104 @@
+//! 输入校验工具类模块
+//! 提供判断字符串字符类别的校验函数，适用于表单、命令行参数等输入校验场景
+
+/// 检查字符串是否只包含 ASCII 数字（0-9）
+///
+/// # 参数
+/// * `s` - 要检查的字符串
+///
+/// # 返回值
+/// 如果字符串非空且所有字符均为 ASCII 数字，返回 `true`；否则返回 `false`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::validation;
+///
+/// assert!(validation::is_numeric_str("12345"));
+/// assert!(!validation::is_numeric_str("-12"));
+/// assert!(!validation::is_numeric_str(""));
+/// ```
+pub fn is_numeric_str(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_digit())
+}
+
+/// 检查字符串是否只包含 ASCII 字母（a-z、A-Z）
+///
+/// # 参数
+/// * `s` - 要检查的字符串
+///
+/// # 返回值
+/// 如果字符串非空且所有字符均为 ASCII 字母，返回 `true`；否则返回 `false`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::validation;
+///
+/// assert!(validation::is_alpha_str("HelloWorld"));
+/// assert!(!validation::is_alpha_str("Hello World"));
+/// assert!(!validation::is_alpha_str("café"));
+/// ```
+pub fn is_alpha_str(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+/// 检查字符串是否只包含 ASCII 字母和数字
+///
+/// # 参数
+/// * `s` - 要检查的字符串
+///
+/// # 返回值
+/// 如果字符串非空且所有字符均为 ASCII 字母或数字，返回 `true`；否则返回 `false`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::validation;
+///
+/// assert!(validation::is_alphanumeric_str("abc123"));
+/// assert!(!validation::is_alphanumeric_str("abc_123"));
+/// ```
+pub fn is_alphanumeric_str(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// 检查字符串是否只包含 ASCII 字符
+///
+/// # 参数
+/// * `s` - 要检查的字符串
+///
+/// # 返回值
+/// 如果字符串非空且所有字符均为 ASCII 字符（码点 0-127），返回 `true`；否则返回 `false`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::validation;
+///
+/// assert!(validation::is_ascii_str("hello, world!\n"));
+/// assert!(!validation::is_ascii_str("你好"));
+/// ```
+pub fn is_ascii_str(s: &str) -> bool {
+    !s.is_empty() && s.is_ascii()
+}
+
+/// 检查字符串是否只包含可打印的 ASCII 字符
+///
+/// # 参数
+/// * `s` - 要检查的字符串
+///
+/// # 返回值
+/// 如果字符串非空且所有字符均为可打印 ASCII 字符（空格 `0x20` 到 `~` `0x7E`），返回 `true`；否则返回 `false`
+///
+/// # 示例
+///
+/// ```
+/// use huturs_core::validation;
+///
+/// assert!(validation::is_ascii_printable_str("Hello, World!"));
+/// assert!(!validation::is_ascii_printable_str("Hello\tWorld"));
+/// ```
+pub fn is_ascii_printable_str(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| (' '..='~').contains(&c))
+}
@@ -0,0 +1,418 @@
+use huturs_core::amount::*;
+use std::str::FromStr;
+
+#[test]
+pub fn test_amount_arithmetic() {
+    let a = Amount::new(1234, 2);
+    let b = Amount::new(766, 2);
+
+    assert_eq!((a + b).to_string(), "20.00");
+    assert_eq!((a - b).to_string(), "4.68");
+    assert_eq!((b - a).to_string(), "-4.68");
+    assert_eq!((a * 3).to_string(), "37.02");
+    assert_eq!((a / 4).to_string(), "3.08");
+    assert_eq!((a * -1).to_string(), "-12.34");
+}
+
+#[test]
+pub fn test_amount_mismatched_scale() {
+    let a = Amount::new(1234, 2); // 12.34
+    let b = Amount::new(5, 1); // 0.5
+
+    let sum = a + b;
+    assert_eq!(sum.scale(), 2);
+    assert_eq!(sum.value(), 1284);
+
+    let diff = b - a;
+    assert_eq!(diff.to_string(), "-11.84");
+
+    // 数值相等但小数位数不同的金额视为相等
+    assert_eq!(Amount::new(150, 2), Amount::new(15, 1));
+    assert!(Amount::new(151, 2) > Amount::new(15, 1));
+    assert!(Amount::new(-1, 0) < Amount::zero(4));
+}
+
+#[test]
+pub fn test_amount_overflow() {
+    let max = Amount::new(i64::MAX, 0);
+    assert_eq!(max.checked_add(Amount::new(1, 0)), None);
+    assert_eq!(
+        Amount::new(i64::MIN, 0).checked_sub(Amount::new(1, 0)),
+        None
+    );
+    assert_eq!(max.checked_mul(2), None);
+    assert_eq!(Amount::new(i64::MIN, 0).checked_div(-1), None);
+    assert_eq!(max.checked_div(0), None);
+
+    // 统一小数位数时溢出
+    assert_eq!(max.checked_add(Amount::new(1, 1)), None);
+    assert_eq!(max.rescale(1), None);
+}
+
+#[test]
+#[should_panic]
+pub fn test_amount_add_overflow_panics() {
+    let _ = Amount::new(i64::MAX, 2) + Amount::new(1, 2);
+}
+
+#[test]
+pub fn test_amount_display() {
+    assert_eq!(Amount::new(1234, 2).to_string(), "12.34");
+    assert_eq!(Amount::new(5, 2).to_string(), "0.05");
+    assert_eq!(Amount::new(-5, 2).to_string(), "-0.05");
+    assert_eq!(Amount::new(1234, 0).to_string(), "1234");
+    assert_eq!(Amount::zero(2).to_string(), "0.00");
+    assert_eq!(
+        Amount::new(i64::MIN, 2).to_string(),
+        "-92233720368547758.08"
+    );
+}
+
+#[test]
+pub fn test_amount_from_str() {
+    let amount = Amount::from_str("12.34").unwrap();
+    assert_eq!(amount.value(), 1234);
+    assert_eq!(amount.scale(), 2);
+
+    let amount: Amount = "-0.05".parse().unwrap();
+    assert_eq!(amount.value(), -5);
+    assert_eq!(amount.scale(), 2);
+
+    let amount: Amount = "+42".parse().unwrap();
+    assert_eq!(amount.value(), 42);
+    assert_eq!(amount.scale(), 0);
+
+    assert_eq!(Amount::from_str(".5").unwrap().to_string(), "0.5");
+    assert_eq!(Amount::from_str("5.").unwrap().to_string(), "5");
+    assert_eq!(
+        Amount::from_str("-9223372036854775808").unwrap().value(),
+        i64::MIN
+    );
+
+    assert_eq!(Amount::from_str(""), Err(AmountError::EmptyInput));
+    assert_eq!(Amount::from_str("-"), Err(AmountError::EmptyInput));
+    assert_eq!(
+        Amount::from_str("."),
+        Err(AmountError::InvalidCharacter('.'))
+    );
+    assert_eq!(
+        Amount::from_str("12a"),
+        Err(AmountError::InvalidCharacter('a'))
+    );
+    assert_eq!(
+        Amount::from_str("1.2.3"),
+        Err(AmountError::MultipleDecimalPoints)
+    );
+    assert_eq!(
+        Amount::from_str("9223372036854775808"),
+        Err(AmountError::Overflow)
+    );
+}
+
+#[test]
+pub fn test_amount_to_f64() {
+    assert_eq!(Amount::new(1234, 2).to_f64(), 12.34);
+    assert_eq!(Amount::new(-5, 1).to_f64(), -0.5);
+    assert_eq!(Amount::zero(3).to_f64(), 0.0);
+}
+
+#[test]
+pub fn test_format_amount() {
+    // 零
+    assert_eq!(format_amount(0, 2, ',', '.'), "0.00");
+    assert_eq!(format_amount(0, 0, ',', '.'), "0");
+
+    // 无需千分位分隔符
+    assert_eq!(format_amount(99999, 2, ',', '.'), "999.99");
+    assert_eq!(format_amount(5, 3, ',', '.'), "0.005");
+
+    // 需要千分位分隔符
+    assert_eq!(format_amount(100000, 2, ',', '.'), "1,000.00");
+    assert_eq!(format_amount(123456789, 0, ',', '.'), "123,456,789");
+    assert_eq!(format_amount(1234567891, 2, ',', '.'), "12,345,678.91");
+
+    // 负数
+    assert_eq!(format_amount(-123456, 2, ',', '.'), "-1,234.56");
+    assert_eq!(format_amount(-99, 2, ',', '.'), "-0.99");
+    assert_eq!(
+        format_amount(i64::MIN, 2, ',', '.'),
+        "-92,233,720,368,547,758.08"
+    );
+
+    // 分隔符组合
+    assert_eq!(format_amount(123456, 2, ',', '.'), "1,234.56");
+    assert_eq!(format_amount(123456, 2, '.', ','), "1.234,56");
+    assert_eq!(format_amount(123456, 2, ' ', ','), "1 234,56");
+    assert_eq!(format_amount(123456, 2, '\'', '.'), "1'234.56");
+
+    // 最大小数位数
+    assert_eq!(
+        format_amount(i64::MAX, 18, ',', '.'),
+        "9.223372036854775807"
+    );
+}
+
+#[test]
+#[should_panic(expected = "scale must not exceed 18")]
+pub fn test_format_amount_scale_too_large() {
+    let _ = format_amount(1, 19, ',', '.');
+}
+
+#[test]
+#[should_panic(expected = "scale must not exceed 18")]
+pub fn test_format_currency_scale_too_large() {
+    let _ = format_currency(1, 39, "$", false);
+}
+
+#[test]
+pub fn test_format_currency() {
+    assert_eq!(format_currency(123456, 2, "$", false), "$1,234.56");
+    assert_eq!(format_currency(-123456, 2, "$", false), "-$1,234.56");
+    assert_eq!(format_currency(123456, 2, "¥", false), "¥1,234.56");
+    assert_eq!(format_currency(123456, 2, " €", true), "1,234.56 €");
+    assert_eq!(format_currency(-123456, 2, "元", true), "-1,234.56元");
+    assert_eq!(format_currency(0, 2, "$", false), "$0.00");
+}
+
+#[test]
+pub fn test_parse_amount() {
+    // 美式格式
+    assert_eq!(parse_amount("$1,234.56"), Ok((123456, 2)));
+    assert_eq!(parse_amount("1,234,567"), Ok((1234567, 0)));
+    assert_eq!(parse_amount("-$1,234.56"), Ok((-123456, 2)));
+    assert_eq!(parse_amount("$-1,234.56"), Ok((-123456, 2)));
+
+    // 欧式格式
+    assert_eq!(parse_amount("1.234,56 €"), Ok((123456, 2)));
+    assert_eq!(parse_amount("1.234.567"), Ok((1234567, 0)));
+    assert_eq!(parse_amount("-1.234,56 €"), Ok((-123456, 2)));
+
+    // 法式及瑞士格式
+    assert_eq!(parse_amount("1 234,56"), Ok((123456, 2)));
+    assert_eq!(parse_amount("1\u{a0}234,56"), Ok((123456, 2)));
+    assert_eq!(parse_amount("1'234.56"), Ok((123456, 2)));
+
+    // 其他
+    assert_eq!(parse_amount("-500.00"), Ok((-50000, 2)));
+    assert_eq!(parse_amount("¥ 88"), Ok((88, 0)));
+    assert_eq!(parse_amount("+0,5"), Ok((5, 1)));
+    assert_eq!(parse_amount("  42  "), Ok((42, 0)));
+}
+
+#[test]
+pub fn test_parse_amount_errors() {
+    assert_eq!(parse_amount(""), Err(AmountError::EmptyInput));
+    assert_eq!(parse_amount("   "), Err(AmountError::EmptyInput));
+    assert_eq!(parse_amount("$"), Err(AmountError::EmptyInput));
+    assert_eq!(parse_amount("-"), Err(AmountError::EmptyInput));
+    assert_eq!(
+        parse_amount("12a.5"),
+        Err(AmountError::InvalidCharacter('a'))
+    );
+    assert_eq!(
+        parse_amount("1.2.3"),
+        Err(AmountError::MultipleDecimalPoints)
+    );
+    assert_eq!(
+        parse_amount("1,234.5.6"),
+        Err(AmountError::MultipleDecimalPoints)
+    );
+    assert_eq!(
+        parse_amount("12,34.5"),
+        Err(AmountError::InvalidCharacter(','))
+    );
+    assert_eq!(
+        parse_amount("99,999,999,999,999,999,999"),
+        Err(AmountError::Overflow)
+    );
+}
+
+#[test]
+pub fn test_parse_amount_with_config() {
+    assert_eq!(parse_amount_with_config("1,234", '.', ','), Ok((1234, 0)));
+    assert_eq!(
+        parse_amount_with_config("$1,234.56", '.', ','),
+        Ok((123456, 2))
+    );
+    assert_eq!(
+        parse_amount_with_config("1.234,56 €", ',', '.'),
+        Ok((123456, 2))
+    );
+    assert_eq!(
+        parse_amount_with_config("1 234,56", ',', ' '),
+        Ok((123456, 2))
+    );
+    assert_eq!(
+        parse_amount_with_config("-1 234,56", ',', ' '),
+        Ok((-123456, 2))
+    );
+
+    assert_eq!(
+        parse_amount_with_config("", '.', ','),
+        Err(AmountError::EmptyInput)
+    );
+    assert_eq!(
+        parse_amount_with_config("1.234,56", '.', ','),
+        Err(AmountError::InvalidCharacter(','))
+    );
+    assert_eq!(
+        parse_amount_with_config("1,2,3", ',', '.'),
+        Err(AmountError::MultipleDecimalPoints)
+    );
+    assert_eq!(
+        parse_amount_with_config("1x", '.', ','),
+        Err(AmountError::InvalidCharacter('x'))
+    );
+    assert_eq!(
+        parse_amount_with_config("9223372036854775808", '.', ','),
+        Err(AmountError::Overflow)
+    );
+}
+
+#[test]
+pub fn test_split_amount() {
+    for total in [0, 1, 99, 100, 10000, 10001, -10, -10001, i64::MAX, i64::MIN] {
+        for n in 1..=7 {
+            let parts = split_amount(total, 2, n);
+            assert_eq!(parts.len(), n as usize);
+            assert_eq!(
+                parts.iter().map(|&p| p as i128).sum::<i128>(),
+                total as i128
+            );
+
+            let max = parts.iter().max().unwrap();
+            let min = parts.iter().min().unwrap();
+            assert!(max - min <= 1);
+        }
+    }
+
+    assert_eq!(split_amount(10000, 2, 3), vec![3334, 3333, 3333]);
+    assert_eq!(split_amount(10, 2, 4), vec![3, 3, 2, 2]);
+    assert_eq!(split_amount(-10, 2, 4), vec![-3, -3, -2, -2]);
+    assert_eq!(split_amount(2, 2, 5), vec![1, 1, 0, 0, 0]);
+    assert!(split_amount(100, 2, 0).is_empty());
+    assert_eq!(split_amount(100, 18, 3), vec![34, 33, 33]);
+}
+
+#[test]
+#[should_panic(expected = "scale must not exceed 18")]
+pub fn test_split_amount_scale_too_large() {
+    let _ = split_amount(100, 19, 3);
+}
+
+#[test]
+#[should_panic(expected = "scale must not exceed 18")]
+pub fn test_allocate_scale_too_large() {
+    let _ = allocate(100, 19, &[1, 1]);
+}
+
+#[test]
+pub fn test_allocate() {
+    assert_eq!(allocate(10000, 2, &[1, 1, 1]), vec![3334, 3333, 3333]);
+    assert_eq!(allocate(10000, 2, &[70, 20, 10]), vec![7000, 2000, 1000]);
+    assert_eq!(allocate(100, 2, &[1, 0, 1]), vec![50, 0, 50]);
+
+    // 余数最大的份优先获得剩余金额：11 * [3, 3, 4] / 10 = [3.3, 3.3, 4.4]，
+    // 取整后剩余 1，分配给余数最大的第三份
+    assert_eq!(allocate(11, 2, &[3, 3, 4]), vec![3, 3, 5]);
+    assert_eq!(allocate(-11, 2, &[3, 3, 4]), vec![-3, -3, -5]);
+    // 余数相同时优先分配给靠前的份
+    assert_eq!(allocate(2, 2, &[1, 1, 1]), vec![1, 1, 0]);
+
+    for total in [0, 1, 7, 999, -999, i64::MAX, i64::MIN] {
+        let ratios = [5, 3, 2, 7];
+        let shares = allocate(total, 2, &ratios);
+        assert_eq!(shares.len(), ratios.len());
+        assert_eq!(
+            shares.iter().map(|&s| s as i128).sum::<i128>(),
+            total as i128
+        );
+    }
+
+    assert!(allocate(100, 2, &[]).is_empty());
+    assert!(allocate(100, 2, &[0, 0]).is_empty());
+}
+
+#[test]
+pub fn test_tax_calculate() {
+    assert_eq!(tax_calculate(10000, 2, 2000), (10000, 2000));
+    assert_eq!(tax_calculate(10000, 2, 0), (10000, 0));
+    assert_eq!(tax_calculate(0, 2, 2000), (0, 0));
+
+    // 四舍五入：0.0594 -> 0.06，0.0549 -> 0.05，0.005 -> 0.01
+    assert_eq!(tax_calculate(99, 2, 600), (99, 6));
+    assert_eq!(tax_calculate(915, 2, 60), (915, 5));
+    assert_eq!(tax_calculate(5, 2, 10000), (5, 5));
+    assert_eq!(tax_calculate(1, 2, 5000), (1, 1));
+
+    // 负数金额（退款）
+    assert_eq!(tax_calculate(-99, 2, 600), (-99, -6));
+}
+
+#[test]
+pub fn test_tax_inclusive() {
+    assert_eq!(tax_inclusive(11300, 2, 1300), (10000, 1300));
+    assert_eq!(tax_inclusive(12000, 2, 2000), (10000, 2000));
+    assert_eq!(tax_inclusive(100, 2, 0), (100, 0));
+
+    for gross in [0, 1, 99, 100, 12345, 999999, -12345] {
+        for bps in [0, 300, 600, 1300, 2000, 10000] {
+            let (net, tax) = tax_inclusive(gross, 2, bps);
+            assert_eq!(net + tax, gross);
+        }
+    }
+}
+
+#[test]
+pub fn test_tax_round_trip() {
+    // 不含税金额计算出的含税总额，倒算后应得到原不含税金额
+    for net in [1, 99, 100, 12345, 999999] {
+        for bps in [300, 600, 1300, 2000] {
+            let (_, tax) = tax_calculate(net, 2, bps);
+            assert_eq!(tax_inclusive(net + tax, 2, bps), (net, tax));
+        }
+    }
+}
+
+#[test]
+pub fn test_discount() {
+    assert_eq!(discount(20000, 2, 1500), (17000, 3000));
+    assert_eq!(discount(20000, 2, 0), (20000, 0));
+    assert_eq!(discount(20000, 2, 10000), (0, 20000));
+    assert_eq!(discount(999, 2, 3333), (666, 333));
+
+    for amount in [0, 1, 99, 12345, -12345] {
+        for bps in [0, 500, 3333, 5000, 10000] {
+            let (discounted, given) = discount(amount, 2, bps);
+            assert_eq!(discounted + given, amount);
+        }
+    }
+
+    // 折扣率超过 100% 时折后金额为负数
+    assert_eq!(discount(20000, 2, 15000), (-10000, 30000));
+}
+
+#[test]
+pub fn test_tax_and_discount_large_rates() {
+    assert_eq!(tax_calculate(10000, 2, 20000), (10000, 20000));
+    assert_eq!(tax_calculate(i64::MAX, 2, 10000), (i64::MAX, i64::MAX));
+    assert_eq!(discount(i64::MAX, 2, 10000), (0, i64::MAX));
+}
+
+#[test]
+#[should_panic(expected = "amount overflow")]
+pub fn test_tax_calculate_overflow() {
+    let _ = tax_calculate(i64::MAX, 2, 20000);
+}
+
+#[test]
+#[should_panic(expected = "amount overflow")]
+pub fn test_discount_overflow() {
+    let _ = discount(i64::MAX, 2, 20000);
+}
+
+#[test]
+#[should_panic(expected = "scale must not exceed 18")]
+pub fn test_tax_inclusive_scale_too_large() {
+    let _ = tax_inclusive(100, 19, 1300);
+}
@@ -460,3 +460,726 @@ pub fn test_equal() {
     let time_offset = offset(date_time_east8, 10, DateTimeOffsetUnit::HOURS);
     assert_eq!(equal(&date_time_east7, &time_offset), false);
 }
+
+#[test]
+pub fn test_format_date_and_time_only() {
+    let naive = NaiveDateTime::parse_from_str("2024-06-05 08:09:07", "%Y-%m-%d %H:%M:%S").unwrap();
+    let date_time = Local.from_local_datetime(&naive).unwrap();
+
+    assert_eq!(format_date_only(&date_time), "2024-06-05");
+    assert_eq!(format_time_only(&date_time), "08:09:07");
+    assert_eq!(format_datetime_standard(&date_time), "2024-06-05T08:09:07");
+    assert_eq!(format_datetime_display(&date_time), "2024-06-05 08:09:07");
+
+    let date_time_utc = Utc.from_utc_datetime(&naive);
+    assert_eq!(
+        format_datetime_standard(&date_time_utc),
+        "2024-06-05T08:09:07"
+    );
+}
+
+#[test]
+pub fn test_format_round_trip() {
+    let naive = NaiveDateTime::parse_from_str("2023-12-31 23:59:58", "%Y-%m-%d %H:%M:%S").unwrap();
+    let date_time = Local.from_local_datetime(&naive).unwrap();
+
+    let display = format_datetime_display(&date_time);
+    let parsed = parse(&display, &String::from("%Y-%m-%d %H:%M:%S"), Local).unwrap();
+    assert_eq!(parsed, date_time);
+
+    let standard = format_datetime_standard(&date_time);
+    let parsed = parse(&standard, &String::from("%Y-%m-%dT%H:%M:%S"), Local).unwrap();
+    assert_eq!(parsed, date_time);
+
+    let combined = format!(
+        "{} {}",
+        format_date_only(&date_time),
+        format_time_only(&date_time)
+    );
+    assert_eq!(combined, display);
+}
+
+#[test]
+pub fn test_unix_timestamp_round_trip() {
+    let cases = [
+        ("1970-01-01 00:00:00", 0),
+        ("2000-01-01 00:00:00", 946_684_800),
+        ("2038-01-19 03:14:07", i32::MAX as i64),
+        ("2038-01-19 03:14:08", i32::MAX as i64 + 1),
+        ("1969-12-31 23:59:59", -1),
+    ];
+    for (text, expected) in cases {
+        let naive = NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S").unwrap();
+        let date_time = Utc.from_utc_datetime(&naive);
+        assert_eq!(to_unix_timestamp(&date_time), expected);
+        assert_eq!(from_unix_timestamp(expected, Utc).unwrap(), date_time);
+    }
+
+    let offset_east8 = FixedOffset::east_opt(8 * 3600).unwrap();
+    let date_time = from_unix_timestamp(0, offset_east8).unwrap();
+    assert_eq!(format_datetime_display(&date_time), "1970-01-01 08:00:00");
+    assert_eq!(to_unix_timestamp(&date_time), 0);
+
+    assert!(from_unix_timestamp(i64::MAX, Utc).is_none());
+}
+
+#[test]
+pub fn test_is_leap_year() {
+    assert!(is_leap_year(2000));
+    assert!(is_leap_year(2400));
+    assert!(is_leap_year(2024));
+    assert!(is_leap_year(1996));
+    assert!(!is_leap_year(1900));
+    assert!(!is_leap_year(2100));
+    assert!(!is_leap_year(2023));
+    assert!(!is_leap_year(2019));
+    assert!(is_leap_year(0));
+    assert!(is_leap_year(-4));
+    assert!(!is_leap_year(-100));
+}
+
+#[test]
+pub fn test_days_in_month() {
+    let leap = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    let common = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    for month in 1..=12u32 {
+        assert_eq!(days_in_month(2024, month), Some(leap[month as usize - 1]));
+        assert_eq!(days_in_month(2023, month), Some(common[month as usize - 1]));
+    }
+    assert_eq!(days_in_month(1900, 2), Some(28));
+    assert_eq!(days_in_month(2000, 2), Some(29));
+    assert_eq!(days_in_month(2023, 0), None);
+    assert_eq!(days_in_month(2023, 13), None);
+
+    assert_eq!(days_in_year(2024), 366);
+    assert_eq!(days_in_year(2000), 366);
+    assert_eq!(days_in_year(1900), 365);
+    assert_eq!(days_in_year(2023), 365);
+    assert_eq!(
+        (1..=12)
+            .map(|m| days_in_month(2024, m).unwrap())
+            .sum::<u32>(),
+        days_in_year(2024)
+    );
+}
+
+#[test]
+pub fn test_last_day_of_month() {
+    assert_eq!(last_day_of_month(2024, 2), Some(29));
+    assert_eq!(last_day_of_month(2023, 2), Some(28));
+    assert_eq!(last_day_of_month(2000, 2), Some(29));
+    assert_eq!(last_day_of_month(1900, 2), Some(28));
+
+    let expected = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    for (index, days) in expected.iter().enumerate() {
+        assert_eq!(last_day_of_month(2023, index as u32 + 1), Some(*days));
+    }
+
+    assert_eq!(last_day_of_month(2023, 0), None);
+    assert_eq!(last_day_of_month(2023, 13), None);
+    assert_eq!(last_day_of_month(i32::MAX, 1), None);
+    assert_eq!(last_day_of_month(i32::MIN, 1), None);
+}
+
+#[test]
+pub fn test_is_first_and_last_day_of_month() {
+    let to_local = |text: &str| {
+        let naive = NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S").unwrap();
+        Local.from_local_datetime(&naive).unwrap()
+    };
+
+    assert!(is_last_day_of_month(&to_local("2024-02-29 12:00:00")));
+    assert!(!is_last_day_of_month(&to_local("2024-02-28 12:00:00")));
+    assert!(is_last_day_of_month(&to_local("2023-02-28 12:00:00")));
+    assert!(is_last_day_of_month(&to_local("2023-12-31 23:59:59")));
+    assert!(!is_last_day_of_month(&to_local("2023-12-01 00:00:00")));
+
+    assert!(is_first_day_of_month(&to_local("2023-12-01 00:00:00")));
+    assert!(is_first_day_of_month(&to_local("2024-03-01 12:00:00")));
+    assert!(!is_first_day_of_month(&to_local("2024-02-29 12:00:00")));
+}
+
+#[test]
+pub fn test_timezone_offset() {
+    let date_time_utc = Utc.with_ymd_and_hms(2024, 6, 15, 10, 30, 0).unwrap();
+    assert_eq!(timezone_offset_seconds(&date_time_utc), 0);
+    assert_eq!(timezone_offset_hours_minutes(&date_time_utc), (0, 0));
+
+    let east8 = FixedOffset::east_opt(8 * 3600).unwrap();
+    let date_time_east8 = east8.with_ymd_and_hms(2024, 6, 15, 10, 30, 0).unwrap();
+    assert_eq!(timezone_offset_seconds(&date_time_east8), 8 * 3600);
+    assert_eq!(timezone_offset_hours_minutes(&date_time_east8), (8, 0));
+
+    let east545 = FixedOffset::east_opt(5 * 3600 + 45 * 60).unwrap();
+    let date_time_east545 = east545.with_ymd_and_hms(2024, 6, 15, 10, 30, 0).unwrap();
+    assert_eq!(timezone_offset_hours_minutes(&date_time_east545), (5, 45));
+
+    let west330 = FixedOffset::west_opt(3 * 3600 + 30 * 60).unwrap();
+    let date_time_west330 = west330.with_ymd_and_hms(2024, 6, 15, 10, 30, 0).unwrap();
+    assert_eq!(
+        timezone_offset_seconds(&date_time_west330),
+        -(3 * 3600 + 30 * 60)
+    );
+    assert_eq!(timezone_offset_hours_minutes(&date_time_west330), (-3, 30));
+
+    let now = Local::now();
+    assert_eq!(
+        timezone_offset_seconds(&now),
+        now.offset().local_minus_utc()
+    );
+    assert!(!timezone_name(&now).is_empty());
+}
+
+#[test]
+pub fn test_format_with_locale_months() {
+    let zh_months = [
+        "一月",
+        "二月",
+        "三月",
+        "四月",
+        "五月",
+        "六月",
+        "七月",
+        "八月",
+        "九月",
+        "十月",
+        "十一月",
+        "十二月",
+    ];
+    for (index, expected) in zh_months.iter().enumerate() {
+        let date_time = Utc
+            .with_ymd_and_hms(2024, index as u32 + 1, 10, 8, 0, 0)
+            .unwrap();
+        assert_eq!(
+            format_with_locale(&date_time, "%B", "zh").unwrap(),
+            *expected
+        );
+        assert_eq!(
+            format_with_locale(&date_time, "%B", "en").unwrap(),
+            date_time.format("%B").to_string()
+        );
+    }
+}
+
+#[test]
+pub fn test_format_with_locale_weekdays() {
+    let ja_weekdays = [
+        "月曜日",
+        "火曜日",
+        "水曜日",
+        "木曜日",
+        "金曜日",
+        "土曜日",
+        "日曜日",
+    ];
+    // 2024-06-10 是星期一
+    for (offset, expected) in ja_weekdays.iter().enumerate() {
+        let date_time = Utc
+            .with_ymd_and_hms(2024, 6, 10 + offset as u32, 8, 0, 0)
+            .unwrap();
+        assert_eq!(
+            format_with_locale(&date_time, "%A", "ja").unwrap(),
+            *expected
+        );
+        assert_eq!(
+            format_with_locale(&date_time, "%A", "ja_JP").unwrap(),
+            *expected
+        );
+    }
+}
+
+#[test]
+pub fn test_format_with_locale_am_pm_and_fallback() {
+    let morning = Utc.with_ymd_and_hms(2024, 6, 15, 9, 5, 0).unwrap();
+    let afternoon = Utc.with_ymd_and_hms(2024, 6, 15, 21, 5, 0).unwrap();
+
+    assert_eq!(format_with_locale(&morning, "%p", "zh").unwrap(), "上午");
+    assert_eq!(
+        format_with_locale(&afternoon, "%p", "zh-CN").unwrap(),
+        "下午"
+    );
+    assert_eq!(format_with_locale(&morning, "%p", "ja").unwrap(), "午前");
+    assert_eq!(format_with_locale(&afternoon, "%p", "JA").unwrap(), "午後");
+    assert_eq!(format_with_locale(&afternoon, "%p", "en").unwrap(), "PM");
+
+    // 未知语言环境回退为英语
+    assert_eq!(
+        format_with_locale(&afternoon, "%A %B %I:%M %p", "de").unwrap(),
+        "Saturday June 09:05 PM"
+    );
+    assert_eq!(
+        format_with_locale(&afternoon, "%A %B %I:%M %p", "").unwrap(),
+        "Saturday June 09:05 PM"
+    );
+
+    // 其它格式说明符和 %% 转义保持不变
+    assert_eq!(
+        format_with_locale(&morning, "%Y-%m-%d %H:%M 100%% %A", "zh").unwrap(),
+        "2024-06-15 09:05 100% 星期六"
+    );
+    assert!(format_with_locale(&morning, "%Q", "zh").is_none());
+}
+
+#[test]
+pub fn test_countdown_to_and_elapsed_since() {
+    let now = Utc.with_ymd_and_hms(2024, 6, 15, 10, 0, 0).unwrap();
+    let future = Utc.with_ymd_and_hms(2024, 6, 16, 11, 2, 3).unwrap();
+    let past = Utc.with_ymd_and_hms(2023, 12, 31, 23, 59, 59).unwrap();
+
+    let remaining = countdown_to(&future, &now).unwrap();
+    assert_eq!(remaining.num_seconds(), between(&now, &future));
+    assert_eq!(remaining, chrono::Duration::seconds(90_123));
+    assert_eq!(countdown_to(&past, &now), None);
+    assert_eq!(countdown_to(&now, &now), None);
+
+    let elapsed = elapsed_since(&past, &now).unwrap();
+    assert_eq!(elapsed.num_seconds(), between(&past, &now));
+    assert_eq!(elapsed_since(&future, &now), None);
+    assert_eq!(elapsed_since(&now, &now), Some(chrono::Duration::zero()));
+
+    let east8 = FixedOffset::east_opt(8 * 3600).unwrap();
+    let now_east8 = east8.with_ymd_and_hms(2024, 6, 15, 18, 0, 0).unwrap();
+    let target_east8 = east8.with_ymd_and_hms(2024, 6, 15, 18, 0, 30).unwrap();
+    assert_eq!(
+        countdown_to(&target_east8, &now_east8),
+        Some(chrono::Duration::seconds(30))
+    );
+}
+
+#[test]
+pub fn test_is_same_day_month_year() {
+    let to_utc = |y: i32, m: u32, d: u32, h: u32| Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap();
+
+    // 同一天不同时间
+    assert!(is_same_day(
+        &to_utc(2024, 6, 15, 0),
+        &to_utc(2024, 6, 15, 23)
+    ));
+    assert!(is_same_month(
+        &to_utc(2024, 6, 15, 0),
+        &to_utc(2024, 6, 15, 23)
+    ));
+    assert!(is_same_year(
+        &to_utc(2024, 6, 15, 0),
+        &to_utc(2024, 6, 15, 23)
+    ));
+
+    // 相邻两天
+    assert!(!is_same_day(
+        &to_utc(2024, 6, 15, 23),
+        &to_utc(2024, 6, 16, 0)
+    ));
+    assert!(is_same_month(
+        &to_utc(2024, 6, 15, 23),
+        &to_utc(2024, 6, 16, 0)
+    ));
+    assert!(!is_same_day(
+        &to_utc(2024, 6, 30, 23),
+        &to_utc(2024, 7, 1, 0)
+    ));
+    assert!(!is_same_month(
+        &to_utc(2024, 6, 30, 23),
+        &to_utc(2024, 7, 1, 0)
+    ));
+
+    // 同年不同月
+    assert!(!is_same_month(
+        &to_utc(2024, 1, 15, 12),
+        &to_utc(2024, 12, 15, 12)
+    ));
+    assert!(is_same_year(
+        &to_utc(2024, 1, 15, 12),
+        &to_utc(2024, 12, 15, 12)
+    ));
+
+    // 跨年
+    assert!(!is_same_day(
+        &to_utc(2023, 12, 31, 23),
+        &to_utc(2024, 1, 1, 0)
+    ));
+    assert!(!is_same_month(
+        &to_utc(2023, 12, 31, 23),
+        &to_utc(2024, 1, 1, 0)
+    ));
+    assert!(!is_same_year(
+        &to_utc(2023, 12, 31, 23),
+        &to_utc(2024, 1, 1, 0)
+    ));
+    // 不同年份的同月同日
+    assert!(!is_same_day(
+        &to_utc(2023, 6, 15, 12),
+        &to_utc(2024, 6, 15, 12)
+    ));
+    assert!(!is_same_month(
+        &to_utc(2023, 6, 15, 12),
+        &to_utc(2024, 6, 15, 12)
+    ));
+}
+
+#[test]
+pub fn test_format_duration() {
+    assert_eq!(format_duration(&chrono::Duration::zero()), "0 seconds");
+    assert_eq!(
+        format_duration(&chrono::Duration::milliseconds(999)),
+        "0 seconds"
+    );
+    assert_eq!(format_duration(&chrono::Duration::seconds(1)), "1 second");
+    assert_eq!(
+        format_duration(&chrono::Duration::seconds(59)),
+        "59 seconds"
+    );
+    assert_eq!(format_duration(&chrono::Duration::seconds(60)), "1 minute");
+    assert_eq!(
+        format_duration(&chrono::Duration::seconds(3_661)),
+        "1 hour 1 minute 1 second"
+    );
+    assert_eq!(
+        format_duration(&chrono::Duration::seconds(90_000)),
+        "1 day 1 hour"
+    );
+    assert_eq!(format_duration(&chrono::Duration::days(3)), "3 days");
+    assert_eq!(
+        format_duration(&chrono::Duration::seconds(-90)),
+        "negative 1 minute 30 seconds"
+    );
+}
+
+#[test]
+pub fn test_format_elapsed() {
+    let start = Utc.with_ymd_and_hms(2024, 6, 15, 10, 0, 0).unwrap();
+
+    let sub_minute = Utc.with_ymd_and_hms(2024, 6, 15, 10, 0, 42).unwrap();
+    assert_eq!(format_elapsed(&start, &sub_minute), "42 seconds");
+    assert_eq!(format_elapsed(&start, &start), "0 seconds");
+
+    let multi_hour = Utc.with_ymd_and_hms(2024, 6, 15, 15, 7, 3).unwrap();
+    assert_eq!(
+        format_elapsed(&start, &multi_hour),
+        "5 hours 7 minutes 3 seconds"
+    );
+
+    let next_days = Utc.with_ymd_and_hms(2024, 6, 17, 11, 0, 0).unwrap();
+    assert_eq!(format_elapsed(&start, &next_days), "2 days 1 hour");
+
+    assert_eq!(format_elapsed(&sub_minute, &start), "negative 42 seconds");
+    assert_eq!(
+        format_elapsed(&multi_hour, &start),
+        "negative 5 hours 7 minutes 3 seconds"
+    );
+
+    let offset = FixedOffset::east_opt(8 * 3600).unwrap();
+    let start = offset.with_ymd_and_hms(2024, 6, 15, 10, 0, 0).unwrap();
+    let end = offset.with_ymd_and_hms(2024, 6, 15, 10, 3, 42).unwrap();
+    assert_eq!(format_elapsed(&start, &end), "3 minutes 42 seconds");
+}
+
+#[test]
+pub fn test_format_elapsed_since() {
+    let start = Local::now() - chrono::Duration::minutes(90);
+    assert!(format_elapsed_since(&start).starts_with("1 hour 30 minutes"));
+
+    let start = Utc::now() + chrono::Duration::hours(3);
+    assert!(format_elapsed_since(&start).starts_with("negative 2 hours 59 minutes"));
+}
+
+#[test]
+pub fn test_parse_or_default() {
+    let fmt = "%Y-%m-%d %H:%M:%S";
+    let default = Local.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+
+    let parsed = parse_or_default("2024-06-15 08:30:00", fmt, default);
+    assert_eq!(
+        parsed,
+        Local.with_ymd_and_hms(2024, 6, 15, 8, 30, 0).unwrap()
+    );
+
+    assert_eq!(parse_or_default("", fmt, default), default);
+    assert_eq!(parse_or_default("2024-06-15", fmt, default), default);
+    assert_eq!(
+        parse_or_default("2024-02-30 00:00:00", fmt, default),
+        default
+    );
+    assert_eq!(
+        parse_or_default("2024-06-15 08:30:00", "%d/%m/%Y", default),
+        default
+    );
+}
+
+#[test]
+pub fn test_parse_or_now() {
+    let fmt = "%Y-%m-%d %H:%M:%S";
+    let parsed = parse_or_now("2024-06-15 08:30:00", fmt);
+    assert_eq!(
+        parsed,
+        Local.with_ymd_and_hms(2024, 6, 15, 8, 30, 0).unwrap()
+    );
+
+    let before = Local::now();
+    let fallback = parse_or_now("invalid", fmt);
+    let after = Local::now();
+    assert!(fallback >= before && fallback <= after);
+    assert!((fallback - before).num_seconds().abs() < 5);
+}
+
+/// 测试用时区：标准时间为 UTC+1，每年 4 月至 9 月（按 UTC 日期）实行夏令时 UTC+2
+#[derive(Debug, Clone, Copy)]
+struct SummerTimeZone;
+
+impl SummerTimeZone {
+    fn offset_for(month: u32) -> FixedOffset {
+        let hours = if (4..=9).contains(&month) { 2 } else { 1 };
+        FixedOffset::east_opt(hours * 3600).unwrap()
+    }
+}
+
+impl TimeZone for SummerTimeZone {
+    type Offset = FixedOffset;
+
+    fn from_offset(_offset: &FixedOffset) -> Self {
+        SummerTimeZone
+    }
+
+    fn offset_from_local_date(
+        &self,
+        local: &chrono::NaiveDate,
+    ) -> chrono::LocalResult<FixedOffset> {
+        chrono::LocalResult::Single(Self::offset_for(local.month()))
+    }
+
+    fn offset_from_local_datetime(
+        &self,
+        local: &NaiveDateTime,
+    ) -> chrono::LocalResult<FixedOffset> {
+        chrono::LocalResult::Single(Self::offset_for(local.month()))
+    }
+
+    fn offset_from_utc_date(&self, utc: &chrono::NaiveDate) -> FixedOffset {
+        Self::offset_for(utc.month())
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+        Self::offset_for(utc.month())
+    }
+}
+
+#[test]
+pub fn test_utc_offset_seconds() {
+    let east8 = FixedOffset::east_opt(8 * 3600).unwrap();
+    let west = FixedOffset::west_opt(3 * 3600 + 30 * 60).unwrap();
+    assert_eq!(
+        utc_offset_seconds(&east8.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()),
+        28800
+    );
+    assert_eq!(
+        utc_offset_seconds(&west.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()),
+        -12600
+    );
+    assert_eq!(
+        utc_offset_seconds(&Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()),
+        0
+    );
+
+    let winter = SummerTimeZone
+        .with_ymd_and_hms(2024, 1, 15, 12, 0, 0)
+        .unwrap();
+    let summer = SummerTimeZone
+        .with_ymd_and_hms(2024, 7, 15, 12, 0, 0)
+        .unwrap();
+    assert_eq!(utc_offset_seconds(&winter), 3600);
+    assert_eq!(utc_offset_seconds(&summer), 7200);
+
+    let now = Local::now();
+    assert_eq!(utc_offset_seconds(&now), timezone_offset_seconds(&now));
+}
+
+#[test]
+pub fn test_is_dst_observed() {
+    // 固定偏移量的时区没有夏令时
+    for hours in [-11, -5, 0, 8, 14] {
+        let offset = FixedOffset::east_opt(hours * 3600).unwrap();
+        for month in [1, 4, 7, 10] {
+            let dt = offset.with_ymd_and_hms(2024, month, 15, 12, 0, 0).unwrap();
+            assert!(!is_dst_observed(&dt));
+        }
+    }
+    assert!(!is_dst_observed(
+        &Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap()
+    ));
+
+    // 有夏令时规则的时区
+    for month in 1..=12 {
+        let dt = SummerTimeZone
+            .with_ymd_and_hms(2024, month, 15, 12, 0, 0)
+            .unwrap();
+        assert_eq!(is_dst_observed(&dt), (4..=9).contains(&month));
+    }
+
+    // 本地时区：处于夏令时的时刻偏移量一定大于当年的最小偏移量
+    let now = Local::now();
+    if is_dst_observed(&now) {
+        let january = Local.with_ymd_and_hms(now.year(), 1, 1, 12, 0, 0).unwrap();
+        let july = Local.with_ymd_and_hms(now.year(), 7, 1, 12, 0, 0).unwrap();
+        let standard = utc_offset_seconds(&january).min(utc_offset_seconds(&july));
+        assert!(utc_offset_seconds(&now) > standard);
+    }
+}
+
+#[test]
+pub fn test_iter_months_in_range() {
+    let start = Utc.with_ymd_and_hms(2023, 11, 15, 10, 30, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+    let months: Vec<DateTime<Utc>> = iter_months_in_range(&start, &end).collect();
+    assert_eq!(
+        months,
+        vec![
+            Utc.with_ymd_and_hms(2023, 11, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2023, 12, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap(),
+        ]
+    );
+
+    // 同一个月只生成一个值，起点晚于终点时不生成任何值
+    assert_eq!(iter_months_in_range(&start, &start).count(), 1);
+    assert_eq!(iter_months_in_range(&end, &start).count(), 0);
+
+    // 与 between 交叉验证：相邻两个月首之间的秒数等于上一个月的天数
+    let start = Utc.with_ymd_and_hms(2019, 7, 20, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2024, 6, 5, 0, 0, 0).unwrap();
+    let months: Vec<DateTime<Utc>> = iter_months_in_range(&start, &end).collect();
+    assert_eq!(months.len(), 5 * 12);
+    for pair in months.windows(2) {
+        let days = days_in_month(pair[0].year(), pair[0].month()).unwrap();
+        assert_eq!(between(&pair[0], &pair[1]), i64::from(days) * 86400);
+    }
+    let total_days: u32 = months[..months.len() - 1]
+        .iter()
+        .map(|dt| days_in_month(dt.year(), dt.month()).unwrap())
+        .sum();
+    // 2019-07-01 到 2024-06-01，期间经过 2020 和 2024 两个闰二月
+    assert_eq!(total_days, 5 * 365 + 2 - 30);
+    assert_eq!(
+        between(&months[0], months.last().unwrap()),
+        i64::from(total_days) * 86400
+    );
+
+    // 固定偏移时区
+    let tz = FixedOffset::east_opt(8 * 3600).unwrap();
+    let start = tz.with_ymd_and_hms(2024, 12, 31, 23, 0, 0).unwrap();
+    let end = tz.with_ymd_and_hms(2025, 1, 1, 1, 0, 0).unwrap();
+    let months: Vec<DateTime<FixedOffset>> = iter_months_in_range(&start, &end).collect();
+    assert_eq!(
+        months,
+        vec![
+            tz.with_ymd_and_hms(2024, 12, 1, 0, 0, 0).unwrap(),
+            tz.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+        ]
+    );
+}
+
+#[test]
+pub fn test_iter_quarters_and_years_in_range() {
+    let start = Utc.with_ymd_and_hms(2023, 2, 15, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2024, 10, 1, 0, 0, 0).unwrap();
+    let quarters: Vec<(i32, u32)> = iter_quarters_in_range(&start, &end)
+        .map(|dt| (dt.year(), dt.month()))
+        .collect();
+    assert_eq!(
+        quarters,
+        vec![
+            (2023, 1),
+            (2023, 4),
+            (2023, 7),
+            (2023, 10),
+            (2024, 1),
+            (2024, 4),
+            (2024, 7),
+            (2024, 10),
+        ]
+    );
+    assert!(iter_quarters_in_range(&start, &end).all(|dt| dt.day() == 1 && dt.hour() == 0));
+    assert_eq!(iter_quarters_in_range(&end, &start).count(), 0);
+
+    let years: Vec<DateTime<Utc>> = iter_years_in_range(&start, &end).collect();
+    assert_eq!(
+        years,
+        vec![
+            Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        ]
+    );
+    assert_eq!(
+        between(&years[0], &years[1]),
+        i64::from(days_in_year(2023)) * 86400
+    );
+    assert_eq!(iter_years_in_range(&end, &start).count(), 0);
+
+    // 迭代器按需生成，可以只取前几个值
+    let start = Utc.with_ymd_and_hms(1, 1, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(9999, 12, 31, 0, 0, 0).unwrap();
+    let first: Vec<i32> = iter_years_in_range(&start, &end)
+        .take(3)
+        .map(|dt| dt.year())
+        .collect();
+    assert_eq!(first, vec![1, 2, 3]);
+    assert_eq!(iter_months_in_range(&start, &end).count(), 9999 * 12);
+}
+
+#[test]
+pub fn test_with_time() {
+    let dt = Utc.with_ymd_and_hms(2024, 6, 15, 10, 30, 45).unwrap()
+        + chrono::Duration::milliseconds(123);
+    assert_eq!(
+        with_time(&dt, 9, 0, 0),
+        Some(Utc.with_ymd_and_hms(2024, 6, 15, 9, 0, 0).unwrap())
+    );
+    assert_eq!(
+        with_time(&dt, 23, 59, 59),
+        Some(Utc.with_ymd_and_hms(2024, 6, 15, 23, 59, 59).unwrap())
+    );
+    assert_eq!(
+        with_time(&dt, 0, 0, 0),
+        Some(start_time_of_day(&dt).unwrap())
+    );
+    assert_eq!(with_time(&dt, 24, 0, 0), None);
+    assert_eq!(with_time(&dt, 12, 60, 0), None);
+    assert_eq!(with_time(&dt, 12, 0, 60), None);
+
+    // 保留时区和日期
+    let tz = FixedOffset::east_opt(8 * 3600).unwrap();
+    let dt = tz.with_ymd_and_hms(2024, 1, 1, 1, 0, 0).unwrap();
+    let result = with_time(&dt, 18, 30, 0).unwrap();
+    assert_eq!(result, tz.with_ymd_and_hms(2024, 1, 1, 18, 30, 0).unwrap());
+    assert_eq!(result.offset(), dt.offset());
+}
+
+#[test]
+pub fn test_with_date() {
+    let dt = Utc.with_ymd_and_hms(2024, 6, 15, 10, 30, 45).unwrap()
+        + chrono::Duration::milliseconds(123);
+    let result = with_date(&dt, 2025, 12, 31).unwrap();
+    assert_eq!(
+        (result.year(), result.month(), result.day()),
+        (2025, 12, 31)
+    );
+    assert_eq!(
+        (result.hour(), result.minute(), result.second()),
+        (10, 30, 45)
+    );
+    assert_eq!(result.timestamp_subsec_millis(), 123);
+
+    assert!(with_date(&dt, 2024, 2, 29).is_some());
+    assert_eq!(with_date(&dt, 2023, 2, 29), None);
+    assert_eq!(with_date(&dt, 2024, 4, 31), None);
+    assert_eq!(with_date(&dt, 2024, 13, 1), None);
+    assert_eq!(with_date(&dt, 2024, 0, 1), None);
+    assert_eq!(with_date(&dt, 2024, 1, 0), None);
+
+    let tz = FixedOffset::west_opt(5 * 3600).unwrap();
+    let dt = tz.with_ymd_and_hms(2024, 3, 10, 22, 15, 0).unwrap();
+    assert_eq!(
+        with_date(&dt, 1999, 12, 31),
+        Some(tz.with_ymd_and_hms(1999, 12, 31, 22, 15, 0).unwrap())
+    );
+}
@@ -40,3 +40,646 @@ pub fn test_write_and_read_dir() {
         Err(e) => assert!(false, "Failed to read dir: {}", e),
     }
 }
+
+#[cfg(feature = "file-json")]
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct JsonRecord {
+    id: u64,
+    name: String,
+    score: f64,
+    active: bool,
+    tags: Vec<String>,
+    parent: Option<u64>,
+}
+
+#[cfg(feature = "file-json")]
+fn json_record() -> JsonRecord {
+    JsonRecord {
+        id: 42,
+        name: String::from("测试"),
+        score: 98.5,
+        active: true,
+        tags: vec![String::from("a"), String::from("b")],
+        parent: None,
+    }
+}
+
+#[cfg(feature = "file-json")]
+#[test]
+pub fn test_write_and_read_json() {
+    let file_path = format!(
+        "{}/huturs_json_{}.json",
+        std::env::temp_dir().display(),
+        huturs_core::current_timestamp_millis()
+    );
+    let record = json_record();
+
+    file::write_json(&file_path, &record, false).unwrap();
+    let content = file::read_file(&file_path).unwrap();
+    assert!(!content.contains('\n'));
+    let loaded: JsonRecord = file::read_json(&file_path).unwrap();
+    assert_eq!(loaded, record);
+
+    file::write_json(&file_path, &record, true).unwrap();
+    let content = file::read_file(&file_path).unwrap();
+    assert!(content.starts_with("{\n  \"id\": 42,\n  \"name\": \"测试\","));
+    let loaded: JsonRecord = file::read_json(&file_path).unwrap();
+    assert_eq!(loaded, record);
+
+    file::delete_file(&file_path).unwrap();
+}
+
+#[cfg(feature = "file-json")]
+#[test]
+pub fn test_read_json_invalid_data() {
+    let file_path = format!(
+        "{}/huturs_invalid_{}.json",
+        std::env::temp_dir().display(),
+        huturs_core::current_timestamp_millis()
+    );
+
+    file::write_file(&file_path, "{ not json").unwrap();
+    let error = file::read_json::<JsonRecord>(&file_path).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+
+    file::write_file(&file_path, "{\"id\": 1}").unwrap();
+    let error = file::read_json::<JsonRecord>(&file_path).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+
+    file::delete_file(&file_path).unwrap();
+
+    let error = file::read_json::<JsonRecord>(&file_path).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
+}
+
+#[cfg(feature = "file-csv")]
+#[test]
+pub fn test_write_and_read_csv_rows() {
+    let file_path = format!(
+        "{}/huturs_csv_{}.csv",
+        std::env::temp_dir().display(),
+        huturs_core::current_timestamp_millis()
+    );
+    let rows = vec![
+        vec![String::from("1"), String::from("plain"), String::from("")],
+        vec![
+            String::from("2"),
+            String::from("Tom, Jr."),
+            String::from("say \"hi\""),
+        ],
+        vec![String::from("3"), String::from("line1\nline2")],
+        vec![
+            String::from("4"),
+            String::from("张三"),
+            String::from("x"),
+            String::from("extra"),
+        ],
+    ];
+
+    file::write_csv_rows(&file_path, &["id", "name", "note"], &rows).unwrap();
+    let content = file::read_file(&file_path).unwrap();
+    assert_eq!(
+        content,
+        "id,name,note\r\n\
+         1,plain,\r\n\
+         2,\"Tom, Jr.\",\"say \"\"hi\"\"\"\r\n\
+         3,\"line1\nline2\",\r\n\
+         4,张三,x\r\n"
+    );
+
+    let (headers, loaded) = file::read_csv_rows(&file_path).unwrap();
+    assert_eq!(headers, vec!["id", "name", "note"]);
+    assert_eq!(loaded.len(), 4);
+    assert_eq!(loaded[0], vec!["1", "plain", ""]);
+    assert_eq!(loaded[1], vec!["2", "Tom, Jr.", "say \"hi\""]);
+    assert_eq!(loaded[2], vec!["3", "line1\nline2", ""]);
+    assert_eq!(loaded[3], vec!["4", "张三", "x"]);
+
+    file::delete_file(&file_path).unwrap();
+}
+
+#[cfg(feature = "file-csv")]
+#[test]
+pub fn test_read_csv_rows_edge_cases() {
+    let file_path = format!(
+        "{}/huturs_csv_edge_{}.csv",
+        std::env::temp_dir().display(),
+        huturs_core::current_timestamp_millis()
+    );
+
+    file::write_file(&file_path, "").unwrap();
+    let (headers, rows) = file::read_csv_rows(&file_path).unwrap();
+    assert!(headers.is_empty());
+    assert!(rows.is_empty());
+
+    file::write_file(&file_path, "a,b\n1,2\n3,4").unwrap();
+    let (headers, rows) = file::read_csv_rows(&file_path).unwrap();
+    assert_eq!(headers, vec!["a", "b"]);
+    assert_eq!(rows, vec![vec!["1", "2"], vec!["3", "4"]]);
+
+    file::write_file(&file_path, "a,b\n\"unterminated,2\n").unwrap();
+    let error = file::read_csv_rows(&file_path).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+
+    file::delete_file(&file_path).unwrap();
+}
+
+#[test]
+pub fn test_atomic_write() {
+    let dir = std::env::temp_dir().join(format!(
+        "huturs_atomic_{}",
+        huturs_core::current_timestamp_millis()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    let file_path = dir.join("data.txt").display().to_string();
+
+    file::atomic_write(&file_path, "first version").unwrap();
+    assert_eq!(file::read_file(&file_path).unwrap(), "first version");
+
+    // 模拟写入中途进程退出：临时文件只写了一半，目标文件不应受影响
+    std::fs::write(dir.join(".data.txt.interrupted.tmp"), "second ver").unwrap();
+    assert_eq!(file::read_file(&file_path).unwrap(), "first version");
+
+    file::atomic_write(&file_path, "second version").unwrap();
+    assert_eq!(file::read_file(&file_path).unwrap(), "second version");
+
+    file::atomic_write_bytes(&file_path, &[0xE4, 0xBD, 0xA0, 0xE5, 0xA5, 0xBD]).unwrap();
+    assert_eq!(file::read_file(&file_path).unwrap(), "你好");
+
+    // 成功写入后除了模拟残留的临时文件外，不应留下其它临时文件
+    let entries = read_dirs(dir.to_str().unwrap()).unwrap();
+    assert_eq!(entries.len(), 2);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+pub fn test_atomic_write_failure() {
+    let dir = std::env::temp_dir().join(format!(
+        "huturs_atomic_fail_{}",
+        huturs_core::current_timestamp_millis()
+    ));
+    let file_path = dir.join("missing").join("data.txt").display().to_string();
+    assert!(file::atomic_write(&file_path, "content").is_err());
+    assert!(file::atomic_write("", "content").is_err());
+
+    // 目标路径是一个目录时重命名失败，临时文件需要被清理
+    std::fs::create_dir_all(dir.join("target")).unwrap();
+    std::fs::write(dir.join("target").join("keep.txt"), "keep").unwrap();
+    let target = dir.join("target").display().to_string();
+    assert!(file::atomic_write(&target, "content").is_err());
+    assert_eq!(read_dirs(dir.to_str().unwrap()).unwrap().len(), 1);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+/// 在临时目录下创建一个已知结构的目录树，返回根目录
+fn create_sample_tree(name: &str) -> std::path::PathBuf {
+    let root = std::env::temp_dir().join(format!(
+        "huturs_{}_{}",
+        name,
+        huturs_core::current_timestamp_millis()
+    ));
+    std::fs::create_dir_all(root.join("a").join("b")).unwrap();
+    std::fs::create_dir_all(root.join("empty")).unwrap();
+    std::fs::write(root.join("root.txt"), "x".repeat(10)).unwrap();
+    std::fs::write(root.join("a").join("one.rs"), "x".repeat(300)).unwrap();
+    std::fs::write(root.join("a").join("two.TXT"), "x".repeat(20)).unwrap();
+    std::fs::write(root.join("a").join("b").join("deep.md"), "x".repeat(500)).unwrap();
+    std::fs::write(root.join("a").join("b").join("notes.txt"), "").unwrap();
+    root
+}
+
+#[test]
+pub fn test_total_dir_size_and_largest_files() {
+    let root = create_sample_tree("dir_size");
+    let root_str = root.to_str().unwrap();
+
+    assert_eq!(file::total_dir_size(root_str).unwrap(), 830);
+    assert_eq!(
+        file::total_dir_size(root.join("empty").to_str().unwrap()).unwrap(),
+        0
+    );
+
+    let largest = file::largest_files(root_str, 3).unwrap();
+    assert_eq!(
+        largest,
+        vec![
+            (root.join("a").join("b").join("deep.md"), 500),
+            (root.join("a").join("one.rs"), 300),
+            (root.join("a").join("two.TXT"), 20),
+        ]
+    );
+    assert_eq!(file::largest_files(root_str, 100).unwrap().len(), 5);
+    assert!(file::largest_files(root_str, 0).unwrap().is_empty());
+
+    #[cfg(unix)]
+    {
+        // 符号链接不计入统计
+        std::os::unix::fs::symlink(root.join("a"), root.join("link")).unwrap();
+        assert_eq!(file::total_dir_size(root_str).unwrap(), 830);
+    }
+
+    assert!(file::total_dir_size(root.join("missing").to_str().unwrap()).is_err());
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+pub fn test_search_files_by_extension() {
+    let root = create_sample_tree("search_ext");
+    let root_str = root.to_str().unwrap();
+
+    assert_eq!(
+        file::search_files_by_extension(root_str, "txt").unwrap(),
+        vec![
+            root.join("a").join("b").join("notes.txt"),
+            root.join("a").join("two.TXT"),
+            root.join("root.txt"),
+        ]
+    );
+    assert_eq!(
+        file::search_files_by_extension(root_str, "RS").unwrap(),
+        vec![root.join("a").join("one.rs")]
+    );
+    assert!(file::search_files_by_extension(root_str, "toml")
+        .unwrap()
+        .is_empty());
+    assert!(file::search_files_by_extension(root_str, ".txt")
+        .unwrap()
+        .is_empty());
+
+    #[cfg(unix)]
+    {
+        // 无权限访问的子目录会被跳过（以 root 身份运行时权限不生效，因此只断言不报错）
+        use std::os::unix::fs::PermissionsExt;
+        let locked = root.join("a").join("b");
+        std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o000)).unwrap();
+        let result = file::search_files_by_extension(root_str, "txt");
+        std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o755)).unwrap();
+        assert!(result.unwrap().contains(&root.join("root.txt")));
+    }
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+pub fn test_search_files_by_name() {
+    let root = create_sample_tree("search_name");
+    let root_str = root.to_str().unwrap();
+
+    assert_eq!(
+        file::search_files_by_name(root_str, "o").unwrap(),
+        vec![
+            root.join("a").join("b").join("notes.txt"),
+            root.join("a").join("one.rs"),
+            root.join("a").join("two.TXT"),
+            root.join("root.txt"),
+        ]
+    );
+    assert_eq!(
+        file::search_files_by_name(root_str, "deep").unwrap(),
+        vec![root.join("a").join("b").join("deep.md")]
+    );
+    // 只匹配文件名，不匹配目录名
+    assert!(file::search_files_by_name(root_str, "empty")
+        .unwrap()
+        .is_empty());
+    assert!(file::search_files_by_name(root_str, "*.txt")
+        .unwrap()
+        .is_empty());
+    assert!(file::search_files_by_name(root.join("missing").to_str().unwrap(), "a").is_err());
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+pub fn test_file_line_count() {
+    let file_path = format!(
+        "{}/huturs_lines_{}.txt",
+        std::env::temp_dir().display(),
+        huturs_core::current_timestamp_millis()
+    );
+    let cases = [
+        String::new(),
+        String::from("\n"),
+        String::from("one line"),
+        String::from("one line\n"),
+        String::from("a\nb\r\nc"),
+        String::from("\n\n\n"),
+        (0..50_000)
+            .map(|i| format!("line {} of a moderately large file", i))
+            .collect::<Vec<String>>()
+            .join("\n"),
+    ];
+    for content in cases.iter() {
+        file::write_file(&file_path, content).unwrap();
+        let expected = file::read_file(&file_path).unwrap().lines().count();
+        assert_eq!(file::file_line_count(&file_path).unwrap(), expected);
+    }
+    assert_eq!(file::file_line_count(&file_path).unwrap(), 50_000);
+
+    file::delete_file(&file_path).unwrap();
+    assert!(file::file_line_count(&file_path).is_err());
+}
+
+#[test]
+pub fn test_file_word_count() {
+    let file_path = format!(
+        "{}/huturs_words_{}.txt",
+        std::env::temp_dir().display(),
+        huturs_core::current_timestamp_millis()
+    );
+
+    file::write_file(&file_path, "").unwrap();
+    assert_eq!(file::file_word_count(&file_path).unwrap(), 0);
+
+    file::write_file(&file_path, "hello world\n  foo\tbar  \n\nbaz").unwrap();
+    assert_eq!(file::file_word_count(&file_path).unwrap(), 5);
+
+    std::fs::write(&file_path, [0xFF, 0xFE, 0x20]).unwrap();
+    let error = file::file_word_count(&file_path).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+
+    file::delete_file(&file_path).unwrap();
+}
+
+#[cfg(feature = "file-encoding")]
+#[test]
+pub fn test_read_file_with_encoding_detection() {
+    let file_path = format!(
+        "{}/huturs_encoding_{}.txt",
+        std::env::temp_dir().display(),
+        huturs_core::current_timestamp_millis()
+    );
+    let text = "héllo, 世界 😀";
+    let utf16_units: Vec<u16> = text.encode_utf16().collect();
+
+    let mut cases: Vec<(Vec<u8>, &str, &str)> = vec![
+        (text.as_bytes().to_vec(), text, "UTF-8"),
+        (b"\xEF\xBB\xBFplain".to_vec(), "plain", "UTF-8"),
+        (Vec::new(), "", "UTF-8"),
+        (b"caf\xe9 \xa9 2024".to_vec(), "café © 2024", "ISO-8859-1"),
+        (vec![0x80, 0xFF, 0x41], "\u{80}ÿA", "ISO-8859-1"),
+    ];
+
+    let mut utf16_le = vec![0xFF, 0xFE];
+    utf16_le.extend(utf16_units.iter().flat_map(|unit| unit.to_le_bytes()));
+    cases.push((utf16_le, text, "UTF-16LE"));
+
+    let mut utf16_be = vec![0xFE, 0xFF];
+    utf16_be.extend(utf16_units.iter().flat_map(|unit| unit.to_be_bytes()));
+    cases.push((utf16_be, text, "UTF-16BE"));
+
+    // BOM 后的内容不是合法的 UTF-16 时回退到 ISO-8859-1
+    cases.push((vec![0xFF, 0xFE, 0x41], "ÿþA", "ISO-8859-1"));
+
+    for (bytes, expected_content, expected_encoding) in cases {
+        std::fs::write(&file_path, &bytes).unwrap();
+        let (content, encoding) = file::read_file_with_encoding_detection(&file_path).unwrap();
+        assert_eq!(content, expected_content);
+        assert_eq!(encoding, expected_encoding);
+    }
+    file::delete_file(&file_path).unwrap();
+
+    assert!(file::read_file_with_encoding_detection("").is_err());
+    let error = file::read_file_with_encoding_detection(&file_path).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
+}
+
+#[test]
+pub fn test_diff_files() {
+    use huturs_core::file::DiffLine;
+
+    let prefix = format!(
+        "{}/huturs_diff_{}",
+        std::env::temp_dir().display(),
+        huturs_core::current_timestamp_millis()
+    );
+    let old_path = format!("{}_old.txt", prefix);
+    let new_path = format!("{}_new.txt", prefix);
+
+    // 相同的文件全部为 Same
+    let content = "server=localhost\nport=8080\n\ndebug=false\n";
+    file::write_file(&old_path, content).unwrap();
+    file::write_file(&new_path, content).unwrap();
+    let diff = file::diff_files(&old_path, &new_path).unwrap();
+    assert_eq!(diff.len(), 4);
+    assert!(diff.iter().all(|line| matches!(line, DiffLine::Same(_))));
+    assert_eq!(
+        file::diff_files_unified(&old_path, &new_path, 3).unwrap(),
+        ""
+    );
+
+    // 修改、删除与新增
+    file::write_file(&new_path, "server=localhost\nport=9090\n\ntimeout=30\n").unwrap();
+    let diff = file::diff_files(&old_path, &new_path).unwrap();
+    assert_eq!(
+        diff,
+        vec![
+            DiffLine::Same(String::from("server=localhost")),
+            DiffLine::Removed(String::from("port=8080")),
+            DiffLine::Added(String::from("port=9090")),
+            DiffLine::Same(String::from("")),
+            DiffLine::Removed(String::from("debug=false")),
+            DiffLine::Added(String::from("timeout=30")),
+        ]
+    );
+
+    // 从差异中还原两个文件
+    let rebuild = |keep_added: bool| -> Vec<String> {
+        diff.iter()
+            .filter_map(|line| match line {
+                DiffLine::Same(text) => Some(text.clone()),
+                DiffLine::Added(text) if keep_added => Some(text.clone()),
+                DiffLine::Removed(text) if !keep_added => Some(text.clone()),
+                _ => None,
+            })
+            .collect()
+    };
+    assert_eq!(rebuild(false), content.lines().collect::<Vec<&str>>());
+    assert_eq!(
+        rebuild(true),
+        vec!["server=localhost", "port=9090", "", "timeout=30"]
+    );
+
+    // 一侧为空文件
+    file::write_file(&old_path, "").unwrap();
+    file::write_file(&new_path, "a\nb\n").unwrap();
+    assert_eq!(
+        file::diff_files(&old_path, &new_path).unwrap(),
+        vec![
+            DiffLine::Added(String::from("a")),
+            DiffLine::Added(String::from("b"))
+        ]
+    );
+
+    file::delete_file(&old_path).unwrap();
+    assert_eq!(
+        file::diff_files(&old_path, &new_path).unwrap_err().kind(),
+        std::io::ErrorKind::NotFound
+    );
+    assert!(file::diff_files("", &new_path).is_err());
+    file::delete_file(&new_path).unwrap();
+}
+
+#[test]
+pub fn test_diff_files_unified() {
+    let prefix = format!(
+        "{}/huturs_unified_{}",
+        std::env::temp_dir().display(),
+        huturs_core::current_timestamp_millis()
+    );
+    let old_path = format!("{}_old.txt", prefix);
+    let new_path = format!("{}_new.txt", prefix);
+
+    let old: Vec<String> = (1..=20).map(|i| format!("line {}", i)).collect();
+    let mut new = old.clone();
+    new[1] = String::from("line 2 changed");
+    new.remove(14);
+    new.push(String::from("line 21"));
+    file::write_file(&old_path, &(old.join("\n") + "\n")).unwrap();
+    file::write_file(&new_path, &(new.join("\n") + "\n")).unwrap();
+
+    let diff = file::diff_files_unified(&old_path, &new_path, 2).unwrap();
+    let expected = format!(
+        "--- {}\n+++ {}\n\
+         @@ -1,4 +1,4 @@\n line 1\n-line 2\n+line 2 changed\n line 3\n line 4\n\
+         @@ -13,5 +13,4 @@\n line 13\n line 14\n-line 15\n line 16\n line 17\n\
+         @@ -19,2 +18,3 @@\n line 19\n line 20\n+line 21\n",
+        old_path, new_path
+    );
+    assert_eq!(diff, expected);
+
+    // 改动之间的距离不超过两倍上下文时合并为一个块
+    let diff = file::diff_files_unified(&old_path, &new_path, 3).unwrap();
+    assert!(diff.contains("@@ -12,9 +12,9 @@\n line 12\n"));
+    assert_eq!(diff.matches("@@ -").count(), 2);
+
+    // 上下文为 0 时只输出改动行
+    let diff = file::diff_files_unified(&old_path, &new_path, 0).unwrap();
+    assert!(diff.contains("@@ -2,1 +2,1 @@\n-line 2\n+line 2 changed\n"));
+    assert!(diff.contains("@@ -15,1 +14,0 @@\n-line 15\n"));
+    assert!(diff.contains("@@ -20,0 +20,1 @@\n+line 21\n"));
+
+    file::delete_file(&old_path).unwrap();
+    file::delete_file(&new_path).unwrap();
+}
+
+#[test]
+pub fn test_write_file_creating_dirs() {
+    let dir = std::env::temp_dir().join(format!(
+        "huturs_creating_dirs_{}",
+        huturs_core::current_timestamp_millis()
+    ));
+    let file_path = dir.join("a").join("b").join("c").join("output.txt");
+    let file_path = file_path.to_str().unwrap();
+
+    // 中间目录不存在时普通写入失败
+    assert!(file::write_file(file_path, "content").is_err());
+
+    file::write_file_creating_dirs(file_path, "content").unwrap();
+    assert_eq!(file::read_file(file_path).unwrap(), "content");
+
+    // 目录已存在时再次写入会覆盖内容
+    file::write_file_creating_dirs(file_path, "new content").unwrap();
+    assert_eq!(file::read_file(file_path).unwrap(), "new content");
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+pub fn test_ensure_parent_dir_exists() {
+    let dir = std::env::temp_dir().join(format!(
+        "huturs_ensure_parent_{}",
+        huturs_core::current_timestamp_millis()
+    ));
+    let file_path = dir.join("x").join("y").join("z").join("data.txt");
+    file::ensure_parent_dir_exists(file_path.to_str().unwrap()).unwrap();
+    assert!(dir.join("x").join("y").join("z").is_dir());
+    assert!(!file_path.exists());
+
+    // 已存在时再次调用不报错
+    file::ensure_parent_dir_exists(file_path.to_str().unwrap()).unwrap();
+
+    // 没有父目录的路径
+    assert!(file::ensure_parent_dir_exists("output.txt").is_ok());
+    assert!(file::ensure_parent_dir_exists("/").is_ok());
+    assert!(file::ensure_parent_dir_exists("").is_err());
+
+    // 父路径是一个文件时创建失败
+    let blocker = dir.join("blocker");
+    std::fs::write(&blocker, "").unwrap();
+    let nested = blocker.join("data.txt");
+    assert!(file::ensure_parent_dir_exists(nested.to_str().unwrap()).is_err());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(feature = "file-watch")]
+#[test]
+pub fn test_watch_directory() {
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    let dir = std::env::temp_dir().join(format!(
+        "huturs_watch_{}",
+        huturs_core::current_timestamp_millis()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    let existing = dir.join("existing.txt");
+    std::fs::write(&existing, "v1").unwrap();
+
+    let (sender, receiver) = mpsc::channel();
+    let handle = file::watch_directory_with_interval(
+        dir.to_str().unwrap(),
+        Duration::from_millis(20),
+        move |path, event| {
+            let _ = sender.send((path, event));
+        },
+    )
+    .unwrap();
+    let timeout = Duration::from_secs(5);
+
+    // 修改已有文件
+    std::fs::write(&existing, "version 2").unwrap();
+    assert_eq!(
+        receiver.recv_timeout(timeout).unwrap(),
+        (existing.clone(), file::WatchEvent::Modified)
+    );
+
+    // 新建文件
+    let created = dir.join("created.txt");
+    std::fs::write(&created, "new").unwrap();
+    assert_eq!(
+        receiver.recv_timeout(timeout).unwrap(),
+        (created.clone(), file::WatchEvent::Created)
+    );
+
+    // 删除文件
+    std::fs::remove_file(&created).unwrap();
+    assert_eq!(
+        receiver.recv_timeout(timeout).unwrap(),
+        (created.clone(), file::WatchEvent::Deleted)
+    );
+
+    // 停止监听后不再触发回调，发送端随线程一起被释放
+    handle.stop();
+    std::fs::write(dir.join("after_stop.txt"), "ignored").unwrap();
+    assert!(matches!(
+        receiver.recv_timeout(Duration::from_millis(200)),
+        Err(mpsc::RecvTimeoutError::Disconnected)
+    ));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(feature = "file-watch")]
+#[test]
+pub fn test_watch_directory_missing() {
+    let dir = std::env::temp_dir().join(format!(
+        "huturs_watch_missing_{}",
+        huturs_core::current_timestamp_millis()
+    ));
+    assert!(file::watch_directory(dir.to_str().unwrap(), |_, _| {}).is_err());
+    assert!(file::watch_directory("", |_, _| {}).is_err());
+}
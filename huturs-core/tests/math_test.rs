@@ -82,4 +82,1547 @@ pub fn test_sample_standard_deviation_single() {
     let nums = vec![5.0];
     let std_dev = math::sample_standard_deviation(&nums);
     assert_eq!(std_dev, 0.0);
-}
\ No newline at end of file
+}
+/// 试除法判断素数，作为筛法的参照实现
+fn is_prime_by_trial_division(n: u32) -> bool {
+    if n < 2 {
+        return false;
+    }
+    (2..)
+        .take_while(|i| i * i <= n)
+        .all(|i| !n.is_multiple_of(i))
+}
+
+#[test]
+pub fn test_sieve_of_eratosthenes() {
+    let first_25 = vec![
+        2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89,
+        97,
+    ];
+    assert_eq!(math::sieve_of_eratosthenes(100), first_25);
+
+    assert!(math::sieve_of_eratosthenes(0).is_empty());
+    assert!(math::sieve_of_eratosthenes(1).is_empty());
+    assert_eq!(math::sieve_of_eratosthenes(2), vec![2]);
+    assert_eq!(math::sieve_of_eratosthenes(97).last(), Some(&97));
+}
+
+#[test]
+pub fn test_sieve_of_eratosthenes_count() {
+    assert_eq!(math::sieve_of_eratosthenes(1_000_000).len(), 78498);
+}
+
+#[test]
+pub fn test_sieve_matches_trial_division() {
+    let primes = math::sieve_of_eratosthenes(1000);
+    let expected: Vec<u32> = (0..=1000)
+        .filter(|&n| is_prime_by_trial_division(n))
+        .collect();
+    assert_eq!(primes, expected);
+}
+
+#[test]
+pub fn test_nth_prime() {
+    assert_eq!(math::nth_prime(1), 2);
+    assert_eq!(math::nth_prime(2), 3);
+    assert_eq!(math::nth_prime(5), 11);
+    assert_eq!(math::nth_prime(6), 13);
+    assert_eq!(math::nth_prime(25), 97);
+    assert_eq!(math::nth_prime(78498), 999983);
+
+    let primes = math::sieve_of_eratosthenes(10000);
+    for (i, &p) in primes.iter().enumerate() {
+        assert_eq!(math::nth_prime(i + 1), p);
+    }
+}
+
+#[test]
+pub fn test_count_digits() {
+    assert_eq!(math::count_digits(0), 1);
+    assert_eq!(math::count_digits(1), 1);
+    assert_eq!(math::count_digits(9), 1);
+    assert_eq!(math::count_digits(10), 2);
+    assert_eq!(math::count_digits(99), 2);
+    assert_eq!(math::count_digits(100), 3);
+    assert_eq!(math::count_digits(u64::MAX), 20);
+}
+
+#[test]
+pub fn test_digit_sum() {
+    assert_eq!(math::digit_sum(0), 0);
+    assert_eq!(math::digit_sum(1), 1);
+    assert_eq!(math::digit_sum(9), 9);
+    assert_eq!(math::digit_sum(10), 1);
+    assert_eq!(math::digit_sum(100), 1);
+    assert_eq!(math::digit_sum(987654321), 45);
+    assert_eq!(math::digit_sum(u64::MAX), 87);
+}
+
+#[test]
+pub fn test_digital_root() {
+    assert_eq!(math::digital_root(0), 0);
+    assert_eq!(math::digital_root(1), 1);
+    assert_eq!(math::digital_root(9), 9);
+    assert_eq!(math::digital_root(10), 1);
+    assert_eq!(math::digital_root(100), 1);
+    assert_eq!(math::digital_root(99999), 9);
+
+    // 与反复求各位数字之和的结果一致
+    for n in 0..10000u64 {
+        let mut root = n;
+        while root >= 10 {
+            root = math::digit_sum(root) as u64;
+        }
+        assert_eq!(math::digital_root(n), root as u32);
+    }
+}
+
+#[test]
+pub fn test_reverse_number() {
+    assert_eq!(math::reverse_number(0), 0);
+    assert_eq!(math::reverse_number(1), 1);
+    assert_eq!(math::reverse_number(9), 9);
+    assert_eq!(math::reverse_number(10), 1);
+    assert_eq!(math::reverse_number(100), 1);
+    assert_eq!(math::reverse_number(12321), 12321);
+    assert_eq!(math::reverse_number(1234567890), 987654321);
+}
+
+#[test]
+#[should_panic]
+pub fn test_reverse_number_overflow() {
+    math::reverse_number(u64::MAX);
+}
+
+#[test]
+pub fn test_is_armstrong_number() {
+    for n in [0, 1, 9, 153, 370, 371, 407, 1634, 8208, 9474] {
+        assert!(math::is_armstrong_number(n), "{} is an Armstrong number", n);
+    }
+    for n in [10, 100, 152, 154, 372, 1000, u32::MAX] {
+        assert!(
+            !math::is_armstrong_number(n),
+            "{} is not an Armstrong number",
+            n
+        );
+    }
+}
+
+#[test]
+pub fn test_ceil_div() {
+    assert_eq!(math::ceil_div(10, 5), Some(2));
+    assert_eq!(math::ceil_div(10, 3), Some(4));
+    assert_eq!(math::ceil_div(0, 3), Some(0));
+    assert_eq!(math::ceil_div(1, 3), Some(1));
+
+    // 负数
+    assert_eq!(math::ceil_div(-10, 3), Some(-3));
+    assert_eq!(math::ceil_div(10, -3), Some(-3));
+    assert_eq!(math::ceil_div(-10, -3), Some(4));
+    assert_eq!(math::ceil_div(-9, 3), Some(-3));
+
+    // 大数不会溢出
+    assert_eq!(math::ceil_div(i64::MAX, 2), Some(i64::MAX / 2 + 1));
+    assert_eq!(math::ceil_div(i64::MAX, i64::MAX), Some(1));
+    assert_eq!(math::ceil_div(i64::MIN, 2), Some(i64::MIN / 2));
+
+    assert_eq!(math::ceil_div(10, 0), None);
+    assert_eq!(math::ceil_div(i64::MIN, -1), None);
+}
+
+#[test]
+pub fn test_floor_div() {
+    assert_eq!(math::floor_div(10, 5), Some(2));
+    assert_eq!(math::floor_div(7, 2), Some(3));
+    assert_eq!(math::floor_div(0, 3), Some(0));
+
+    // 负数
+    assert_eq!(math::floor_div(-7, 2), Some(-4));
+    assert_eq!(math::floor_div(7, -2), Some(-4));
+    assert_eq!(math::floor_div(-7, -2), Some(3));
+    assert_eq!(math::floor_div(-8, 2), Some(-4));
+
+    assert_eq!(math::floor_div(i64::MIN, 2), Some(i64::MIN / 2));
+    assert_eq!(math::floor_div(i64::MAX, -2), Some(i64::MAX / -2 - 1));
+
+    assert_eq!(math::floor_div(7, 0), None);
+    assert_eq!(math::floor_div(i64::MIN, -1), None);
+
+    // 与浮点数 floor 的结果一致
+    for a in -20..=20i64 {
+        for b in [-7, -3, -1, 1, 2, 5] {
+            assert_eq!(
+                math::floor_div(a, b),
+                Some((a as f64 / b as f64).floor() as i64)
+            );
+            assert_eq!(
+                math::ceil_div(a, b),
+                Some((a as f64 / b as f64).ceil() as i64)
+            );
+        }
+    }
+}
+
+#[test]
+pub fn test_next_power_of_two() {
+    assert_eq!(math::next_power_of_two(0), Some(1));
+    assert_eq!(math::next_power_of_two(1), Some(1));
+    assert_eq!(math::next_power_of_two(3), Some(4));
+    assert_eq!(math::next_power_of_two(1000), Some(1024));
+
+    for i in 0..64 {
+        let p = 1u64 << i;
+        assert_eq!(math::next_power_of_two(p), Some(p));
+        if i > 1 {
+            assert_eq!(math::next_power_of_two(p - 1), Some(p));
+        }
+    }
+
+    assert_eq!(math::next_power_of_two((1 << 63) + 1), None);
+    assert_eq!(math::next_power_of_two(u64::MAX), None);
+}
+
+#[test]
+pub fn test_is_power_of_two() {
+    assert!(!math::is_power_of_two(0));
+    assert!(math::is_power_of_two(1));
+    for i in 0..=62 {
+        assert!(math::is_power_of_two(1 << i));
+        if i > 1 {
+            assert!(!math::is_power_of_two((1 << i) - 1));
+            assert!(!math::is_power_of_two((1 << i) + 1));
+        }
+    }
+    assert!(math::is_power_of_two(1 << 63));
+    assert!(!math::is_power_of_two(u64::MAX));
+}
+
+#[test]
+pub fn test_log2_floor() {
+    assert_eq!(math::log2_floor(0), None);
+    assert_eq!(math::log2_floor(1), Some(0));
+    assert_eq!(math::log2_floor(2), Some(1));
+    assert_eq!(math::log2_floor(3), Some(1));
+    for i in 0..=62 {
+        assert_eq!(math::log2_floor(1 << i), Some(i));
+        assert_eq!(math::log2_floor((1 << (i + 1)) - 1), Some(i));
+    }
+    assert_eq!(math::log2_floor(u64::MAX), Some(63));
+}
+
+#[test]
+pub fn test_softmax() {
+    assert!(math::softmax(&[]).is_empty());
+    assert_eq!(math::softmax(&[42.0]), vec![1.0]);
+
+    let probs = math::softmax(&[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(probs.len(), 4);
+    assert!((probs.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    assert!(probs.windows(2).all(|w| w[0] < w[1]));
+
+    // 相同输入得到均匀分布
+    for p in math::softmax(&[3.0, 3.0, 3.0, 3.0]) {
+        assert!((p - 0.25).abs() < 1e-12);
+    }
+
+    // 大数值不会溢出
+    let probs = math::softmax(&[1000.0, 1000.0, 999.0]);
+    assert!(probs.iter().all(|p| p.is_finite()));
+    assert!((probs.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    assert!((probs[0] - probs[1]).abs() < 1e-12);
+}
+
+#[test]
+pub fn test_entropy() {
+    // 均匀分布的熵为 log2(n)
+    for n in 1..=16 {
+        let probs = vec![1.0 / n as f64; n];
+        let h = math::entropy(&probs).unwrap();
+        assert!((h - (n as f64).log2()).abs() < 1e-9);
+    }
+
+    // 确定分布的熵为 0
+    assert_eq!(math::entropy(&[1.0]), Some(0.0));
+    assert_eq!(math::entropy(&[0.0, 1.0, 0.0]), Some(0.0));
+
+    let h = math::entropy(&[0.25, 0.75]).unwrap();
+    assert!((h - 0.811278124459).abs() < 1e-9);
+
+    // softmax 的输出可以直接用于计算熵
+    assert!(math::entropy(&math::softmax(&[1.0, 2.0, 3.0])).is_some());
+
+    // 非法分布
+    assert_eq!(math::entropy(&[]), None);
+    assert_eq!(math::entropy(&[0.5, 0.4]), None);
+    assert_eq!(math::entropy(&[1.5, -0.5]), None);
+}
+
+#[test]
+pub fn test_collatz_sequence() {
+    assert!(math::collatz_sequence(0).is_empty());
+    assert_eq!(math::collatz_sequence(1), vec![1]);
+    assert_eq!(math::collatz_sequence(2), vec![2, 1]);
+    assert_eq!(math::collatz_sequence(3), vec![3, 10, 5, 16, 8, 4, 2, 1]);
+
+    // 27 需要 111 步才能到达 1，过程中的最大值为 9232
+    let seq = math::collatz_sequence(27);
+    assert_eq!(seq.len() - 1, 111);
+    assert_eq!(seq.first(), Some(&27));
+    assert_eq!(seq.last(), Some(&1));
+    assert_eq!(seq.iter().max(), Some(&9232));
+}
+
+#[test]
+pub fn test_collatz_length() {
+    assert_eq!(math::collatz_length(0), 0);
+    assert_eq!(math::collatz_length(1), 1);
+    assert_eq!(math::collatz_length(27), 112);
+    for n in 0..1000 {
+        assert_eq!(math::collatz_length(n), math::collatz_sequence(n).len());
+    }
+}
+
+#[test]
+pub fn test_max_collatz_value() {
+    assert_eq!(math::max_collatz_value(0), 0);
+    assert_eq!(math::max_collatz_value(1), 1);
+    assert_eq!(math::max_collatz_value(8), 8);
+    assert_eq!(math::max_collatz_value(27), 9232);
+}
+
+#[test]
+pub fn test_range_statistics() {
+    assert!(math::range_statistics(&[]).is_none());
+    assert!(math::RangeStats::from_iter(std::iter::empty()).is_none());
+
+    let numbers = [3.5, -1.25, 8.0, 2.0, 2.0, 10.75, 0.5];
+    let stats = math::range_statistics(&numbers).unwrap();
+    assert_eq!(stats.count, numbers.len());
+    assert_eq!(stats.min, math::min_in_array(&numbers).unwrap());
+    assert_eq!(stats.max, math::max_in_array(&numbers).unwrap());
+    assert!((stats.sum - math::sum(&numbers)).abs() < 1e-12);
+    assert!((stats.mean - math::average(&numbers)).abs() < 1e-12);
+    assert!((stats.variance - math::variance(&numbers)).abs() < 1e-12);
+    assert!((stats.std_dev - math::standard_deviation(&numbers)).abs() < 1e-12);
+    assert_eq!(stats.median, 2.0);
+
+    let stats = math::range_statistics(&[4.0, 1.0, 3.0, 2.0]).unwrap();
+    assert_eq!(stats.median, 2.5);
+
+    let stats = math::range_statistics(&[42.0]).unwrap();
+    assert_eq!(stats.min, 42.0);
+    assert_eq!(stats.max, 42.0);
+    assert_eq!(stats.median, 42.0);
+    assert_eq!(stats.variance, 0.0);
+
+    let streamed = math::RangeStats::from_iter((1..=100).map(|x| x as f64)).unwrap();
+    assert_eq!(streamed.count, 100);
+    assert_eq!(streamed.sum, 5050.0);
+    assert_eq!(streamed.median, 50.5);
+    assert!((streamed.variance - 833.25).abs() < 1e-9);
+}
+
+#[test]
+pub fn test_median_absolute_deviation() {
+    assert_eq!(math::median_absolute_deviation(&[]), None);
+    assert_eq!(math::median_absolute_deviation(&[5.0]), Some(0.0));
+    assert_eq!(
+        math::median_absolute_deviation(&[1.0, 1.0, 2.0, 2.0, 4.0, 6.0, 9.0]),
+        Some(1.0)
+    );
+    // 顺序无关
+    assert_eq!(
+        math::median_absolute_deviation(&[9.0, 2.0, 1.0, 6.0, 2.0, 4.0, 1.0]),
+        Some(1.0)
+    );
+    // 偶数个元素：中位数 2.5，偏差 [1.5, 0.5, 0.5, 1.5]
+    assert_eq!(
+        math::median_absolute_deviation(&[1.0, 2.0, 3.0, 4.0]),
+        Some(1.0)
+    );
+    // 离群值几乎不影响结果
+    assert_eq!(
+        math::median_absolute_deviation(&[1.0, 1.0, 2.0, 2.0, 4.0, 6.0, 1000.0]),
+        Some(1.0)
+    );
+}
+
+#[test]
+pub fn test_trimmed_mean() {
+    let nums = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 100.0];
+    assert_eq!(math::trimmed_mean(&nums, 0.0), Some(14.5));
+    assert_eq!(math::trimmed_mean(&nums, 0.1), Some(5.5));
+    assert_eq!(math::trimmed_mean(&nums, 0.15), Some(5.5));
+    assert_eq!(math::trimmed_mean(&nums, 0.2), Some(5.5));
+    assert_eq!(math::trimmed_mean(&nums, 0.49), Some(5.5));
+    assert_eq!(math::trimmed_mean(&[3.0, 1.0, 2.0], 0.4), Some(2.0));
+
+    assert_eq!(math::trimmed_mean(&[], 0.1), None);
+    assert_eq!(math::trimmed_mean(&nums, 0.5), None);
+    assert_eq!(math::trimmed_mean(&nums, -0.1), None);
+    assert_eq!(math::trimmed_mean(&nums, f64::NAN), None);
+}
+
+#[test]
+pub fn test_running_extremes_and_sum() {
+    let inputs: [&[f64]; 6] = [
+        &[],
+        &[7.0],
+        &[2.0, 2.0, 2.0, 2.0],
+        &[1.0, 2.0, 3.0, 4.0, 5.0],
+        &[5.0, 4.0, 3.0, 2.0, 1.0],
+        &[3.5, -1.0, 8.25, 0.0, 8.25, -4.5, 6.0],
+    ];
+    for numbers in inputs {
+        let naive_max: Vec<f64> = (0..numbers.len())
+            .map(|i| {
+                numbers[..=i]
+                    .iter()
+                    .cloned()
+                    .fold(f64::NEG_INFINITY, f64::max)
+            })
+            .collect();
+        let naive_min: Vec<f64> = (0..numbers.len())
+            .map(|i| numbers[..=i].iter().cloned().fold(f64::INFINITY, f64::min))
+            .collect();
+        let naive_sum: Vec<f64> = (0..numbers.len())
+            .map(|i| numbers[..=i].iter().sum())
+            .collect();
+
+        assert_eq!(math::running_max(numbers), naive_max);
+        assert_eq!(math::running_min(numbers), naive_min);
+        assert_eq!(math::running_sum(numbers), naive_sum);
+        assert_eq!(math::running_max(numbers).len(), numbers.len());
+    }
+
+    assert_eq!(math::running_max(&[1.0, 2.0, 3.0]), vec![1.0, 2.0, 3.0]);
+    assert_eq!(math::running_min(&[1.0, 2.0, 3.0]), vec![1.0, 1.0, 1.0]);
+    assert_eq!(math::running_max(&[3.0, 2.0, 1.0]), vec![3.0, 3.0, 3.0]);
+    assert_eq!(math::running_min(&[3.0, 2.0, 1.0]), vec![3.0, 2.0, 1.0]);
+}
+
+#[test]
+pub fn test_number_base_convert() {
+    let convert = |n: &str, from: u32, to: u32| math::number_base_convert(n, from, to).unwrap();
+
+    assert_eq!(convert("0", 10, 2), "0");
+    assert_eq!(convert("255", 10, 2), "11111111");
+    assert_eq!(convert("255", 10, 16), "ff");
+    assert_eq!(convert("FF", 16, 10), "255");
+    assert_eq!(convert("ff", 16, 2), "11111111");
+    assert_eq!(convert("777", 8, 10), "511");
+    assert_eq!(convert("zz", 36, 10), "1295");
+    assert_eq!(convert("1295", 10, 36), "zz");
+    assert_eq!(convert("0010", 2, 10), "2");
+    assert_eq!(convert(&u64::MAX.to_string(), 10, 16), "ffffffffffffffff");
+
+    for value in [0u64, 1, 7, 42, 1000, 65535, 123_456_789, u64::MAX] {
+        let binary = convert(&value.to_string(), 10, 2);
+        let hex = convert(&binary, 2, 16);
+        assert_eq!(convert(&hex, 16, 10), value.to_string());
+        assert_eq!(binary, math::to_binary(value));
+        assert_eq!(hex, math::to_hex(value));
+        assert_eq!(convert(&value.to_string(), 10, 8), math::to_octal(value));
+    }
+
+    assert!(math::number_base_convert("12", 2, 10).is_err());
+    assert!(math::number_base_convert("g", 16, 10).is_err());
+    assert!(math::number_base_convert("", 10, 2).is_err());
+    assert!(math::number_base_convert("-1", 10, 2).is_err());
+    assert!(math::number_base_convert("+1", 10, 2).is_err());
+    assert!(math::number_base_convert("1 0", 10, 2).is_err());
+    assert!(math::number_base_convert("10", 1, 10).is_err());
+    assert!(math::number_base_convert("10", 10, 37).is_err());
+    assert!(math::number_base_convert("18446744073709551616", 10, 16).is_err());
+}
+
+#[test]
+pub fn test_to_binary_octal_hex() {
+    assert_eq!(math::to_binary(0), "0");
+    assert_eq!(math::to_binary(5), "101");
+    assert_eq!(math::to_octal(0), "0");
+    assert_eq!(math::to_octal(8), "10");
+    assert_eq!(math::to_hex(0), "0");
+    assert_eq!(math::to_hex(48879), "beef");
+    assert_eq!(math::to_hex(u64::MAX), "ffffffffffffffff");
+}
+
+#[test]
+pub fn test_interpolate_slices() {
+    // 以 y = x^2 在整数点的取值作为查找表
+    let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+    let ys = [0.0, 1.0, 4.0, 9.0, 16.0];
+
+    // 采样点上精确命中
+    for (x, y) in xs.iter().zip(ys.iter()) {
+        assert_eq!(math::interpolate_slices(&xs, &ys, *x), Some(*y));
+    }
+    assert_eq!(math::interpolate_slices(&xs, &ys, 0.5), Some(0.5));
+    assert_eq!(math::interpolate_slices(&xs, &ys, 2.5), Some(6.5));
+    assert_eq!(math::interpolate_slices(&xs, &ys, 3.25), Some(10.75));
+
+    // 线性函数的查找表插值结果应与函数值一致
+    let xs = [-10.0, -2.0, 5.0, 40.0];
+    let ys: Vec<f64> = xs.iter().map(|x| 3.0 * x + 1.0).collect();
+    for x in [-10.0, -7.5, 0.0, 1.0, 12.3, 39.9, 40.0] {
+        let y = math::interpolate_slices(&xs, &ys, x).unwrap();
+        assert!((y - (3.0 * x + 1.0)).abs() < 1e-9);
+    }
+
+    // 超出范围不做外推
+    assert_eq!(math::interpolate_slices(&xs, &ys, -10.001), None);
+    assert_eq!(math::interpolate_slices(&xs, &ys, 40.001), None);
+    assert_eq!(math::interpolate_slices(&xs, &ys, f64::NAN), None);
+
+    // 无效的查找表
+    assert_eq!(math::interpolate_slices(&[], &[], 0.0), None);
+    assert_eq!(math::interpolate_slices(&[1.0], &[1.0], 1.0), None);
+    assert_eq!(math::interpolate_slices(&[1.0, 2.0], &[1.0], 1.5), None);
+    assert_eq!(
+        math::interpolate_slices(&[2.0, 1.0], &[1.0, 2.0], 1.5),
+        None
+    );
+    assert_eq!(
+        math::interpolate_slices(&[1.0, 1.0], &[1.0, 2.0], 1.0),
+        None
+    );
+}
+
+#[test]
+pub fn test_interpolate_extrapolate() {
+    let xs = [0.0, 10.0, 20.0];
+    let ys = [0.0, 100.0, 50.0];
+
+    // 范围内与 interpolate_slices 一致
+    for x in [0.0, 2.5, 10.0, 17.0, 20.0] {
+        assert_eq!(
+            math::interpolate_extrapolate(&xs, &ys, x),
+            math::interpolate_slices(&xs, &ys, x)
+        );
+    }
+
+    // 沿首尾线段外推
+    assert_eq!(math::interpolate_extrapolate(&xs, &ys, -10.0), Some(-100.0));
+    assert_eq!(math::interpolate_extrapolate(&xs, &ys, 40.0), Some(-50.0));
+
+    assert_eq!(math::interpolate_extrapolate(&xs, &ys, f64::NAN), None);
+    assert_eq!(math::interpolate_extrapolate(&[1.0], &[1.0], 5.0), None);
+    assert_eq!(math::interpolate_extrapolate(&xs, &ys[..2], 5.0), None);
+}
+
+#[test]
+pub fn test_safe_integer_ops_i64() {
+    assert_eq!(math::safe_add_i64(2, 3), Some(5));
+    assert_eq!(math::safe_add_i64(i64::MAX, 0), Some(i64::MAX));
+    assert_eq!(math::safe_add_i64(i64::MAX, 1), None);
+    assert_eq!(math::safe_add_i64(i64::MIN, -1), None);
+    assert_eq!(math::safe_add_i64(i64::MIN, i64::MAX), Some(-1));
+
+    assert_eq!(math::safe_sub_i64(2, 5), Some(-3));
+    assert_eq!(math::safe_sub_i64(i64::MIN, 1), None);
+    assert_eq!(math::safe_sub_i64(i64::MAX, -1), None);
+    assert_eq!(math::safe_sub_i64(0, i64::MIN), None);
+    assert_eq!(math::safe_sub_i64(-1, i64::MIN), Some(i64::MAX));
+
+    assert_eq!(math::safe_mul_i64(-6, 7), Some(-42));
+    assert_eq!(math::safe_mul_i64(i64::MAX, 2), None);
+    assert_eq!(math::safe_mul_i64(i64::MIN, -1), None);
+    assert_eq!(math::safe_mul_i64(i64::MIN, 1), Some(i64::MIN));
+    assert_eq!(math::safe_mul_i64(i64::MAX, 0), Some(0));
+}
+
+#[test]
+pub fn test_safe_integer_ops_u64() {
+    assert_eq!(math::safe_add_u64(2, 3), Some(5));
+    assert_eq!(math::safe_add_u64(u64::MAX, 1), None);
+    assert_eq!(math::safe_sub_u64(5, 5), Some(0));
+    assert_eq!(math::safe_sub_u64(0, 1), None);
+    assert_eq!(math::safe_mul_u64(u64::MAX, 1), Some(u64::MAX));
+    assert_eq!(math::safe_mul_u64(u64::MAX / 2 + 1, 2), None);
+}
+
+#[test]
+pub fn test_saturating_ops_i64() {
+    assert_eq!(math::saturating_add_i64(2, 3), 5);
+    assert_eq!(math::saturating_add_i64(i64::MAX, 1), i64::MAX);
+    assert_eq!(math::saturating_add_i64(i64::MIN, -1), i64::MIN);
+    assert_eq!(math::saturating_add_i64(i64::MIN, i64::MAX), -1);
+
+    assert_eq!(math::saturating_mul_i64(-6, 7), -42);
+    assert_eq!(math::saturating_mul_i64(i64::MAX, 2), i64::MAX);
+    assert_eq!(math::saturating_mul_i64(i64::MAX, -2), i64::MIN);
+    assert_eq!(math::saturating_mul_i64(i64::MIN, -1), i64::MAX);
+    assert_eq!(math::saturating_mul_i64(i64::MIN, 2), i64::MIN);
+}
+
+#[test]
+pub fn test_gcd_and_lcm() {
+    assert_eq!(math::gcd(12, 18), 6);
+    assert_eq!(math::gcd(18, 12), 6);
+    assert_eq!(math::gcd(17, 5), 1);
+    assert_eq!(math::gcd(0, 9), 9);
+    assert_eq!(math::gcd(9, 0), 9);
+    assert_eq!(math::gcd(0, 0), 0);
+    assert_eq!(math::gcd(u64::MAX, u64::MAX), u64::MAX);
+
+    assert_eq!(math::lcm(4, 6), Some(12));
+    assert_eq!(math::lcm(17, 5), Some(85));
+    assert_eq!(math::lcm(0, 5), Some(0));
+    assert_eq!(math::lcm(u64::MAX, u64::MAX), Some(u64::MAX));
+    assert_eq!(math::lcm(u64::MAX, u64::MAX - 1), None);
+}
+
+#[test]
+pub fn test_gcd_of_slice() {
+    assert_eq!(math::gcd_of_slice(&[]), None);
+    assert_eq!(math::gcd_of_slice(&[42]), Some(42));
+    assert_eq!(math::gcd_of_slice(&[0]), Some(0));
+
+    // 有公因数
+    assert_eq!(math::gcd_of_slice(&[12, 18, 30]), Some(6));
+    assert_eq!(math::gcd_of_slice(&[100, 250, 1000, 50]), Some(50));
+    assert_eq!(math::gcd_of_slice(&[0, 8, 12]), Some(4));
+
+    // 互质
+    assert_eq!(math::gcd_of_slice(&[6, 10, 15]), Some(1));
+    assert_eq!(math::gcd_of_slice(&[7, 11, 13]), Some(1));
+
+    // gcd(a, b, c) = gcd(gcd(a, b), c)
+    let (a, b, c) = (84, 126, 210);
+    assert_eq!(
+        math::gcd_of_slice(&[a, b, c]),
+        Some(math::gcd(math::gcd(a, b), c))
+    );
+}
+
+#[test]
+pub fn test_lcm_of_slice() {
+    assert_eq!(math::lcm_of_slice(&[]), None);
+    assert_eq!(math::lcm_of_slice(&[42]), Some(42));
+
+    // 有公因数
+    assert_eq!(math::lcm_of_slice(&[4, 6, 10]), Some(60));
+    assert_eq!(math::lcm_of_slice(&[12, 18, 30]), Some(180));
+
+    // 互质
+    assert_eq!(math::lcm_of_slice(&[3, 5, 7]), Some(105));
+    assert_eq!(
+        math::lcm_of_slice(&(1..=20).collect::<Vec<u64>>()),
+        Some(232_792_560)
+    );
+
+    assert_eq!(math::lcm_of_slice(&[5, 0, 7]), Some(0));
+
+    // 中间结果溢出
+    assert_eq!(math::lcm_of_slice(&[u64::MAX, 2]), None);
+    assert_eq!(math::lcm_of_slice(&(1..=50).collect::<Vec<u64>>()), None);
+    assert_eq!(math::lcm_of_slice(&[1 << 32, 1 << 40, 3]), Some(3 << 40));
+}
+
+#[test]
+pub fn test_bin_values() {
+    assert!(math::bin_values(&[], 5).is_empty());
+    assert!(math::bin_values(&[1.0, 2.0], 0).is_empty());
+    assert!(math::bin_values(&[f64::NAN, f64::INFINITY], 3).is_empty());
+
+    let values = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+    let bins = math::bin_values(&values, 5);
+    assert_eq!(bins.len(), 5);
+    assert_eq!(
+        bins,
+        vec![
+            (0.0, 2.0, 2),
+            (2.0, 4.0, 2),
+            (4.0, 6.0, 2),
+            (6.0, 8.0, 2),
+            (8.0, 10.0, 3)
+        ]
+    );
+    assert_eq!(bins.iter().map(|b| b.2).sum::<usize>(), values.len());
+
+    // 区间边界值落在右侧区间，最大值落在最后一个区间
+    let bins = math::bin_values(&[0.0, 5.0, 10.0], 2);
+    assert_eq!(bins, vec![(0.0, 5.0, 1), (5.0, 10.0, 2)]);
+
+    // 数量之和等于有效值的总数
+    let values: Vec<f64> = (0..1000).map(|i| ((i * 37) % 101) as f64 / 7.0).collect();
+    for bin_count in [1, 2, 3, 7, 10, 64] {
+        let bins = math::bin_values(&values, bin_count);
+        assert_eq!(bins.len(), bin_count);
+        assert_eq!(bins.iter().map(|b| b.2).sum::<usize>(), values.len());
+        assert_eq!(bins[0].0, 0.0);
+        assert_eq!(bins[bin_count - 1].1, 100.0 / 7.0);
+        for pair in bins.windows(2) {
+            assert_eq!(pair[0].1, pair[1].0);
+        }
+    }
+
+    // 非有限值被忽略
+    let bins = math::bin_values(&[1.0, f64::NAN, 3.0, f64::NEG_INFINITY], 2);
+    assert_eq!(bins, vec![(1.0, 2.0, 1), (2.0, 3.0, 1)]);
+}
+
+#[test]
+pub fn test_bin_values_single_value() {
+    let bins = math::bin_values(&[4.0], 1);
+    assert_eq!(bins, vec![(3.5, 4.5, 1)]);
+
+    let bins = math::bin_values(&[4.0, 4.0, 4.0], 2);
+    assert_eq!(bins, vec![(3.5, 4.0, 0), (4.0, 4.5, 3)]);
+
+    let bins = math::bin_values(&[2.0; 5], 3);
+    assert_eq!(
+        bins.iter().map(|b| b.2).collect::<Vec<usize>>(),
+        vec![0, 5, 0]
+    );
+}
+
+#[test]
+pub fn test_histogram_ascii() {
+    assert_eq!(math::histogram_ascii(&[], 3, 10), "");
+    assert_eq!(math::histogram_ascii(&[1.0], 0, 10), "");
+
+    let chart = math::histogram_ascii(&[1.0, 2.0, 2.5, 4.0, 5.0], 2, 6);
+    assert_eq!(chart, "1.00 - 3.00 | ###### 3\n3.00 - 5.00 | #### 2");
+
+    // 非零数量至少显示一个字符，标签右对齐
+    let mut values = vec![0.0; 100];
+    values.push(20.0);
+    let chart = math::histogram_ascii(&values, 2, 10);
+    assert_eq!(chart, " 0.00 - 10.00 | ########## 100\n10.00 - 20.00 | # 1");
+
+    let chart = math::histogram_ascii(&[0.0, 9.0], 3, 4);
+    let lines: Vec<&str> = chart.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[1], "3.00 - 6.00 |  0");
+}
+
+#[test]
+pub fn test_is_close() {
+    // 相等的值
+    assert!(math::is_close(1.5, 1.5, 0.0, 0.0));
+    assert!(math::is_close(0.0, -0.0, 0.0, 0.0));
+
+    // 相差 1 ULP
+    let one_ulp = f64::from_bits(1.0f64.to_bits() + 1);
+    assert!(math::is_close(1.0, one_ulp, 1e-9, 0.0));
+    assert!(!math::is_close(1.0, one_ulp, 0.0, 0.0));
+    let big = 1e300;
+    assert!(math::is_close(
+        big,
+        f64::from_bits(big.to_bits() + 1),
+        1e-9,
+        0.0
+    ));
+
+    // 超出容差
+    assert!(!math::is_close(1.0, 1.0 + 1e-8, 1e-9, 0.0));
+    assert!(math::is_close(1.0, 1.0 + 1e-8, 1e-7, 0.0));
+    assert!(!math::is_close(100.0, 101.0, 1e-3, 0.0));
+    assert!(math::is_close(100.0, 101.0, 1e-2, 0.0));
+
+    // 与 0 比较时相对容差无效，需要绝对容差
+    assert!(!math::is_close(0.0, 1e-15, 1e-9, 0.0));
+    assert!(math::is_close(0.0, 1e-15, 1e-9, 1e-12));
+    assert!(!math::is_close(0.0, 1e-11, 1e-9, 1e-12));
+
+    // NaN 与无穷大
+    assert!(!math::is_close(f64::NAN, f64::NAN, 1e-9, 0.0));
+    assert!(!math::is_close(f64::NAN, 1.0, 1.0, 1.0));
+    assert!(math::is_close(f64::INFINITY, f64::INFINITY, 1e-9, 0.0));
+    assert!(math::is_close(
+        f64::NEG_INFINITY,
+        f64::NEG_INFINITY,
+        1e-9,
+        0.0
+    ));
+    assert!(!math::is_close(f64::INFINITY, f64::NEG_INFINITY, 1e-9, 0.0));
+    assert!(!math::is_close(f64::INFINITY, f64::MAX, 1.0, f64::MAX));
+}
+
+#[test]
+pub fn test_approx_eq() {
+    assert!(math::approx_eq(0.1 + 0.2, 0.3));
+    assert!(math::approx_eq((2.0f64).sqrt() * (2.0f64).sqrt(), 2.0));
+    assert!(math::approx_eq(1e20, 1e20 + 1.0));
+    assert!(!math::approx_eq(1.0, 1.0001));
+    assert!(!math::approx_eq(0.0, 1e-300));
+    assert!(!math::approx_eq(f64::NAN, f64::NAN));
+}
+
+#[test]
+pub fn test_is_close_slice() {
+    assert!(math::is_close_slice(&[], &[], 1e-9, 0.0));
+    assert!(math::is_close_slice(
+        &[0.1 + 0.2, 1.0, -5.0],
+        &[0.3, 1.0, -5.0],
+        1e-9,
+        0.0
+    ));
+    assert!(!math::is_close_slice(&[1.0, 2.0], &[1.0, 2.1], 1e-9, 0.0));
+    assert!(!math::is_close_slice(&[1.0, 2.0], &[1.0], 1e-9, 0.0));
+    assert!(!math::is_close_slice(&[f64::NAN], &[f64::NAN], 1e-9, 0.0));
+    assert!(math::is_close_slice(&[0.0, 1.0], &[1e-12, 1.0], 0.0, 1e-9));
+}
+
+#[test]
+pub fn test_welford_variance() {
+    let empty = math::WelfordVariance::new();
+    assert_eq!(empty.count(), 0);
+    assert_eq!(empty.mean(), 0.0);
+    assert_eq!(empty.variance(), 0.0);
+    assert_eq!(empty.sample_variance(), 0.0);
+
+    let mut single = math::WelfordVariance::new();
+    single.update(42.0);
+    assert_eq!(single.mean(), 42.0);
+    assert_eq!(single.variance(), 0.0);
+    assert_eq!(single.sample_variance(), 0.0);
+
+    let datasets: [&[f64]; 4] = [
+        &[1.0, 2.0, 3.0, 4.0, 5.0],
+        &[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0],
+        &[-3.5, 0.25, 1e3, -7.75, 12.0, 0.0],
+        &[1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0],
+    ];
+    for numbers in datasets {
+        let mut stats = math::WelfordVariance::new();
+        numbers.iter().for_each(|v| stats.update(*v));
+
+        assert_eq!(stats.count(), numbers.len() as u64);
+        assert!(math::approx_eq(stats.mean(), math::average(numbers)));
+        assert!(math::is_close(
+            stats.variance(),
+            math::variance(numbers),
+            1e-9,
+            1e-9
+        ));
+        assert!(math::is_close(
+            stats.sample_variance(),
+            math::sample_variance(numbers),
+            1e-9,
+            1e-9
+        ));
+    }
+}
+
+#[test]
+pub fn test_welford_variance_merge() {
+    let numbers: Vec<f64> = (0..100)
+        .map(|i| ((i * 37) % 23) as f64 * 1.5 - 10.0)
+        .collect();
+
+    let mut whole = math::WelfordVariance::new();
+    numbers.iter().for_each(|v| whole.update(*v));
+
+    for split in [0, 1, 37, 50, 99, 100] {
+        let mut left = math::WelfordVariance::new();
+        let mut right = math::WelfordVariance::new();
+        numbers[..split].iter().for_each(|v| left.update(*v));
+        numbers[split..].iter().for_each(|v| right.update(*v));
+
+        let merged = math::WelfordVariance::merge(&left, &right);
+        assert_eq!(merged.count(), whole.count());
+        assert!(math::is_close(merged.mean(), whole.mean(), 1e-9, 1e-12));
+        assert!(math::is_close(
+            merged.variance(),
+            math::variance(&numbers),
+            1e-9,
+            0.0
+        ));
+        assert!(math::is_close(
+            merged.sample_variance(),
+            math::sample_variance(&numbers),
+            1e-9,
+            0.0
+        ));
+    }
+
+    let empty = math::WelfordVariance::new();
+    assert_eq!(math::WelfordVariance::merge(&empty, &empty), empty);
+    assert_eq!(math::WelfordVariance::merge(&whole, &empty), whole);
+    assert_eq!(math::WelfordVariance::merge(&empty, &whole), whole);
+}
+
+#[test]
+pub fn test_modular_exponentiation() {
+    assert_eq!(math::modular_exponentiation(4, 13, 497), 445);
+    assert_eq!(math::modular_exponentiation(2, 10, 1000), 24);
+    assert_eq!(math::modular_exponentiation(3, 200, 13), 9);
+    assert_eq!(math::modular_exponentiation(0, 0, 7), 1);
+    assert_eq!(math::modular_exponentiation(0, 5, 7), 0);
+    assert_eq!(math::modular_exponentiation(10, 0, 7), 1);
+
+    // 模数为 1 时总是返回 0
+    assert_eq!(math::modular_exponentiation(0, 0, 1), 0);
+    assert_eq!(math::modular_exponentiation(123, 456, 1), 0);
+
+    // 费马小定理：a^(p-1) ≡ 1 (mod p)
+    let p = 1_000_000_007;
+    for a in [2, 3, 12345, 999_999_999] {
+        assert_eq!(math::modular_exponentiation(a, p - 1, p), 1);
+    }
+
+    // 中间结果超过 u64 也不会溢出
+    let m = u64::MAX - 58; // 2^64 - 59，最大的 64 位素数
+    assert_eq!(math::modular_exponentiation(u64::MAX, 2, m), 3364);
+    assert_eq!(math::modular_exponentiation(2, m - 1, m), 1);
+
+    // RSA 教科书示例：p = 61, q = 53, n = 3233, e = 17, d = 2753
+    let (n, e, d) = (3233, 17, 2753);
+    let ciphertext = math::modular_exponentiation(65, e, n);
+    assert_eq!(ciphertext, 2790);
+    assert_eq!(math::modular_exponentiation(ciphertext, d, n), 65);
+    for message in [0, 1, 2, 42, 1000, 3232] {
+        let encrypted = math::modular_exponentiation(message, e, n);
+        assert_eq!(math::modular_exponentiation(encrypted, d, n), message);
+    }
+}
+
+#[test]
+#[should_panic(expected = "modulus must not be zero")]
+pub fn test_modular_exponentiation_zero_modulus() {
+    math::modular_exponentiation(2, 3, 0);
+}
+
+#[test]
+pub fn test_modular_inverse() {
+    assert_eq!(math::modular_inverse(3, 11), Some(4));
+    assert_eq!(math::modular_inverse(10, 17), Some(12));
+    assert_eq!(math::modular_inverse(1, 5), Some(1));
+    assert_eq!(math::modular_inverse(14, 11), Some(4));
+
+    // RSA 私钥：d = e^-1 mod φ(n)
+    assert_eq!(math::modular_inverse(17, 3120), Some(2753));
+    assert_eq!(math::modular_inverse(65537, 3120), Some(2753)); // 65537 ≡ 17 (mod 3120)
+
+    // 不互质
+    assert_eq!(math::modular_inverse(6, 9), None);
+    assert_eq!(math::modular_inverse(0, 7), None);
+    assert_eq!(math::modular_inverse(4, 8), None);
+    assert_eq!(math::modular_inverse(7, 7), None);
+
+    // 特殊模数
+    assert_eq!(math::modular_inverse(5, 0), None);
+    assert_eq!(math::modular_inverse(5, 1), Some(0));
+
+    // 逆元满足 a * x ≡ 1 (mod m)
+    let m = 1_000_000_007;
+    for a in [2, 3, 123_456_789, m - 1, u64::MAX] {
+        let inverse = math::modular_inverse(a, m).unwrap();
+        assert!(inverse < m);
+        assert_eq!((a as u128 % m as u128) * inverse as u128 % m as u128, 1);
+    }
+    let big = u64::MAX - 58;
+    let inverse = math::modular_inverse(u64::MAX - 1, big).unwrap();
+    assert_eq!(
+        (u64::MAX - 1) as u128 % big as u128 * inverse as u128 % big as u128,
+        1
+    );
+}
+
+#[test]
+pub fn test_matrix_multiply() {
+    let a = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]];
+    let b = vec![vec![7.0, 8.0], vec![9.0, 10.0], vec![11.0, 12.0]];
+    assert_eq!(
+        math::matrix_multiply(&a, &b),
+        Some(vec![vec![58.0, 64.0], vec![139.0, 154.0]])
+    );
+    assert_eq!(
+        math::matrix_multiply(&b, &a),
+        Some(vec![
+            vec![39.0, 54.0, 69.0],
+            vec![49.0, 68.0, 87.0],
+            vec![59.0, 82.0, 105.0]
+        ])
+    );
+
+    // A * I = A，I * A = A
+    assert_eq!(
+        math::matrix_multiply(&a, &math::identity_matrix(3)),
+        Some(a.clone())
+    );
+    assert_eq!(
+        math::matrix_multiply(&math::identity_matrix(2), &a),
+        Some(a.clone())
+    );
+
+    // 行向量与列向量
+    let row = vec![vec![1.0, 2.0, 3.0]];
+    let col = vec![vec![4.0], vec![5.0], vec![6.0]];
+    assert_eq!(math::matrix_multiply(&row, &col), Some(vec![vec![32.0]]));
+    assert_eq!(math::matrix_multiply(&col, &row).unwrap().len(), 3);
+
+    // (AB)^T = B^T A^T
+    let ab = math::matrix_multiply(&a, &b).unwrap();
+    let bt_at =
+        math::matrix_multiply(&math::matrix_transpose(&b), &math::matrix_transpose(&a)).unwrap();
+    assert_eq!(math::matrix_transpose(&ab), bt_at);
+
+    // 维度不匹配
+    assert_eq!(math::matrix_multiply(&a, &a), None);
+    assert_eq!(math::matrix_multiply(&row, &row), None);
+    // 锯齿矩阵与空矩阵
+    let jagged = vec![vec![1.0, 2.0], vec![3.0]];
+    assert_eq!(math::matrix_multiply(&jagged, &b[..2]), None);
+    assert_eq!(
+        math::matrix_multiply(&a, &[vec![1.0], vec![2.0, 3.0], vec![4.0]]),
+        None
+    );
+    assert_eq!(math::matrix_multiply(&[], &b), None);
+    assert_eq!(math::matrix_multiply(&[vec![]], &[vec![]]), None);
+}
+
+#[test]
+pub fn test_matrix_transpose_and_identity() {
+    let m = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]];
+    let t = math::matrix_transpose(&m);
+    assert_eq!(t, vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]);
+    assert_eq!(math::matrix_transpose(&t), m);
+    assert_eq!(
+        math::matrix_transpose(&[vec![1.0, 2.0]]),
+        vec![vec![1.0], vec![2.0]]
+    );
+    assert!(math::matrix_transpose(&[]).is_empty());
+    assert!(math::matrix_transpose(&[vec![], vec![]]).is_empty());
+    assert_eq!(
+        math::matrix_transpose(&[vec![1.0, 2.0, 3.0], vec![4.0]]),
+        vec![vec![1.0, 4.0]]
+    );
+
+    assert!(math::identity_matrix(0).is_empty());
+    assert_eq!(math::identity_matrix(1), vec![vec![1.0]]);
+    let identity = math::identity_matrix(3);
+    assert_eq!(
+        identity,
+        vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1.0]
+        ]
+    );
+    assert_eq!(math::matrix_transpose(&identity), identity);
+}
+
+#[test]
+pub fn test_count_bits() {
+    assert_eq!(math::count_set_bits(0), 0);
+    assert_eq!(math::count_set_bits(1), 1);
+    assert_eq!(math::count_set_bits(0b1011_0110), 5);
+    assert_eq!(math::count_set_bits(0xFF00_FF00_FF00_FF00), 32);
+    assert_eq!(math::count_set_bits(u64::MAX), 64);
+
+    assert_eq!(math::count_trailing_zeros(0), 64);
+    assert_eq!(math::count_trailing_zeros(1), 0);
+    assert_eq!(math::count_trailing_zeros(0b1011_0000), 4);
+    assert_eq!(math::count_trailing_zeros(1 << 63), 63);
+    assert_eq!(math::count_trailing_zeros(u64::MAX), 0);
+
+    assert_eq!(math::count_leading_zeros(0), 64);
+    assert_eq!(math::count_leading_zeros(1), 63);
+    assert_eq!(math::count_leading_zeros(0b1011_0000), 56);
+    assert_eq!(math::count_leading_zeros(1 << 63), 0);
+    assert_eq!(math::count_leading_zeros(u64::MAX), 0);
+}
+
+#[test]
+pub fn test_bit_length() {
+    assert_eq!(math::bit_length(0), 0);
+    assert_eq!(math::bit_length(1), 1);
+    assert_eq!(math::bit_length(2), 2);
+    assert_eq!(math::bit_length(3), 2);
+    assert_eq!(math::bit_length(255), 8);
+    assert_eq!(math::bit_length(256), 9);
+    assert_eq!(math::bit_length(u64::MAX), 64);
+
+    for n in [1u64, 7, 100, 1 << 40, u64::MAX / 3] {
+        let length = math::bit_length(n);
+        assert!(n >> (length - 1) == 1);
+        assert_eq!(length, math::log2_floor(n).unwrap() + 1);
+    }
+}
+
+#[test]
+pub fn test_bit_reverse() {
+    assert_eq!(math::bit_reverse(0, 8), 0);
+    assert_eq!(math::bit_reverse(0b0001, 4), 0b1000);
+    assert_eq!(math::bit_reverse(0b0110, 4), 0b0110);
+    assert_eq!(math::bit_reverse(0b1101, 4), 0b1011);
+    assert_eq!(math::bit_reverse(0b0000_0001, 8), 0b1000_0000);
+    assert_eq!(math::bit_reverse(0b1110_0100, 8), 0b0010_0111);
+
+    // FFT 中 8 点的位反转置换
+    let order: Vec<u64> = (0..8).map(|i| math::bit_reverse(i, 3)).collect();
+    assert_eq!(order, vec![0, 4, 2, 6, 1, 5, 3, 7]);
+
+    // 高于 bit_width 的位被丢弃
+    assert_eq!(math::bit_reverse(0b1_0001, 4), 0b1000);
+    assert_eq!(math::bit_reverse(u64::MAX, 5), 0b11111);
+
+    // 边界位宽
+    assert_eq!(math::bit_reverse(u64::MAX, 0), 0);
+    assert_eq!(math::bit_reverse(1, 1), 1);
+    assert_eq!(math::bit_reverse(1, 64), 1 << 63);
+    assert_eq!(math::bit_reverse(u64::MAX, 64), u64::MAX);
+    assert_eq!(math::bit_reverse(1, 200), 1 << 63);
+
+    // 反转两次还原
+    for n in [0u64, 1, 0xDEAD_BEEF, u64::MAX / 7] {
+        assert_eq!(math::bit_reverse(math::bit_reverse(n, 64), 64), n);
+        assert_eq!(
+            math::bit_reverse(math::bit_reverse(n, 32), 32),
+            n & 0xFFFF_FFFF
+        );
+    }
+}
+
+#[test]
+pub fn test_factorial_and_binomial_coefficient() {
+    assert_eq!(math::factorial(0), Some(1));
+    assert_eq!(math::factorial(1), Some(1));
+    assert_eq!(math::factorial(10), Some(3_628_800));
+    assert_eq!(math::factorial(20), Some(2_432_902_008_176_640_000));
+    assert_eq!(math::factorial(21), None);
+
+    assert_eq!(math::binomial_coefficient(0, 0), Some(1));
+    assert_eq!(math::binomial_coefficient(5, 0), Some(1));
+    assert_eq!(math::binomial_coefficient(5, 5), Some(1));
+    assert_eq!(math::binomial_coefficient(5, 2), Some(10));
+    assert_eq!(math::binomial_coefficient(5, 3), Some(10));
+    assert_eq!(math::binomial_coefficient(3, 4), Some(0));
+    assert_eq!(math::binomial_coefficient(52, 5), Some(2_598_960));
+    assert_eq!(
+        math::binomial_coefficient(64, 32),
+        Some(1_832_624_140_942_590_534)
+    );
+    assert_eq!(
+        math::binomial_coefficient(67, 33),
+        Some(14_226_520_737_620_288_370)
+    );
+    assert_eq!(math::binomial_coefficient(68, 34), None);
+
+    // 帕斯卡恒等式 C(n, k) = C(n-1, k-1) + C(n-1, k)
+    for n in 1..30 {
+        for k in 1..n {
+            assert_eq!(
+                math::binomial_coefficient(n, k).unwrap(),
+                math::binomial_coefficient(n - 1, k - 1).unwrap()
+                    + math::binomial_coefficient(n - 1, k).unwrap()
+            );
+        }
+    }
+}
+
+#[test]
+pub fn test_combination_iter() {
+    for n in 0..=10 {
+        for k in 0..=n + 1 {
+            let combinations: Vec<Vec<u32>> = math::combination_iter(n, k).collect();
+            assert_eq!(
+                combinations.len() as u64,
+                math::binomial_coefficient(n, k).unwrap()
+            );
+            for combination in &combinations {
+                assert_eq!(combination.len() as u32, k);
+                assert!(combination.windows(2).all(|w| w[0] < w[1]));
+                assert!(combination.iter().all(|&x| x < n));
+            }
+            let mut unique = combinations.clone();
+            unique.sort();
+            unique.dedup();
+            assert_eq!(unique.len(), combinations.len());
+        }
+    }
+
+    // 第一个和最后一个组合
+    let mut iter = math::combination_iter(10, 3);
+    assert_eq!(iter.next(), Some(vec![0, 1, 2]));
+    assert_eq!(iter.last(), Some(vec![7, 8, 9]));
+    assert_eq!(
+        math::combination_iter(5, 0).collect::<Vec<_>>(),
+        vec![Vec::<u32>::new()]
+    );
+    assert_eq!(math::combination_iter(3, 4).count(), 0);
+
+    // 大规模时按需生成
+    let mut iter = math::combination_iter(64, 32);
+    assert_eq!(iter.next(), Some((0..32).collect::<Vec<u32>>()));
+    assert_eq!(
+        math::combination_iter(64, 64).last(),
+        Some((0..64).collect::<Vec<u32>>())
+    );
+    assert_eq!(math::combination_iter(64, 63).count(), 64);
+}
+
+#[test]
+#[should_panic(expected = "n must not exceed 64")]
+pub fn test_combination_iter_too_many_elements() {
+    let _ = math::combination_iter(65, 1);
+}
+
+#[test]
+pub fn test_permutation_iter() {
+    assert_eq!(
+        math::permutation_iter(&[]).collect::<Vec<_>>(),
+        vec![Vec::<u32>::new()]
+    );
+    assert_eq!(
+        math::permutation_iter(&[7]).collect::<Vec<_>>(),
+        vec![vec![7]]
+    );
+    assert_eq!(
+        math::permutation_iter(&[1, 2]).collect::<Vec<_>>(),
+        vec![vec![1, 2], vec![2, 1]]
+    );
+
+    for n in 0..=7u32 {
+        let items: Vec<u32> = (0..n).collect();
+        let permutations: Vec<Vec<u32>> = math::permutation_iter(&items).collect();
+        assert_eq!(permutations.len() as u64, math::factorial(n).unwrap());
+        assert_eq!(permutations[0], items);
+
+        let mut unique = permutations.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), permutations.len());
+        for permutation in &permutations {
+            let mut sorted = permutation.clone();
+            sorted.sort();
+            assert_eq!(sorted, items);
+        }
+
+        // Heap 算法相邻两个排列之间只交换一对元素
+        for pair in permutations.windows(2) {
+            let differences = pair[0].iter().zip(&pair[1]).filter(|(a, b)| a != b).count();
+            assert_eq!(differences, 2);
+        }
+    }
+
+    // 第一个和最后一个排列
+    let mut iter = math::permutation_iter(&[1, 2, 3, 4]);
+    assert_eq!(iter.next(), Some(vec![1, 2, 3, 4]));
+    assert_eq!(iter.last(), Some(vec![2, 3, 4, 1]));
+
+    // 元素重复时排列也会重复
+    assert_eq!(math::permutation_iter(&[5, 5, 6]).count(), 6);
+}
+
+#[test]
+pub fn test_approximate_pi() {
+    assert_eq!(math::PI, std::f64::consts::PI);
+    assert_eq!(math::approximate_pi(0), 0.0);
+    assert_eq!(math::approximate_pi(1), 4.0);
+    assert!((math::approximate_pi(2) - 8.0 / 3.0).abs() < 1e-12);
+
+    // 误差小于 1 / iterations，且随项数增加而收敛
+    let mut previous_error = f64::MAX;
+    for iterations in [10, 100, 1_000, 10_000, 100_000, 1_000_000] {
+        let error = (math::approximate_pi(iterations) - math::PI).abs();
+        assert!(error < 1.0 / iterations as f64);
+        assert!(error < previous_error);
+        previous_error = error;
+    }
+}
+
+#[test]
+pub fn test_approximate_pi_bbp() {
+    assert_eq!(math::approximate_pi_bbp(0), "3");
+    assert_eq!(math::approximate_pi_bbp(1), "3.2");
+    assert_eq!(
+        math::approximate_pi_bbp(32),
+        "3.243F6A8885A308D313198A2E03707344"
+    );
+
+    // 与 f64 常量的十六进制展开一致
+    let hex = math::approximate_pi_bbp(12);
+    let fraction = u64::from_str_radix(&hex[2..], 16).unwrap();
+    let value = 3.0 + fraction as f64 / 16f64.powi(12);
+    assert!((value - math::PI).abs() < 1e-13);
+}
+
+#[test]
+pub fn test_safe_cast_f64_to_i64() {
+    assert_eq!(math::safe_cast_f64_to_i64(0.0), Some(0));
+    assert_eq!(math::safe_cast_f64_to_i64(-0.0), Some(0));
+    assert_eq!(math::safe_cast_f64_to_i64(3.99), Some(3));
+    assert_eq!(math::safe_cast_f64_to_i64(-3.99), Some(-3));
+    assert_eq!(math::safe_cast_f64_to_i64(f64::NAN), None);
+    assert_eq!(math::safe_cast_f64_to_i64(f64::INFINITY), None);
+    assert_eq!(math::safe_cast_f64_to_i64(f64::NEG_INFINITY), None);
+
+    // 边界：i64::MIN 可以精确表示；i64::MAX 转为 f64 后舍入为 2^63，已越界
+    assert_eq!(math::safe_cast_f64_to_i64(i64::MIN as f64), Some(i64::MIN));
+    assert_eq!(math::safe_cast_f64_to_i64(i64::MAX as f64), None);
+    assert_eq!(
+        math::safe_cast_f64_to_i64(9_223_372_036_854_774_784.0),
+        Some(9_223_372_036_854_774_784)
+    );
+    assert_eq!(
+        math::safe_cast_f64_to_i64(i64::MIN as f64 - 1.0),
+        Some(i64::MIN)
+    );
+    assert_eq!(
+        math::safe_cast_f64_to_i64((i64::MIN as f64).next_down()),
+        None
+    );
+    assert_eq!(math::safe_cast_f64_to_i64(i64::MAX as f64 + 1.0), None);
+    assert_eq!(math::safe_cast_f64_to_i64(-(2f64.powi(64))), None);
+    assert_eq!(math::safe_cast_f64_to_i64(1e300), None);
+}
+
+#[test]
+pub fn test_safe_cast_f64_to_u64() {
+    assert_eq!(math::safe_cast_f64_to_u64(0.0), Some(0));
+    assert_eq!(math::safe_cast_f64_to_u64(-0.0), Some(0));
+    assert_eq!(math::safe_cast_f64_to_u64(-0.999), Some(0));
+    assert_eq!(math::safe_cast_f64_to_u64(-1.0), None);
+    assert_eq!(math::safe_cast_f64_to_u64(42.9), Some(42));
+    assert_eq!(math::safe_cast_f64_to_u64(f64::NAN), None);
+    assert_eq!(math::safe_cast_f64_to_u64(f64::INFINITY), None);
+    assert_eq!(math::safe_cast_f64_to_u64(f64::NEG_INFINITY), None);
+    assert_eq!(
+        math::safe_cast_f64_to_u64(i64::MAX as f64),
+        Some(9_223_372_036_854_775_808)
+    );
+    assert_eq!(math::safe_cast_f64_to_u64(u64::MAX as f64), None);
+    assert_eq!(
+        math::safe_cast_f64_to_u64((u64::MAX as f64).next_down()),
+        Some(18_446_744_073_709_549_568)
+    );
+}
+
+#[test]
+pub fn test_safe_cast_integers() {
+    assert_eq!(math::safe_cast_i64_to_u64(0), Some(0));
+    assert_eq!(math::safe_cast_i64_to_u64(i64::MAX), Some(i64::MAX as u64));
+    assert_eq!(math::safe_cast_i64_to_u64(-1), None);
+    assert_eq!(math::safe_cast_i64_to_u64(i64::MIN), None);
+
+    assert_eq!(math::safe_cast_u64_to_i64(0), Some(0));
+    assert_eq!(math::safe_cast_u64_to_i64(i64::MAX as u64), Some(i64::MAX));
+    assert_eq!(math::safe_cast_u64_to_i64(i64::MAX as u64 + 1), None);
+    assert_eq!(math::safe_cast_u64_to_i64(u64::MAX), None);
+}
+
+#[test]
+pub fn test_sigmoid() {
+    assert_eq!(math::sigmoid(0.0), 0.5);
+    assert!((math::sigmoid(1000.0) - 1.0).abs() < 1e-12);
+    assert!(math::sigmoid(-1000.0).abs() < 1e-12);
+    assert_eq!(math::sigmoid(f64::INFINITY), 1.0);
+    assert_eq!(math::sigmoid(f64::NEG_INFINITY), 0.0);
+    // sigmoid(-x) = 1 - sigmoid(x)
+    for x in [0.1, 0.5, 1.0, 3.0, 10.0] {
+        assert!((math::sigmoid(-x) - (1.0 - math::sigmoid(x))).abs() < 1e-12);
+    }
+}
+
+#[test]
+pub fn test_relu_and_leaky_relu() {
+    assert_eq!(math::relu(-1.0), 0.0);
+    assert_eq!(math::relu(0.0), 0.0);
+    assert_eq!(math::relu(1.0), 1.0);
+    assert_eq!(math::relu(f64::NEG_INFINITY), 0.0);
+
+    assert_eq!(math::leaky_relu(3.0, 0.1), 3.0);
+    assert_eq!(math::leaky_relu(0.0, 0.1), 0.0);
+    for x in [-0.001, -1.0, -50.0] {
+        let y = math::leaky_relu(x, 0.01);
+        assert!(y < 0.0);
+        assert!((y - 0.01 * x).abs() < 1e-15);
+    }
+    // alpha 为 0 时等价于 relu
+    assert_eq!(math::leaky_relu(-5.0, 0.0), 0.0);
+}
+
+#[test]
+pub fn test_tanh_and_elu() {
+    assert_eq!(math::tanh(0.0), 0.0);
+    // tanh 是奇函数
+    for x in [0.1, 0.5, 1.0, 3.0, 20.0] {
+        assert_eq!(math::tanh(-x), -math::tanh(x));
+        assert!(math::tanh(x) > 0.0 && math::tanh(x) <= 1.0);
+    }
+    // tanh(x) = 2 * sigmoid(2x) - 1
+    assert!((math::tanh(0.7) - (2.0 * math::sigmoid(1.4) - 1.0)).abs() < 1e-12);
+
+    assert_eq!(math::elu(2.5, 1.0), 2.5);
+    assert_eq!(math::elu(0.0, 1.0), 0.0);
+    assert!((math::elu(-1.0, 2.0) - 2.0 * ((-1.0f64).exp() - 1.0)).abs() < 1e-12);
+    assert!((math::elu(-100.0, 1.5) + 1.5).abs() < 1e-12);
+    assert!(math::elu(-1e-10, 1.0) < 0.0);
+}
+
+#[test]
+pub fn test_geometric_sequence() {
+    assert!(math::geometric_sequence(3.0, 2.0, 0).is_empty());
+    assert_eq!(math::geometric_sequence(3.0, 2.0, 1), vec![3.0]);
+    let sequence = math::geometric_sequence(3.0, 2.0, 6);
+    assert_eq!(sequence.len(), 6);
+    assert_eq!(sequence[4], 48.0);
+    assert_eq!(math::geometric_sequence(1.0, -0.5, 5)[4], 0.0625);
+    assert_eq!(math::geometric_sequence(5.0, 1.0, 3), vec![5.0, 5.0, 5.0]);
+    assert_eq!(math::geometric_sequence(2.0, 0.0, 3), vec![2.0, 0.0, 0.0]);
+
+    // 复利：本金 1000，年利率 5%
+    let balances = math::geometric_sequence(1000.0, 1.05, 11);
+    assert!((balances[10] - 1628.894626777442).abs() < 1e-9);
+}
+
+#[test]
+pub fn test_arithmetic_sequence() {
+    assert!(math::arithmetic_sequence(1.0, 2.0, 0).is_empty());
+    let sequence = math::arithmetic_sequence(1.0, 2.0, 5);
+    assert_eq!(sequence, vec![1.0, 3.0, 5.0, 7.0, 9.0]);
+    assert_eq!(sequence[4], 9.0);
+    assert_eq!(math::arithmetic_sequence(10.0, -2.5, 5)[4], 0.0);
+
+    // 逐项计算，大量项之后仍然精确
+    let sequence = math::arithmetic_sequence(0.0, 0.1, 1001);
+    assert!((sequence[1000] - 100.0).abs() < 1e-12);
+}
+
+#[test]
+pub fn test_linspace() {
+    assert!(math::linspace(0.0, 1.0, 0).is_empty());
+    assert_eq!(math::linspace(2.0, 5.0, 1), vec![2.0]);
+    assert_eq!(math::linspace(2.0, 5.0, 2), vec![2.0, 5.0]);
+    assert_eq!(
+        math::linspace(0.0, 10.0, 6),
+        vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0]
+    );
+    assert_eq!(math::linspace(1.0, 1.0, 3), vec![1.0, 1.0, 1.0]);
+
+    // 两端精确相等
+    for count in [3, 7, 10, 11, 100] {
+        let values = math::linspace(0.1, 0.7, count);
+        assert_eq!(values.len(), count);
+        assert_eq!(values[0], 0.1);
+        assert_eq!(values[count - 1], 0.7);
+        assert!(values.windows(2).all(|w| w[0] < w[1]));
+    }
+    let values = math::linspace(1.0, -1.0, 5);
+    assert_eq!(values, vec![1.0, 0.5, 0.0, -0.5, -1.0]);
+}
+
+#[test]
+pub fn test_statistics_summary() {
+    assert!(math::statistics_summary(&[]).is_none());
+
+    let numbers = [9.0, 2.0, 5.0, 4.0, 4.0, 7.0, 4.0, 5.0];
+    let summary = math::statistics_summary(&numbers).unwrap();
+    assert_eq!(summary.count, numbers.len());
+    assert_eq!(summary.mean, math::average(&numbers));
+    assert!((summary.variance - math::variance(&numbers)).abs() < 1e-12);
+    assert!((summary.std_dev - math::standard_deviation(&numbers)).abs() < 1e-12);
+    assert_eq!(Some(summary.min), math::min_in_array(&numbers));
+    assert_eq!(Some(summary.max), math::max_in_array(&numbers));
+    let stats = math::range_statistics(&numbers).unwrap();
+    assert_eq!(summary.median, stats.median);
+    assert_eq!(summary.range, 7.0);
+    assert_eq!(summary.mode_count, 3);
+    assert_eq!(summary.q1, 4.0);
+    assert_eq!(summary.q3, 5.5);
+    assert_eq!(summary.iqr, 1.5);
+
+    // 单个元素
+    let summary = math::statistics_summary(&[3.5]).unwrap();
+    assert_eq!(summary.count, 1);
+    assert_eq!(summary.median, 3.5);
+    assert_eq!(summary.q1, 3.5);
+    assert_eq!(summary.q3, 3.5);
+    assert_eq!(summary.iqr, 0.0);
+    assert_eq!(summary.variance, 0.0);
+    assert_eq!(summary.mode_count, 1);
+
+    // 四分位数线性插值
+    let summary = math::statistics_summary(&[1.0, 2.0, 3.0, 4.0]).unwrap();
+    assert_eq!(summary.q1, 1.75);
+    assert_eq!(summary.q3, 3.25);
+    assert_eq!(summary.median, 2.5);
+    assert_eq!(summary.mode_count, 1);
+}
+
+#[test]
+pub fn test_statistics_summary_display() {
+    let summary = math::statistics_summary(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).unwrap();
+    let expected = [
+        "count                   8",
+        "mean               5.0000",
+        "median             4.5000",
+        "std_dev            2.0000",
+        "variance           4.0000",
+        "min                2.0000",
+        "q1                 4.0000",
+        "q3                 5.5000",
+        "max                9.0000",
+        "range              7.0000",
+        "iqr                1.5000",
+        "mode_count              3",
+    ]
+    .join("\n");
+    assert_eq!(summary.to_string(), expected);
+}
+
+#[test]
+pub fn test_vector_magnitude_and_normalize() {
+    assert_eq!(math::vector_magnitude(&[]), 0.0);
+    assert_eq!(math::vector_magnitude(&[-5.0]), 5.0);
+    assert_eq!(math::vector_magnitude(&[3.0, 4.0]), 5.0);
+    assert_eq!(math::vector_magnitude(&[1.0, 2.0, 2.0]), 3.0);
+    // 分量的平方超出 f64 范围时仍能正确计算
+    let large = math::vector_magnitude(&[3e200, 4e200]);
+    assert!((large / 5e200 - 1.0).abs() < 1e-12);
+
+    for v in [
+        vec![3.0, 4.0],
+        vec![1.0, 1.0, 1.0],
+        vec![-2.5, 0.0, 7.0, 1e-3],
+        vec![1e-200, 1e-200],
+        vec![42.0],
+    ] {
+        let unit = math::vector_normalize(&v).unwrap();
+        assert!((math::vector_magnitude(&unit) - 1.0).abs() < 1e-12);
+        // 方向不变：每个分量的符号一致
+        assert!(v
+            .iter()
+            .zip(&unit)
+            .all(|(a, b)| a.signum() == b.signum() || *a == 0.0));
+    }
+
+    assert_eq!(math::vector_normalize(&[]), None);
+    assert_eq!(math::vector_normalize(&[0.0, 0.0, 0.0]), None);
+    assert_eq!(math::vector_normalize(&[f64::INFINITY, 1.0]), None);
+    assert_eq!(math::vector_normalize(&[f64::NAN]), None);
+}
+
+#[test]
+pub fn test_vector_add_and_scale() {
+    let v = [1.5, -2.0, 3.0];
+    let zero = [0.0; 3];
+
+    // 加法单位元
+    assert_eq!(math::vector_add(&v, &zero), Some(v.to_vec()));
+    assert_eq!(math::vector_add(&zero, &v), Some(v.to_vec()));
+    assert_eq!(
+        math::vector_add(&v, &[1.0, 1.0, 1.0]),
+        Some(vec![2.5, -1.0, 4.0])
+    );
+    assert_eq!(math::vector_add(&[], &[]), Some(vec![]));
+    assert_eq!(math::vector_add(&v, &[1.0, 2.0]), None);
+
+    // v + (-1 * v) = 0
+    let negated = math::vector_scale(&v, -1.0);
+    assert_eq!(math::vector_add(&v, &negated), Some(zero.to_vec()));
+
+    // 乘以 0 得到零向量
+    assert_eq!(math::vector_scale(&v, 0.0), vec![0.0, 0.0, 0.0]);
+    assert_eq!(math::vector_scale(&v, 1.0), v.to_vec());
+    assert_eq!(math::vector_scale(&v, 2.0), vec![3.0, -4.0, 6.0]);
+    assert!(math::vector_scale(&[], 5.0).is_empty());
+    assert_eq!(
+        math::vector_magnitude(&math::vector_scale(&[3.0, 4.0], -2.0)),
+        10.0
+    );
+}
@@ -9,3 +9,185 @@ fn test_stopwatch() {
     assert!(sw.elapsed().as_secs() >= 1);
     println!("{}", sw.elapsed().as_secs());
 }
+
+#[cfg(feature = "stopwatch-serde")]
+#[test]
+fn test_stopwatch_serde_stopped() {
+    let mut sw = StopWatch::start_new();
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    sw.stop();
+
+    let json = serde_json::to_string(&sw).unwrap();
+    assert_eq!(
+        json,
+        format!(
+            r#"{{"elapsed_nanos":{},"is_running":false,"lap_count":0}}"#,
+            sw.elapsed_nanos()
+        )
+    );
+
+    let restored: StopWatch = serde_json::from_str(&json).unwrap();
+    assert!(!restored.is_running());
+    assert_eq!(restored.elapsed(), sw.elapsed());
+    assert_eq!(restored.elapsed_millis(), sw.elapsed_millis());
+}
+
+#[cfg(feature = "stopwatch-serde")]
+#[test]
+fn test_stopwatch_serde_running() {
+    let sw = StopWatch::start_new();
+    std::thread::sleep(std::time::Duration::from_millis(20));
+
+    let value = serde_json::to_value(&sw).unwrap();
+    assert_eq!(value["is_running"], true);
+    let elapsed_nanos = value["elapsed_nanos"].as_u64().unwrap();
+    assert!(elapsed_nanos >= 20_000_000);
+
+    let mut restored: StopWatch = serde_json::from_value(value).unwrap();
+    assert!(!restored.is_running());
+    assert_eq!(restored.elapsed_nanos(), elapsed_nanos as u128);
+    assert_eq!(restored.elapsed_millis(), elapsed_nanos as u128 / 1_000_000);
+
+    // 恢复后的秒表可以继续计时
+    restored.start();
+    assert!(restored.elapsed_nanos() >= elapsed_nanos as u128);
+
+    let restored: StopWatch =
+        serde_json::from_str(r#"{"elapsed_nanos":3723004000000,"is_running":false}"#).unwrap();
+    assert_eq!(restored.elapsed_millis(), 3_723_004);
+    assert!(serde_json::from_str::<StopWatch>(r#"{"is_running":false}"#).is_err());
+}
+
+#[test]
+fn test_from_elapsed() {
+    let mut sw = StopWatch::from_elapsed(std::time::Duration::from_secs(5));
+    assert!(!sw.is_running());
+    assert_eq!(sw.elapsed(), std::time::Duration::from_secs(5));
+    sw.start();
+    assert!(sw.elapsed() >= std::time::Duration::from_secs(5));
+    sw.reset();
+    assert_eq!(sw.elapsed(), std::time::Duration::ZERO);
+}
+
+#[test]
+fn test_elapsed_human_readable() {
+    let cases = [
+        (0, "0.000 seconds", "0.000s"),
+        (3_456, "3.456 seconds", "3.456s"),
+        (9_999, "9.999 seconds", "9.999s"),
+        (10_000, "10 seconds", "10s"),
+        (42_789, "42 seconds", "42s"),
+        (60_000, "1 minute 0 seconds", "1m0s"),
+        (61_000, "1 minute 1 second", "1m1s"),
+        (123_456, "2 minutes 3 seconds", "2m3s"),
+        (3_599_999, "59 minutes 59 seconds", "59m59s"),
+        (3_600_000, "1 hour 0 minutes", "1h0m"),
+        (3_723_456, "1 hour 2 minutes", "1h2m"),
+        (86_399_000, "23 hours 59 minutes", "23h59m"),
+        (86_400_000, "1 day 0 hours", "1d0h"),
+        (90_000_000, "1 day 1 hour", "1d1h"),
+        (10 * 86_400_000 + 5 * 3_600_000, "10 days 5 hours", "10d5h"),
+    ];
+    for (millis, long, short) in cases {
+        let sw = StopWatch::from_elapsed(std::time::Duration::from_millis(millis));
+        assert_eq!(sw.elapsed_human_readable(), long);
+        assert_eq!(sw.elapsed_short_human_readable(), short);
+    }
+}
+
+#[test]
+fn test_threshold_exceeded_and_remaining() {
+    use std::time::Duration;
+
+    let mut sw = StopWatch::new();
+    assert!(!sw.threshold_exceeded(Duration::ZERO));
+    assert_eq!(sw.remaining(Duration::ZERO), Some(Duration::ZERO));
+    assert_eq!(
+        sw.remaining(Duration::from_secs(1)),
+        Some(Duration::from_secs(1))
+    );
+
+    sw.start();
+    std::thread::sleep(Duration::from_millis(30));
+    assert!(sw.threshold_exceeded(Duration::from_millis(20)));
+    assert_eq!(sw.remaining(Duration::from_millis(20)), None);
+    assert!(!sw.threshold_exceeded(Duration::from_secs(60)));
+    assert!(sw.remaining(Duration::from_secs(60)).unwrap() <= Duration::from_millis(59_970));
+    // 读取阈值不会停止秒表
+    assert!(sw.is_running());
+
+    sw.stop();
+    let elapsed = sw.elapsed();
+    assert!(sw.threshold_exceeded(elapsed - Duration::from_nanos(1)));
+    assert!(!sw.threshold_exceeded(elapsed));
+    assert_eq!(sw.remaining(elapsed), Some(Duration::ZERO));
+    assert_eq!(
+        sw.remaining(elapsed + Duration::from_secs(1)),
+        Some(Duration::from_secs(1))
+    );
+    // 已停止的秒表耗时不会因为调用而改变
+    assert_eq!(sw.elapsed(), elapsed);
+
+    sw.start();
+    std::thread::sleep(Duration::from_millis(5));
+    sw.stop();
+    assert!(sw.threshold_exceeded(elapsed));
+    assert_eq!(sw.remaining(elapsed), None);
+}
+
+#[test]
+fn test_laps_and_split_times() {
+    use std::time::Duration;
+
+    let mut sw = StopWatch::start_new();
+    assert!(sw.split_times().is_empty());
+    assert_eq!(sw.fastest_lap(), None);
+    assert_eq!(sw.slowest_lap(), None);
+
+    let mut laps = Vec::new();
+    for millis in [15, 5, 25] {
+        std::thread::sleep(Duration::from_millis(millis));
+        laps.push(sw.lap());
+    }
+    assert_eq!(sw.elapsed_laps(), laps.as_slice());
+
+    let splits = sw.split_times();
+    assert_eq!(splits.len(), 3);
+    assert_eq!(splits[0], laps[0]);
+    assert_eq!(splits[1], laps[0] + laps[1]);
+    assert_eq!(splits[2], laps[0] + laps[1] + laps[2]);
+    let mut sorted = splits.clone();
+    sorted.sort();
+    assert_eq!(sorted, splits);
+    assert!(*splits.last().unwrap() <= sw.elapsed());
+
+    assert_eq!(sw.fastest_lap(), laps.iter().min().copied());
+    assert_eq!(sw.slowest_lap(), laps.iter().max().copied());
+    assert!(sw.fastest_lap().unwrap() >= Duration::from_millis(5));
+    assert!(sw.slowest_lap().unwrap() >= Duration::from_millis(25));
+
+    // 停止后再记录一圈，最后的累计耗时与总耗时相等
+    sw.stop();
+    sw.lap();
+    assert_eq!(*sw.split_times().last().unwrap(), sw.elapsed());
+
+    sw.reset();
+    assert!(sw.elapsed_laps().is_empty());
+    assert!(sw.split_times().is_empty());
+}
+
+#[cfg(feature = "stopwatch-serde")]
+#[test]
+fn test_stopwatch_serde_lap_count() {
+    let mut sw = StopWatch::start_new();
+    sw.lap();
+    sw.lap();
+    sw.stop();
+
+    let value = serde_json::to_value(&sw).unwrap();
+    assert_eq!(value["lap_count"], 2);
+
+    let restored: StopWatch = serde_json::from_value(value).unwrap();
+    assert_eq!(restored.elapsed(), sw.elapsed());
+    assert!(restored.elapsed_laps().is_empty());
+}
@@ -84,4 +84,865 @@ pub fn test_join(){
     assert_eq!(join(&[""], ","), "");
     assert_eq!(join(&["1","2","3"], ","), "1,2,3");
 
-}
\ No newline at end of file
+}
+
+#[test]
+pub fn test_split_at_nth() {
+    assert_eq!(split_at_nth("a:b:c:d", ":", 1), Some(("a", "b:c:d")));
+    assert_eq!(split_at_nth("a:b:c:d", ":", 2), Some(("a:b", "c:d")));
+    assert_eq!(split_at_nth("a:b:c:d", ":", 3), Some(("a:b:c", "d")));
+    assert_eq!(split_at_nth("a:b:c:d", ":", 4), None);
+    assert_eq!(split_at_nth("a:b:c:d", ":", 0), None);
+    assert_eq!(split_at_nth("abcd", ":", 1), None);
+    assert_eq!(split_at_nth("abcd", "", 1), None);
+    assert_eq!(split_at_nth("a::b::c", "::", 2), Some(("a::b", "c")));
+    assert_eq!(split_at_nth(":a:", ":", 2), Some((":a", "")));
+    assert_eq!(
+        split_at_nth("你好，世界，再见", "，", 2),
+        Some(("你好，世界", "再见"))
+    );
+}
+
+#[test]
+pub fn test_split_first_and_last() {
+    assert_eq!(split_first("a:b:c", ":"), Some(("a", "b:c")));
+    assert_eq!(split_last("a:b:c", ":"), Some(("a:b", "c")));
+    assert_eq!(split_first("abc", ":"), None);
+    assert_eq!(split_last("abc", ":"), None);
+    assert_eq!(split_first("abc", ""), None);
+    assert_eq!(split_last("abc", ""), None);
+    assert_eq!(split_first(":", ":"), Some(("", "")));
+    assert_eq!(split_last(":", ":"), Some(("", "")));
+}
+
+#[test]
+pub fn test_first_n_chars() {
+    assert_eq!(first_n_chars("hello", 0), "");
+    assert_eq!(first_n_chars("hello", 1), "h");
+    assert_eq!(first_n_chars("hello", 5), "hello");
+    assert_eq!(first_n_chars("hello", 100), "hello");
+    assert_eq!(first_n_chars("", 3), "");
+    assert_eq!(first_n_chars("你好世界", 3), "你好世");
+    assert_eq!(first_n_chars("a你b好", 2), "a你");
+    assert_eq!(first_n_chars("😀😃😄", 2), "😀😃");
+}
+
+#[test]
+pub fn test_last_n_chars() {
+    assert_eq!(last_n_chars("hello", 0), "");
+    assert_eq!(last_n_chars("hello", 1), "o");
+    assert_eq!(last_n_chars("hello", 5), "hello");
+    assert_eq!(last_n_chars("hello", 100), "hello");
+    assert_eq!(last_n_chars("", 3), "");
+    assert_eq!(last_n_chars("你好世界", 3), "好世界");
+    assert_eq!(last_n_chars("a你b好", 2), "b好");
+    assert_eq!(last_n_chars("😀😃😄", 2), "😃😄");
+}
+
+#[test]
+pub fn test_abbreviate_initials() {
+    assert_eq!(abbreviate_initials(""), "");
+    assert_eq!(abbreviate_initials("   "), "");
+    assert_eq!(abbreviate_initials("madonna"), "M");
+    assert_eq!(abbreviate_initials("John Doe"), "JD");
+    assert_eq!(abbreviate_initials("John William Smith"), "JWS");
+    assert_eq!(abbreviate_initials("  john   doe  "), "JD");
+    assert_eq!(abbreviate_initials("Ludwig van Beethoven"), "LVB");
+    assert_eq!(abbreviate_initials("Charles de Gaulle"), "CDG");
+    assert_eq!(abbreviate_initials("Émile Zola"), "ÉZ");
+    assert_eq!(abbreviate_initials("ştefan łukasz"), "ŞŁ");
+    assert_eq!(abbreviate_initials("张 三"), "张三");
+}
+
+#[test]
+pub fn test_abbreviate_middle_names() {
+    assert_eq!(abbreviate_middle_names(""), "");
+    assert_eq!(abbreviate_middle_names("Madonna"), "Madonna");
+    assert_eq!(abbreviate_middle_names("John  Doe"), "John Doe");
+    assert_eq!(
+        abbreviate_middle_names("John William Smith"),
+        "John W. Smith"
+    );
+    assert_eq!(
+        abbreviate_middle_names("George Herbert Walker Bush"),
+        "George H. W. Bush"
+    );
+    assert_eq!(
+        abbreviate_middle_names("Ludwig van Beethoven"),
+        "Ludwig v. Beethoven"
+    );
+    assert_eq!(
+        abbreviate_middle_names("Gabriel José García"),
+        "Gabriel J. García"
+    );
+    assert_eq!(
+        abbreviate_middle_names("Ángel Óscar Núñez"),
+        "Ángel Ó. Núñez"
+    );
+}
+
+#[test]
+pub fn test_compact() {
+    assert_eq!(compact(""), "");
+    assert_eq!(compact("   "), "");
+    assert_eq!(compact("abc"), "abc");
+    assert_eq!(compact(" a b c "), "abc");
+    assert_eq!(compact("a\tb\nc\r\nd"), "abcd");
+    assert_eq!(compact("1\u{00A0}2\u{3000}3"), "123");
+    assert_eq!(compact("你 好\t世界"), "你好世界");
+    // 零宽空格不是空白字符
+    assert_eq!(compact("a\u{200B}b"), "a\u{200B}b");
+}
+
+#[test]
+pub fn test_remove_chars() {
+    assert_eq!(remove_chars("", "abc"), "");
+    assert_eq!(remove_chars("abc", ""), "abc");
+    assert_eq!(remove_chars("(010) 1234-5678", "() -"), "01012345678");
+    assert_eq!(remove_chars("a\u{200B}b\u{200B}c", "\u{200B}"), "abc");
+    assert_eq!(remove_chars("a\u{00A0}b", "\u{00A0}"), "ab");
+    assert_eq!(remove_chars("你好，世界！", "，！"), "你好世界");
+    assert_eq!(remove_chars("aaaa", "a"), "");
+}
+
+#[test]
+pub fn test_repeat_with_separator() {
+    assert_eq!(repeat_with_separator("ab", 0, ","), "");
+    assert_eq!(repeat_with_separator("ab", 1, ","), "ab");
+    assert_eq!(repeat_with_separator("ab", 3, ""), "ababab");
+    assert_eq!(repeat_with_separator("?", 5, ", "), "?, ?, ?, ?, ?");
+    assert_eq!(repeat_with_separator("好", 3, "、"), "好、好、好");
+    assert_eq!(repeat_with_separator("", 3, "|"), "||");
+    assert_eq!(repeat_with_separator("x", 4, ","), ["x"; 4].join(","));
+}
+
+#[test]
+pub fn test_count_words() {
+    assert_eq!(count_words(""), 0);
+    assert_eq!(count_words(" \t\n "), 0);
+    assert_eq!(count_words("hello"), 1);
+    assert_eq!(count_words("hello world"), 2);
+    assert_eq!(count_words("hello    world"), 2);
+    assert_eq!(count_words("  hello world  "), 2);
+    assert_eq!(count_words("a\tb\nc\r\nd"), 4);
+}
+
+#[test]
+pub fn test_words() {
+    assert!(words("").is_empty());
+    assert!(words("   ").is_empty());
+    assert_eq!(words("hello"), vec!["hello"]);
+    assert_eq!(
+        words("  hello   big\tworld  "),
+        vec!["hello", "big", "world"]
+    );
+}
+
+#[test]
+pub fn test_unique_words() {
+    assert!(unique_words("").is_empty());
+    assert_eq!(unique_words("hello"), vec!["hello"]);
+    assert_eq!(unique_words("a b a c b"), vec!["a", "b", "c"]);
+    assert_eq!(
+        unique_words("Rust rust RUST is Is fun"),
+        vec!["Rust", "is", "fun"]
+    );
+    assert_eq!(unique_words("  one   two  One "), vec!["one", "two"]);
+}
+
+#[test]
+pub fn test_format_number_string() {
+    assert_eq!(format_number_string(0, ','), "0");
+    assert_eq!(format_number_string(999, ','), "999");
+    assert_eq!(format_number_string(1000, ','), "1,000");
+    assert_eq!(format_number_string(123456, ','), "123,456");
+    assert_eq!(format_number_string(1_000_000, ','), "1,000,000");
+    assert_eq!(format_number_string(-1, ','), "-1");
+    assert_eq!(format_number_string(-999, ','), "-999");
+    assert_eq!(format_number_string(-1000, ','), "-1,000");
+    assert_eq!(format_number_string(-1234567, '.'), "-1.234.567");
+    assert_eq!(format_number_string(1234567, ' '), "1 234 567");
+    assert_eq!(
+        format_number_string(i64::MAX, ','),
+        "9,223,372,036,854,775,807"
+    );
+    assert_eq!(
+        format_number_string(i64::MIN, ','),
+        "-9,223,372,036,854,775,808"
+    );
+}
+
+#[test]
+pub fn test_format_bytes() {
+    assert_eq!(format_bytes(0), "0 B");
+    assert_eq!(format_bytes(1), "1 B");
+    assert_eq!(format_bytes(1023), "1023 B");
+    assert_eq!(format_bytes(1024), "1.00 KB");
+    assert_eq!(format_bytes(1260), "1.23 KB");
+    assert_eq!(format_bytes(1024 * 1024 - 1), "1.00 MB");
+    assert_eq!(format_bytes(4_781_506), "4.56 MB");
+    assert_eq!(format_bytes(1_320_702_444), "1.23 GB");
+    assert_eq!(format_bytes(1024u64.pow(4)), "1.00 TB");
+    assert_eq!(format_bytes(1024u64.pow(5) * 3 / 2), "1.50 PB");
+    assert_eq!(format_bytes(1024u64.pow(6)), "1.00 EB");
+    assert_eq!(format_bytes(u64::MAX), "16.00 EB");
+}
+
+#[test]
+pub fn test_is_numeric() {
+    assert!(is_numeric("42"));
+    assert!(is_numeric("3.14"));
+    assert!(is_numeric("-1.5e10"));
+    assert!(is_numeric("+0.0"));
+    assert!(is_numeric("1E-3"));
+    assert!(is_numeric(" 42 "));
+    assert!(!is_numeric(""));
+    assert!(!is_numeric("   "));
+    assert!(!is_numeric("abc"));
+    assert!(!is_numeric("1.2.3"));
+    assert!(!is_numeric("1,000"));
+    assert!(!is_numeric("0x1A"));
+    assert!(!is_numeric("."));
+    assert!(!is_numeric("e5"));
+    assert!(!is_numeric("1e"));
+    assert!(!is_numeric("--1"));
+
+    // 以下写法可能出乎意料，但都能被 f64 解析
+    assert!(is_numeric(".5"));
+    assert!(is_numeric("5."));
+    assert!(is_numeric("inf"));
+    assert!(is_numeric("-Infinity"));
+    assert!(is_numeric("NaN"));
+    assert!(is_numeric("1e400"));
+}
+
+#[test]
+pub fn test_is_integer_str() {
+    assert!(is_integer_str("0"));
+    assert!(is_integer_str("42"));
+    assert!(is_integer_str("-42"));
+    assert!(is_integer_str("+42"));
+    assert!(is_integer_str(" 7 "));
+    assert!(is_integer_str("123456789012345678901234567890"));
+    assert!(!is_integer_str(""));
+    assert!(!is_integer_str("-"));
+    assert!(!is_integer_str("+-1"));
+    assert!(!is_integer_str("4.2"));
+    assert!(!is_integer_str("1e3"));
+    assert!(!is_integer_str("1 000"));
+    assert!(!is_integer_str("inf"));
+    assert!(!is_integer_str("١٢٣"));
+}
+
+#[test]
+pub fn test_utf8_bytes_round_trip() {
+    for s in [
+        "",
+        "hello",
+        "你好，世界",
+        "こんにちは",
+        "😀 emoji",
+        "mixed 混合 ü",
+    ] {
+        let bytes = to_utf8_bytes(s);
+        assert_eq!(bytes, s.as_bytes());
+        assert!(is_valid_utf8(&bytes));
+        assert_eq!(from_utf8_bytes(&bytes).unwrap(), s);
+    }
+    assert_eq!(to_utf8_bytes("中"), vec![0xE4, 0xB8, 0xAD]);
+}
+
+#[test]
+pub fn test_invalid_utf8_bytes() {
+    let invalid: [&[u8]; 5] = [
+        &[0xFF],
+        &[0xC3, 0x28],
+        &[0xE4, 0xB8],
+        &[0xED, 0xA0, 0x80],
+        &[0x68, 0x69, 0x80],
+    ];
+    for bytes in invalid {
+        assert!(!is_valid_utf8(bytes));
+        let error = from_utf8_bytes(bytes).unwrap_err();
+        assert!(error.starts_with("Invalid UTF-8 sequence"));
+    }
+    assert!(from_utf8_bytes(&[0x68, 0x69, 0x80])
+        .unwrap_err()
+        .contains("index 2"));
+}
+
+#[test]
+pub fn test_wrap_in_quotes() {
+    assert_eq!(wrap_in_quotes("", '\''), "''");
+    assert_eq!(wrap_in_quotes("abc", '\''), "'abc'");
+    assert_eq!(wrap_in_quotes("abc", '"'), "\"abc\"");
+    assert_eq!(wrap_in_quotes("O'Reilly", '\''), "'O''Reilly'");
+    assert_eq!(wrap_in_quotes("say \"hi\"", '"'), "\"say \"\"hi\"\"\"");
+    assert_eq!(wrap_in_quotes("it's \"ok\"", '\''), "'it''s \"ok\"'");
+    assert_eq!(wrap_in_quotes("'", '\''), "''''");
+    assert_eq!(wrap_in_quotes("中文", '「'), "「中文「");
+}
+
+#[test]
+pub fn test_unwrap_quotes() {
+    assert_eq!(unwrap_quotes("''", '\''), Some(String::new()));
+    assert_eq!(unwrap_quotes("'abc'", '\''), Some(String::from("abc")));
+    assert_eq!(unwrap_quotes("\"abc\"", '"'), Some(String::from("abc")));
+    assert_eq!(
+        unwrap_quotes("'O''Reilly'", '\''),
+        Some(String::from("O'Reilly"))
+    );
+    assert_eq!(unwrap_quotes("''''", '\''), Some(String::from("'")));
+
+    assert_eq!(unwrap_quotes("", '\''), None);
+    assert_eq!(unwrap_quotes("'", '\''), None);
+    assert_eq!(unwrap_quotes("abc", '\''), None);
+    assert_eq!(unwrap_quotes("'abc", '\''), None);
+    assert_eq!(unwrap_quotes("abc'", '\''), None);
+    assert_eq!(unwrap_quotes("\"abc\"", '\''), None);
+    assert_eq!(unwrap_quotes("'a'b'", '\''), None);
+    assert_eq!(unwrap_quotes("'''", '\''), None);
+
+    for s in ["", "plain", "O'Reilly", "''", "a'b''c'''"] {
+        assert_eq!(
+            unwrap_quotes(&wrap_in_quotes(s, '\''), '\''),
+            Some(s.to_string())
+        );
+    }
+}
+
+#[test]
+pub fn test_extract_urls() {
+    assert!(extract_urls("").is_empty());
+    assert!(extract_urls("no links here, just http and https words").is_empty());
+    assert!(extract_urls("broken http:// and https://").is_empty());
+
+    assert_eq!(
+        extract_urls("see https://example.com and http://foo.org/path?q=1#top"),
+        vec!["https://example.com", "http://foo.org/path?q=1#top"]
+    );
+    assert_eq!(
+        extract_urls("https://a.com\nhttps://b.com\thttps://c.com"),
+        vec!["https://a.com", "https://b.com", "https://c.com"]
+    );
+    // 与标点相邻
+    assert_eq!(
+        extract_urls("Visit https://example.com. Or (https://rust-lang.org), ok?"),
+        vec!["https://example.com", "https://rust-lang.org"]
+    );
+    assert_eq!(
+        extract_urls("\"https://quoted.com\" <https://angle.com>!"),
+        vec!["https://quoted.com", "https://angle.com"]
+    );
+    // 链接内部配对的括号会被保留
+    assert_eq!(
+        extract_urls("(https://en.wikipedia.org/wiki/Rust_(programming_language))"),
+        vec!["https://en.wikipedia.org/wiki/Rust_(programming_language)"]
+    );
+    assert!(extract_urls("xhttps://example.com").is_empty());
+    assert_eq!(
+        extract_urls("中文https://example.com/a 后面"),
+        vec!["https://example.com/a"]
+    );
+    assert_eq!(
+        extract_urls("链接：https://example.com/路径 后面"),
+        vec!["https://example.com/路径"]
+    );
+}
+
+#[test]
+pub fn test_extract_emails() {
+    assert!(extract_emails("").is_empty());
+    assert!(extract_emails("no emails @ here, user@ or @domain.com").is_empty());
+    assert!(extract_emails("user@localhost user@domain.c user@domain.123").is_empty());
+
+    assert_eq!(
+        extract_emails("mail a@b.co, first.last+tag@sub.example.org; x_y%z@ex-ample.io."),
+        vec![
+            "a@b.co",
+            "first.last+tag@sub.example.org",
+            "x_y%z@ex-ample.io"
+        ]
+    );
+    assert_eq!(
+        extract_emails("<admin@example.com>(support@example.net)"),
+        vec!["admin@example.com", "support@example.net"]
+    );
+    assert_eq!(
+        extract_emails("联系邮箱：admin@example.com。"),
+        vec!["admin@example.com"]
+    );
+    assert_eq!(
+        extract_emails(".user@example.com"),
+        vec!["user@example.com"]
+    );
+    assert!(extract_emails("user@exa..mple.com").is_empty());
+}
+
+#[cfg(feature = "str-unicode")]
+#[test]
+pub fn test_normalize_unicode() {
+    let composed = "Caf\u{00E9} \u{00C5}ngstr\u{00F6}m";
+    let decomposed = "Cafe\u{0301} A\u{030A}ngstro\u{0308}m";
+    assert_ne!(composed.as_bytes(), decomposed.as_bytes());
+
+    assert_eq!(
+        normalize_unicode(decomposed, NormalizationForm::NFC),
+        composed
+    );
+    assert_eq!(
+        normalize_unicode(composed, NormalizationForm::NFD),
+        decomposed
+    );
+    assert_eq!(
+        normalize_unicode(composed, NormalizationForm::NFC).as_bytes(),
+        normalize_unicode(decomposed, NormalizationForm::NFC).as_bytes()
+    );
+
+    // 兼容分解会把全角字符、连字等转换为普通形式
+    assert_eq!(
+        normalize_unicode("ｈｅｌｌｏ１２３", NormalizationForm::NFKC),
+        "hello123"
+    );
+    assert_eq!(
+        normalize_unicode("\u{FB01}le", NormalizationForm::NFKD),
+        "file"
+    );
+    assert_eq!(
+        normalize_unicode("\u{FB01}le", NormalizationForm::NFC),
+        "\u{FB01}le"
+    );
+
+    // 已规范化的字符串保持不变
+    for s in ["", "hello", "你好世界", composed] {
+        assert_eq!(normalize_unicode(s, NormalizationForm::NFC), s);
+    }
+    assert_eq!(
+        normalize_unicode(decomposed, NormalizationForm::NFD),
+        decomposed
+    );
+}
+
+#[cfg(feature = "str-unicode")]
+#[test]
+pub fn test_is_normalized() {
+    let composed = "Caf\u{00E9}";
+    let decomposed = "Cafe\u{0301}";
+
+    assert!(is_normalized("", NormalizationForm::NFC));
+    assert!(is_normalized("ascii only", NormalizationForm::NFKD));
+    assert!(is_normalized(composed, NormalizationForm::NFC));
+    assert!(!is_normalized(composed, NormalizationForm::NFD));
+    assert!(is_normalized(decomposed, NormalizationForm::NFD));
+    assert!(!is_normalized(decomposed, NormalizationForm::NFC));
+    assert!(is_normalized("\u{FB01}", NormalizationForm::NFC));
+    assert!(!is_normalized("\u{FB01}", NormalizationForm::NFKC));
+
+    for form in [
+        NormalizationForm::NFC,
+        NormalizationForm::NFD,
+        NormalizationForm::NFKC,
+        NormalizationForm::NFKD,
+    ] {
+        assert!(is_normalized(&normalize_unicode(decomposed, form), form));
+    }
+}
+
+#[test]
+pub fn test_sentence_case() {
+    assert_eq!(sentence_case(""), "");
+    assert_eq!(sentence_case("hello"), "Hello");
+    assert_eq!(sentence_case("HELLO WORLD"), "Hello world");
+    assert_eq!(
+        sentence_case("hello World FROM Rust"),
+        "Hello world from rust"
+    );
+
+    // 开头的空白字符保持不变
+    assert_eq!(sentence_case("   hello"), "   Hello");
+    assert_eq!(sentence_case("\t\nHELLO"), "\t\nHello");
+    assert_eq!(sentence_case("   "), "   ");
+
+    // 开头的非字母字符保持不变，找到第一个字母再大写
+    assert_eq!(sentence_case("123 GO"), "123 Go");
+    assert_eq!(sentence_case("\"QUOTED\" TEXT"), "\"Quoted\" text");
+    assert_eq!(sentence_case("-- note"), "-- Note");
+    assert_eq!(sentence_case("42!?"), "42!?");
+
+    // 多个句子时只改变第一个字母
+    assert_eq!(
+        sentence_case("first. Second. THIRD."),
+        "First. second. third."
+    );
+
+    // Unicode 字符
+    assert_eq!(sentence_case("éCOLE"), "École");
+    assert_eq!(sentence_case("你好 WORLD"), "你好 world");
+}
+
+#[test]
+pub fn test_is_all_ascii() {
+    assert!(is_all_ascii("hello, world!\n\u{7f}"));
+    assert!(!is_all_ascii("你好世界"));
+    assert!(!is_all_ascii("abc你好"));
+    assert!(!is_all_ascii("é"));
+
+    // 空字符串视为纯 ASCII，与 validation::is_ascii_str 不同
+    assert!(is_all_ascii(""));
+    assert!(!is_ascii_str(""));
+    for s in ["abc", "你好", "abc\u{80}"] {
+        assert_eq!(is_all_ascii(s), is_ascii_str(s));
+        assert_eq!(is_all_ascii(s), find_first_non_ascii(s).is_none());
+    }
+}
+
+#[test]
+pub fn test_find_first_non_ascii() {
+    // 纯 ASCII
+    assert_eq!(find_first_non_ascii(""), None);
+    assert_eq!(find_first_non_ascii("hello, world!\n\u{7f}"), None);
+
+    // 纯中文，非 ASCII 字符位于开头
+    assert_eq!(find_first_non_ascii("你好世界"), Some(0));
+    assert_eq!(find_first_non_ascii("😀abc"), Some(0));
+
+    // 混合字符串，返回字节偏移量
+    assert_eq!(find_first_non_ascii("abc你好"), Some(3));
+    assert_eq!(find_first_non_ascii("hello 世界 again"), Some(6));
+    assert_eq!(find_first_non_ascii("price: 10€"), Some(9));
+    assert_eq!(find_first_non_ascii("abc\u{80}"), Some(3));
+
+    let s = "ascii then ünicode";
+    let index = find_first_non_ascii(s).unwrap();
+    assert_eq!(&s[..index], "ascii then ");
+    assert!(s[index..].starts_with('ü'));
+}
+
+#[test]
+pub fn test_to_ascii_lossy() {
+    assert_eq!(to_ascii_lossy(""), "");
+    assert_eq!(to_ascii_lossy("plain ascii 123"), "plain ascii 123");
+    assert_eq!(to_ascii_lossy("你好世界"), "????");
+    assert_eq!(to_ascii_lossy("é at start"), "? at start");
+    assert_eq!(to_ascii_lossy("mid 中 dle"), "mid ? dle");
+    assert_eq!(to_ascii_lossy("emoji 😀!"), "emoji ?!");
+    assert!(to_ascii_lossy("naïve café 日本").is_ascii());
+}
+
+#[test]
+pub fn test_strip_non_ascii() {
+    assert_eq!(strip_non_ascii(""), "");
+    assert_eq!(strip_non_ascii("plain ascii 123"), "plain ascii 123");
+    assert_eq!(strip_non_ascii("你好世界"), "");
+    assert_eq!(strip_non_ascii("é at start"), " at start");
+    assert_eq!(strip_non_ascii("mid中dle"), "middle");
+    assert_eq!(strip_non_ascii("emoji 😀!"), "emoji !");
+}
+
+#[test]
+pub fn test_char_at() {
+    // ASCII
+    assert_eq!(char_at("hello", 0), Some('h'));
+    assert_eq!(char_at("hello", 4), Some('o'));
+    assert_eq!(char_at("hello", 5), None);
+
+    // 中文
+    assert_eq!(char_at("你好世界", 0), Some('你'));
+    assert_eq!(char_at("你好世界", 3), Some('界'));
+    assert_eq!(char_at("你好世界", 4), None);
+    assert_eq!(char_at("a你b", 2), Some('b'));
+
+    // emoji，多码点序列按 char 逐个计数
+    assert_eq!(char_at("😀x", 0), Some('😀'));
+    assert_eq!(char_at("😀x", 1), Some('x'));
+    let family = "👨\u{200D}👩\u{200D}👧";
+    assert_eq!(char_at(family, 0), Some('👨'));
+    assert_eq!(char_at(family, 1), Some('\u{200D}'));
+    assert_eq!(char_at(family, 4), Some('👧'));
+    assert_eq!(char_at(family, 5), None);
+    assert_eq!(char_at("👍🏽", 1), Some('\u{1F3FD}'));
+
+    // 越界
+    assert_eq!(char_at("", 0), None);
+    assert_eq!(char_at("abc", usize::MAX), None);
+}
+
+#[test]
+pub fn test_char_at_or() {
+    assert_eq!(char_at_or("hello", 1, '?'), 'e');
+    assert_eq!(char_at_or("你好", 1, '?'), '好');
+    assert_eq!(char_at_or("你好", 2, '?'), '?');
+    assert_eq!(char_at_or("", 0, '-'), '-');
+    assert_eq!(char_at_or("😀", 0, '?'), '😀');
+}
+
+#[test]
+pub fn test_insert_at_char() {
+    // ASCII：开头、中间、末尾
+    assert_eq!(insert_at_char("world", 0, "hello ").unwrap(), "hello world");
+    assert_eq!(insert_at_char("helo", 3, "l").unwrap(), "hello");
+    assert_eq!(insert_at_char("hello", 5, "!").unwrap(), "hello!");
+    assert_eq!(insert_at_char("", 0, "abc").unwrap(), "abc");
+    assert_eq!(insert_at_char("abc", 1, "").unwrap(), "abc");
+
+    // 多字节字符
+    assert_eq!(insert_at_char("你好", 0, "嗨，").unwrap(), "嗨，你好");
+    assert_eq!(insert_at_char("你好", 1, "们").unwrap(), "你们好");
+    assert_eq!(insert_at_char("你好", 2, "！").unwrap(), "你好！");
+    assert_eq!(insert_at_char("a😀b", 2, "-").unwrap(), "a😀-b");
+    assert_eq!(insert_at_char("café!", 4, "?").unwrap(), "café?!");
+
+    // 越界
+    assert_eq!(insert_at_char("hello", 6, "!"), None);
+    assert_eq!(insert_at_char("你好", 3, "!"), None);
+    assert_eq!(insert_at_char("", 1, "x"), None);
+}
+
+#[test]
+pub fn test_delete_char_range() {
+    // ASCII：开头、中间、末尾
+    assert_eq!(delete_char_range("hello world", 0, 6).unwrap(), "world");
+    assert_eq!(delete_char_range("hello world", 2, 4).unwrap(), "heo world");
+    assert_eq!(delete_char_range("hello world", 5, 11).unwrap(), "hello");
+    assert_eq!(delete_char_range("hello", 0, 5).unwrap(), "");
+    assert_eq!(delete_char_range("hello", 2, 2).unwrap(), "hello");
+    assert_eq!(delete_char_range("hello", 5, 5).unwrap(), "hello");
+    assert_eq!(delete_char_range("", 0, 0).unwrap(), "");
+
+    // 多字节字符
+    assert_eq!(delete_char_range("你好世界", 0, 2).unwrap(), "世界");
+    assert_eq!(delete_char_range("你好世界", 1, 3).unwrap(), "你界");
+    assert_eq!(delete_char_range("a😀b", 1, 2).unwrap(), "ab");
+
+    // 越界与无效范围
+    assert_eq!(delete_char_range("hello", 0, 6), None);
+    assert_eq!(delete_char_range("你好", 1, 3), None);
+    assert_eq!(delete_char_range("hello", 3, 2), None);
+}
+
+#[test]
+pub fn test_contains_any_and_all() {
+    // 空的模式列表
+    assert!(!contains_any("hello", &[]));
+    assert!(contains_all("hello", &[]));
+    assert!(!contains_any("", &[]));
+    assert!(contains_all("", &[]));
+
+    assert!(contains_any("hello world", &["xyz", "world"]));
+    assert!(!contains_any("hello world", &["xyz", "abc"]));
+    assert!(contains_all("hello world", &["world", "hello", "o w"]));
+    assert!(!contains_all("hello world", &["hello", "xyz"]));
+
+    // 与单个模式的 contains 行为一致，空串总是匹配
+    assert!(contains_any("hello", &[""]));
+    assert!(contains_all("", &[""]));
+    assert!(!contains_any("", &["a"]));
+
+    // 重叠的模式
+    assert!(contains_all("abc", &["ab", "bc", "abc"]));
+    assert!(contains_all("aaa", &["aa", "aa"]));
+
+    // 模式比字符串长
+    assert!(!contains_any("hi", &["hello", "hi there"]));
+    assert!(!contains_all("hi", &["hi", "hi there"]));
+
+    assert!(contains_any("你好世界", &["世界"]));
+}
+
+#[test]
+pub fn test_starts_and_ends_with_any() {
+    assert!(!starts_with_any("hello", &[]));
+    assert!(!ends_with_any("hello", &[]));
+
+    assert!(starts_with_any("hello", &["x", "he"]));
+    assert!(!starts_with_any("hello", &["x", "lo"]));
+    assert!(ends_with_any("hello", &["x", "lo"]));
+    assert!(!ends_with_any("hello", &["x", "he"]));
+
+    assert!(starts_with_any("hello", &["", "x"]));
+    assert!(ends_with_any("", &[""]));
+    assert!(starts_with_any("hello", &["hello"]));
+    assert!(ends_with_any("hello", &["hello"]));
+
+    // 前缀或后缀比字符串长
+    assert!(!starts_with_any("he", &["hello"]));
+    assert!(!ends_with_any("lo", &["hello"]));
+
+    assert!(starts_with_any("你好世界", &["你好"]));
+    assert!(ends_with_any("你好世界", &["世界"]));
+
+    for (s, prefix) in [("abc", "a"), ("abc", "b"), ("", "a"), ("a", "")] {
+        assert_eq!(starts_with_any(s, &[prefix]), starts_with(s, prefix));
+        assert_eq!(ends_with_any(s, &[prefix]), ends_with(s, prefix));
+        assert_eq!(contains_any(s, &[prefix]), contains(s, prefix));
+        assert_eq!(contains_all(s, &[prefix]), contains(s, prefix));
+    }
+}
+
+#[test]
+pub fn test_html_escape_and_unescape() {
+    assert_eq!(html_escape("<>&\"'"), "&lt;&gt;&amp;&quot;&#39;");
+    assert_eq!(html_escape(""), "");
+    assert_eq!(html_escape("plain 文本"), "plain 文本");
+    assert_eq!(html_escape("&amp;"), "&amp;amp;");
+
+    assert_eq!(html_unescape("&lt;&gt;&amp;&quot;&#39;"), "<>&\"'");
+    assert_eq!(html_unescape("&apos;&#x27;&#X27;"), "'''");
+    assert_eq!(html_unescape("&amp;amp;"), "&amp;");
+    assert_eq!(html_unescape(""), "");
+
+    // 无法识别的实体原样保留
+    assert_eq!(html_unescape("&nbsp;"), "&nbsp;");
+    assert_eq!(html_unescape("a & b"), "a & b");
+    assert_eq!(html_unescape("&#xD800;&#abc;&;"), "&#xD800;&#abc;&;");
+    assert_eq!(html_unescape("&lt"), "&lt");
+
+    for s in [
+        "<script>alert('x & y')</script>",
+        "\"quoted\"",
+        "中文 & English",
+    ] {
+        assert_eq!(html_unescape(&html_escape(s)), s);
+    }
+}
+
+#[test]
+pub fn test_wrap_text_html() {
+    assert_eq!(
+        wrap_text_html("<>&\"'", "b", &[]),
+        "<b>&lt;&gt;&amp;&quot;&#39;</b>"
+    );
+    assert_eq!(wrap_text_html("", "div", &[]), "<div></div>");
+    assert_eq!(
+        wrap_text_html("", "div", &[("id", "main")]),
+        "<div id=\"main\"></div>"
+    );
+    assert_eq!(
+        wrap_text_html(
+            "link",
+            "a",
+            &[("href", "/search?q=a&b=\"c\""), ("title", "it's <new>")]
+        ),
+        "<a href=\"/search?q=a&amp;b=&quot;c&quot;\" title=\"it&#39;s &lt;new&gt;\">link</a>"
+    );
+    assert_eq!(
+        wrap_text_html("x", "input", &[("data-value", "")]),
+        "<input data-value=\"\">x</input>"
+    );
+}
+
+#[cfg(feature = "str-json")]
+#[test]
+pub fn test_is_valid_json() {
+    assert!(is_valid_json("{}"));
+    assert!(is_valid_json("[]"));
+    assert!(is_valid_json(r#"{"a": {"b": [1, 2.5, true, null, "x"]}}"#));
+    assert!(is_valid_json("  42  "));
+    assert!(is_valid_json("\"text\""));
+    assert!(!is_valid_json(""));
+    assert!(!is_valid_json("{"));
+    assert!(!is_valid_json("{'a': 1}"));
+    assert!(!is_valid_json("[1, 2,]"));
+    assert!(!is_valid_json("{} {}"));
+}
+
+#[cfg(feature = "str-json")]
+#[test]
+pub fn test_format_and_minify_json() {
+    let source = r#"{"name":"huturs","tags":["a","b"],"meta":{"stars":5,"empty":{}}}"#;
+    let pretty = format_json(source).unwrap();
+    assert!(pretty.contains("\n  \"name\": \"huturs\""));
+    assert!(pretty.contains("\n    \"a\",\n"));
+
+    // pretty -> minify -> pretty 往返一致
+    let minified = minify_json(&pretty).unwrap();
+    assert!(!minified.contains(char::is_whitespace));
+    assert_eq!(format_json(&minified).unwrap(), pretty);
+    assert_eq!(
+        minify_json(&format_json(&minified).unwrap()).unwrap(),
+        minified
+    );
+
+    assert_eq!(format_json("[]").unwrap(), "[]");
+    assert_eq!(minify_json(" [ 1 , 2 ] ").unwrap(), "[1,2]");
+    // 字符串中的空白不受影响
+    assert_eq!(
+        minify_json("{ \"a b\" : \" c \" }").unwrap(),
+        "{\"a b\":\" c \"}"
+    );
+
+    // 非法 JSON 返回包含位置信息的错误
+    let error = format_json("{\"a\": }").unwrap_err();
+    assert!(error.contains("line 1"));
+    assert!(minify_json("[1, 2").is_err());
+    assert!(format_json("").is_err());
+}
+
+#[test]
+pub fn test_repeat_char_and_of_char() {
+    assert_eq!(repeat_char('a', 3), "aaa");
+    assert_eq!(repeat_char('a', 0), "");
+    assert_eq!(repeat_char('a', 1), of_char('a'));
+    assert_eq!(repeat_char('a', 1000).len(), 1000);
+
+    // 多字节字符：字符数等于 count，字节数按 UTF-8 编码长度计算
+    let s = repeat_char('é', 4);
+    assert_eq!(s.chars().count(), 4);
+    assert_eq!(s.len(), 8);
+    let s = repeat_char('中', 5);
+    assert_eq!(s.chars().count(), 5);
+    assert_eq!(s.len(), 15);
+    let s = repeat_char('🦀', 3);
+    assert_eq!(s.chars().count(), 3);
+    assert_eq!(s.len(), 12);
+
+    assert_eq!(of_char('x'), "x");
+    assert_eq!(of_char('中').len(), 3);
+    assert_eq!(of_char('🦀').len(), 4);
+}
+
+#[test]
+pub fn test_separator_and_horizontal_rule() {
+    assert_eq!(separator(0), "");
+    assert_eq!(separator(10), "----------");
+    assert_eq!(separator(80).len(), 80);
+
+    assert_eq!(horizontal_rule(0, '='), "");
+    assert_eq!(horizontal_rule(5, '='), "=====");
+    assert_eq!(horizontal_rule(5, '-'), separator(5));
+    let rule = horizontal_rule(10, '═');
+    assert_eq!(rule.chars().count(), 10);
+    assert_eq!(rule.len(), 30);
+}
+
+#[test]
+pub fn test_count_char_occurrences() {
+    assert_eq!(count_char_occurrences("", 'a'), 0);
+    assert_eq!(count_char_occurrences("hello", 'z'), 0);
+    assert_eq!(count_char_occurrences("hello", 'h'), 1);
+    assert_eq!(count_char_occurrences("hello", 'l'), 2);
+    assert_eq!(count_char_occurrences("Hello", 'h'), 0);
+
+    // 多字节字符按标量值匹配
+    assert_eq!(count_char_occurrences("中文中文中", '中'), 3);
+    assert_eq!(count_char_occurrences("🦀 and 🦀", '🦀'), 2);
+    assert_eq!(count_char_occurrences("é", 'e'), 0);
+    // "中"（E4 B8 AD）与 "串"（E4 B8 B2）共享前两个字节，不应互相匹配
+    assert_eq!(count_char_occurrences("串串中", '中'), 1);
+    // 组合字符序列中的基础字符单独计数
+    assert_eq!(count_char_occurrences("e\u{0301}e", 'e'), 2);
+}
+
+#[test]
+pub fn test_remove_char_and_keep_only_chars() {
+    assert_eq!(remove_char("", 'a'), "");
+    assert_eq!(remove_char("banana", 'a'), "bnn");
+    assert_eq!(remove_char("banana", 'z'), "banana");
+    assert_eq!(remove_char("🦀rust🦀", '🦀'), "rust");
+
+    assert_eq!(keep_only_chars("", "abc"), "");
+    assert_eq!(keep_only_chars("a1b2c3", "0123456789"), "123");
+    assert_eq!(keep_only_chars("abc", ""), "");
+    assert_eq!(keep_only_chars("中文English", "中文"), "中文");
+    assert_eq!(keep_only_chars("banana", "an"), "anana");
+}
@@ -0,0 +1,162 @@
+use huturs_core::timestamp;
+
+#[test]
+pub fn test_is_within_range() {
+    assert!(timestamp::is_within_range(1500, 1000, 2000));
+    assert!(timestamp::is_within_range(1000, 1000, 2000));
+    assert!(timestamp::is_within_range(2000, 1000, 2000));
+    assert!(!timestamp::is_within_range(999, 1000, 2000));
+    assert!(!timestamp::is_within_range(2001, 1000, 2000));
+
+    // 单点范围
+    assert!(timestamp::is_within_range(1000, 1000, 1000));
+    assert!(!timestamp::is_within_range(1001, 1000, 1000));
+
+    // 起点大于终点的范围为空
+    assert!(!timestamp::is_within_range(1500, 2000, 1000));
+    assert!(!timestamp::is_within_range(2000, 2000, 1000));
+    assert!(!timestamp::is_within_range(1000, 2000, 1000));
+}
+
+#[test]
+pub fn test_clamp_to_range() {
+    assert_eq!(timestamp::clamp_to_range(0, 1000, 2000), 1000);
+    assert_eq!(timestamp::clamp_to_range(1000, 1000, 2000), 1000);
+    assert_eq!(timestamp::clamp_to_range(1234, 1000, 2000), 1234);
+    assert_eq!(timestamp::clamp_to_range(2000, 1000, 2000), 2000);
+    assert_eq!(timestamp::clamp_to_range(u64::MAX, 1000, 2000), 2000);
+    assert_eq!(timestamp::clamp_to_range(5, 7, 7), 7);
+
+    // 无效范围总是返回起点
+    assert_eq!(timestamp::clamp_to_range(0, 2000, 1000), 2000);
+    assert_eq!(timestamp::clamp_to_range(1500, 2000, 1000), 2000);
+    assert_eq!(timestamp::clamp_to_range(3000, 2000, 1000), 2000);
+
+    for ts in [0, 999, 1000, 1500, 2000, 2001] {
+        let clamped = timestamp::clamp_to_range(ts, 1000, 2000);
+        assert!(timestamp::is_within_range(clamped, 1000, 2000));
+    }
+}
+
+#[test]
+pub fn test_ranges_overlap() {
+    // 部分重叠与包含
+    assert!(timestamp::ranges_overlap(1000, 2000, 1500, 2500));
+    assert!(timestamp::ranges_overlap(1500, 2500, 1000, 2000));
+    assert!(timestamp::ranges_overlap(1000, 3000, 1500, 2000));
+    assert!(timestamp::ranges_overlap(1500, 2000, 1000, 3000));
+
+    // 端点相接也视为重叠
+    assert!(timestamp::ranges_overlap(1000, 2000, 2000, 3000));
+    assert!(timestamp::ranges_overlap(2000, 3000, 1000, 2000));
+    assert!(timestamp::ranges_overlap(1000, 1000, 1000, 1000));
+
+    // 不相交
+    assert!(!timestamp::ranges_overlap(1000, 2000, 2001, 3000));
+    assert!(!timestamp::ranges_overlap(2001, 3000, 1000, 2000));
+
+    // 无效范围不与任何范围重叠
+    assert!(!timestamp::ranges_overlap(2000, 1000, 0, 5000));
+    assert!(!timestamp::ranges_overlap(0, 5000, 2000, 1000));
+    assert!(!timestamp::ranges_overlap(2000, 1000, 2000, 1000));
+}
+
+#[test]
+pub fn test_format_timestamp_as_datetime() {
+    // ISO 8601
+    assert_eq!(
+        timestamp::format_timestamp_as_datetime(0, "%+").unwrap(),
+        "1970-01-01T00:00:00+00:00"
+    );
+    assert_eq!(
+        timestamp::format_timestamp_as_datetime(0, "%Y-%m-%dT%H:%M:%SZ").unwrap(),
+        "1970-01-01T00:00:00Z"
+    );
+    assert_eq!(
+        timestamp::format_timestamp_as_datetime(1_700_000_000, "%+").unwrap(),
+        "2023-11-14T22:13:20+00:00"
+    );
+    assert_eq!(
+        timestamp::format_timestamp_as_datetime(1_700_000_000, "%Y-%m-%d %H:%M:%S").unwrap(),
+        "2023-11-14 22:13:20"
+    );
+    assert_eq!(
+        timestamp::format_timestamp_as_datetime(951_782_400, "%Y/%m/%d %A").unwrap(),
+        "2000/02/29 Tuesday"
+    );
+
+    // 超出范围的时间戳与无效的格式字符串
+    assert_eq!(
+        timestamp::format_timestamp_as_datetime(u64::MAX, "%Y"),
+        None
+    );
+    assert_eq!(
+        timestamp::format_timestamp_as_datetime(i64::MAX as u64, "%Y"),
+        None
+    );
+    assert_eq!(timestamp::format_timestamp_as_datetime(0, "%Q"), None);
+}
+
+#[test]
+#[allow(deprecated)]
+pub fn test_format_timestamp() {
+    assert_eq!(timestamp::format_timestamp(1234567890), "1234567890");
+    assert_eq!(timestamp::format_timestamp(0), "0");
+}
+
+#[test]
+pub fn test_now_aliases() {
+    let secs_before = timestamp::current_timestamp();
+    let millis = timestamp::now_millis();
+    let nanos = timestamp::now_nanos();
+    let secs_after = timestamp::now_secs();
+
+    assert!(secs_before <= millis / 1000 && millis / 1000 <= secs_after);
+    assert!(u128::from(millis) <= nanos / 1_000_000);
+    assert!(nanos / 1_000_000_000 <= u128::from(secs_after));
+    assert!(u128::from(timestamp::now_millis()) <= timestamp::current_timestamp_millis());
+
+    // 根模块同样可以直接调用
+    assert!(huturs_core::now_secs() >= secs_before);
+    assert!(huturs_core::now_millis() >= millis);
+    assert!(huturs_core::now_nanos() >= nanos);
+}
+
+#[test]
+pub fn test_timestamp_calendar_decomposition() {
+    // 2024-01-15 00:00:00 UTC，周一
+    let ts = 1_705_276_800;
+    assert_eq!(timestamp::timestamp_to_year(ts), 2024);
+    assert_eq!(timestamp::timestamp_to_month(ts), 1);
+    assert_eq!(timestamp::timestamp_to_day(ts), 15);
+    assert_eq!(timestamp::timestamp_to_day_of_year(ts), 15);
+    assert_eq!(timestamp::timestamp_to_day_of_week(ts), 0);
+    assert_eq!(timestamp::timestamp_to_week_number(ts), 3);
+
+    // 同一天的最后一秒
+    let end_of_day = ts + 86_399;
+    assert_eq!(timestamp::timestamp_to_day(end_of_day), 15);
+    assert_eq!(timestamp::timestamp_to_day_of_week(end_of_day), 0);
+    assert_eq!(timestamp::timestamp_to_day_of_week(end_of_day + 1), 1);
+
+    // Unix 纪元：1970-01-01，周四，ISO 第 1 周
+    assert_eq!(timestamp::timestamp_to_year(0), 1970);
+    assert_eq!(timestamp::timestamp_to_month(0), 1);
+    assert_eq!(timestamp::timestamp_to_day(0), 1);
+    assert_eq!(timestamp::timestamp_to_day_of_year(0), 1);
+    assert_eq!(timestamp::timestamp_to_day_of_week(0), 3);
+    assert_eq!(timestamp::timestamp_to_week_number(0), 1);
+
+    // 2024-12-30 属于 2025 年的第 1 周；2024-12-31 是闰年的第 366 天
+    assert_eq!(timestamp::timestamp_to_week_number(1_735_516_800), 1);
+    assert_eq!(timestamp::timestamp_to_day_of_year(1_735_603_200), 366);
+    assert_eq!(timestamp::timestamp_to_month(1_735_603_200), 12);
+    // 2020-12-31 属于 2020 年的第 53 周
+    assert_eq!(timestamp::timestamp_to_week_number(1_609_372_800), 53);
+}
+
+#[test]
+#[should_panic(expected = "out of the representable date range")]
+pub fn test_timestamp_calendar_decomposition_out_of_range() {
+    timestamp::timestamp_to_year(u64::MAX);
+}
@@ -19,6 +19,7 @@ pub fn test_hex_decode() {
 }
 
 #[test]
+#[allow(deprecated)]
 pub fn test_page_transToStartEnd() {
     // 测试第1页
     let (start, end) = page_transToStartEnd(1, 10);
@@ -37,6 +38,7 @@ pub fn test_page_transToStartEnd() {
 }
 
 #[test]
+#[allow(deprecated)]
 pub fn test_page_totalPage() {
     // 整除情况
     assert_eq!(page_totalPage(9, 3), 3);
@@ -52,6 +54,7 @@ pub fn test_page_totalPage() {
 }
 
 #[test]
+#[allow(deprecated)]
 pub fn test_page_rainbow() {
     // 测试总页数大于显示数量，当前页在左侧
     let result = page_rainbow(1, 10, 5);
@@ -77,3 +80,969 @@ pub fn test_page_rainbow() {
     let result = page_rainbow(5, 20, 6);
     assert_eq!(result, vec![3, 4, 5, 6, 7, 8]);
 }
+
+#[test]
+#[allow(deprecated)]
+pub fn test_page_to_range() {
+    assert_eq!(page_to_range(1, 10), (0, 10));
+    assert_eq!(page_to_range(2, 10), (10, 20));
+    assert_eq!(page_to_range(5, 20), (80, 100));
+
+    for page in 1..=10 {
+        for size in 1..=25 {
+            assert_eq!(page_to_range(page, size), page_transToStartEnd(page, size));
+        }
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+pub fn test_total_pages() {
+    assert_eq!(total_pages(0, 10), 0);
+    assert_eq!(total_pages(1, 10), 1);
+    assert_eq!(total_pages(10, 10), 1);
+    assert_eq!(total_pages(11, 10), 2);
+    assert_eq!(total_pages(i32::MAX, 10), 214748365);
+    assert_eq!(total_pages(i32::MAX - 5, 100), 21474837);
+    assert_eq!(total_pages(i32::MAX, 1), i32::MAX);
+    assert_eq!(page_totalPage(i32::MAX, 10), 214748365);
+
+    for total in 0..=100 {
+        for size in 1..=12 {
+            assert_eq!(total_pages(total, size), page_totalPage(total, size));
+        }
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+pub fn test_pagination_window() {
+    assert_eq!(pagination_window(1, 10, 5), vec![1, 2, 3, 4, 5]);
+    assert_eq!(pagination_window(5, 10, 5), vec![3, 4, 5, 6, 7]);
+    assert_eq!(pagination_window(10, 10, 5), vec![6, 7, 8, 9, 10]);
+    assert_eq!(pagination_window(2, 5, 10), vec![1, 2, 3, 4, 5]);
+    assert_eq!(pagination_window(5, 20, 6), vec![3, 4, 5, 6, 7, 8]);
+
+    for total in 0..=15 {
+        for window in 1..=8 {
+            for page in 1..=total.max(1) {
+                let pages = pagination_window(page, total, window);
+                assert_eq!(pages, page_rainbow(page, total, window));
+                assert_eq!(pages.len() as i32, total.min(window));
+                if total > 0 {
+                    assert!(pages.contains(&page));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+pub fn test_pagination_window_non_positive() {
+    assert!(pagination_window(1, 0, 5).is_empty());
+    assert!(pagination_window(1, -1, 5).is_empty());
+    assert!(pagination_window(1, i32::MIN, 5).is_empty());
+    assert!(pagination_window(1, 10, 0).is_empty());
+    assert!(pagination_window(5, 10, -3).is_empty());
+    assert!(pagination_window(1, 10, i32::MIN).is_empty());
+    assert!(page_rainbow(1, -1, 5).is_empty());
+    assert!(page_rainbow(5, 10, -3).is_empty());
+}
+
+#[test]
+pub fn test_is_valid_chinese_id() {
+    // 合法号码
+    assert!(is_valid_chinese_id("11010519491231002X"));
+    assert!(is_valid_chinese_id("11010519491231002x"));
+    assert!(is_valid_chinese_id("110105199003071239"));
+    assert!(is_valid_chinese_id("320106198501010018"));
+
+    // 闰年 2 月 29 日
+    assert!(is_valid_chinese_id("440304200002291236"));
+
+    // 校验码错误
+    assert!(!is_valid_chinese_id("110105199003071238"));
+    assert!(!is_valid_chinese_id("110105194912310021"));
+
+    // 非法日期
+    assert!(!is_valid_chinese_id("440304190002291236")); // 1900 年不是闰年
+    assert!(!is_valid_chinese_id("110105199013071239")); // 13 月
+    assert!(!is_valid_chinese_id("110105199004311239")); // 4 月 31 日
+    assert!(!is_valid_chinese_id("110105199003001239")); // 0 日
+
+    // 格式错误
+    assert!(!is_valid_chinese_id(""));
+    assert!(!is_valid_chinese_id("11010519491231002"));
+    assert!(!is_valid_chinese_id("11010519491231002XX"));
+    assert!(!is_valid_chinese_id("1101051949123100AX"));
+    assert!(!is_valid_chinese_id("11010519491231002Y"));
+    assert!(!is_valid_chinese_id("１10105199003071239"));
+}
+
+#[test]
+pub fn test_parse_chinese_id() {
+    let info = parse_chinese_id("110105199003071239").unwrap();
+    assert_eq!(info.region_code, "110105");
+    assert_eq!(info.birthdate, (1990, 3, 7));
+    assert_eq!(info.sequence, "123");
+    assert_eq!(info.gender, Gender::Male);
+
+    let info = parse_chinese_id("11010519491231002X").unwrap();
+    assert_eq!(info.gender, Gender::Female);
+
+    assert_eq!(parse_chinese_id("110105199003071238"), None);
+}
+
+#[test]
+pub fn test_random_between() {
+    let mut seen_min = false;
+    let mut seen_max = false;
+    for _ in 0..1000 {
+        let n = random_between(-3, 3);
+        assert!((-3..=3).contains(&n));
+        seen_min |= n == -3;
+        seen_max |= n == 3;
+    }
+    assert!(seen_min && seen_max);
+
+    assert_eq!(random_between(7, 7), 7);
+}
+
+#[test]
+pub fn test_random_between_distribution() {
+    // 卡方检验：6 个取值、自由度 5，阈值取 30 时误报概率低于 0.002%
+    let samples = 6000;
+    let mut counts = [0u32; 6];
+    for _ in 0..samples {
+        counts[(random_between(1, 6) - 1) as usize] += 1;
+    }
+    let expected = samples as f64 / 6.0;
+    let chi_square: f64 = counts
+        .iter()
+        .map(|&c| (c as f64 - expected).powi(2) / expected)
+        .sum();
+    assert!(chi_square < 30.0, "chi-square too large: {}", chi_square);
+}
+
+#[test]
+#[should_panic]
+pub fn test_random_between_invalid_range() {
+    random_between(5, 1);
+}
+
+#[test]
+pub fn test_random_float() {
+    for _ in 0..1000 {
+        let f = random_float(-1.5, 2.5);
+        assert!((-1.5..2.5).contains(&f));
+    }
+}
+
+#[test]
+pub fn test_shuffle_slice() {
+    let original: Vec<i32> = (0..20).collect();
+
+    let mut changed = false;
+    for _ in 0..10 {
+        let mut items = original.clone();
+        shuffle_slice(&mut items);
+        changed |= items != original;
+
+        items.sort();
+        assert_eq!(items, original);
+    }
+    assert!(changed);
+
+    let mut empty: Vec<i32> = vec![];
+    shuffle_slice(&mut empty);
+    assert!(empty.is_empty());
+}
+
+#[test]
+pub fn test_shuffle_slice_all_orderings() {
+    // 3 个元素共 6 种排列，多次打乱后每种排列都应出现
+    let mut orderings = std::collections::HashSet::new();
+    for _ in 0..1000 {
+        let mut items = [1, 2, 3];
+        shuffle_slice(&mut items);
+        orderings.insert(items);
+    }
+    assert_eq!(orderings.len(), 6);
+}
+
+#[test]
+pub fn test_sample() {
+    let items: Vec<i32> = (0..10).collect();
+
+    for n in 0..=10 {
+        let mut picked = sample(&items, n);
+        assert_eq!(picked.len(), n);
+
+        // 不放回抽样，不应出现重复元素
+        picked.sort();
+        picked.dedup();
+        assert_eq!(picked.len(), n);
+        assert!(picked.iter().all(|x| items.contains(x)));
+    }
+
+    assert_eq!(sample(&items, 20).len(), 10);
+    assert!(sample::<i32>(&[], 3).is_empty());
+}
+
+#[test]
+pub fn test_choose() {
+    let items = vec![1, 2, 3];
+    let mut seen = std::collections::HashSet::new();
+    for _ in 0..300 {
+        seen.insert(*choose(&items).unwrap());
+    }
+    assert_eq!(seen.len(), 3);
+
+    let empty: Vec<i32> = vec![];
+    assert_eq!(choose(&empty), None);
+}
+
+#[test]
+pub fn test_snowflake_monotonic_and_unique() {
+    let mut snowflake = Snowflake::new(1);
+    let ids: Vec<u64> = (0..1000).map(|_| snowflake.next_id()).collect();
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    let unique: std::collections::HashSet<u64> = ids.iter().copied().collect();
+    assert_eq!(unique.len(), 1000);
+
+    // 同一毫秒内序列号用尽后仍然保持递增
+    let ids: Vec<u64> = (0..10_000).map(|_| snowflake.next_id()).collect();
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+
+    // 机器 ID 编码在第 12 ~ 21 位
+    let mut snowflake = Snowflake::new(Snowflake::MAX_MACHINE_ID);
+    let id = snowflake.next_id();
+    assert_eq!((id >> 12) & 0x3FF, 1023);
+    assert_eq!(id >> 63, 0);
+}
+
+#[test]
+pub fn test_snowflake_timestamp_from_id() {
+    let now = || {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64
+    };
+
+    let mut snowflake = Snowflake::new(3);
+    let before = now();
+    let id = snowflake.next_id();
+    let after = now();
+    let timestamp = snowflake.timestamp_from_id(id);
+    assert!(before <= timestamp && timestamp <= after);
+
+    let mut snowflake = Snowflake::with_epoch(3, 1_577_836_800_000);
+    let before = now();
+    let id = snowflake.next_id();
+    let after = now();
+    let timestamp = snowflake.timestamp_from_id(id);
+    assert!(before <= timestamp && timestamp <= after);
+}
+
+#[test]
+#[should_panic]
+pub fn test_snowflake_invalid_machine_id() {
+    Snowflake::new(1024);
+}
+
+#[test]
+pub fn test_version_compare() {
+    use std::cmp::Ordering;
+
+    assert_eq!(version_compare("1.9", "1.10"), Ordering::Less);
+    assert_eq!(version_compare("1.10", "1.9"), Ordering::Greater);
+    assert_eq!(version_compare("1.10.2", "1.9.3"), Ordering::Greater);
+    assert_eq!(version_compare("1.2.3", "1.2.3"), Ordering::Equal);
+    assert_eq!(version_compare("01.2", "1.02"), Ordering::Equal);
+    assert_eq!(version_compare(" 1.2 ", "1.2"), Ordering::Equal);
+    assert_eq!(version_compare("1.0", "1.0.1"), Ordering::Less);
+    assert_eq!(version_compare("1.0.0", "1.0"), Ordering::Greater);
+    assert_eq!(version_compare("2", "10"), Ordering::Less);
+
+    // 数字部分相同时按字典序比较后缀
+    assert_eq!(version_compare("1.0.0-alpha", "1.0.0-beta"), Ordering::Less);
+    assert_eq!(
+        version_compare("1.0.0-rc2", "1.0.0-rc10"),
+        Ordering::Greater
+    );
+    assert_eq!(
+        version_compare("1.0.1-alpha", "1.0.0-beta"),
+        Ordering::Greater
+    );
+    assert_eq!(version_compare("1.0.0", "1.0.0-alpha"), Ordering::Less);
+
+    // 无法解析的版本号小于任何合法版本号
+    assert_eq!(version_compare("", "0"), Ordering::Less);
+    assert_eq!(version_compare("abc", "0.0.1"), Ordering::Less);
+    assert_eq!(version_compare("1.x", "1.0"), Ordering::Less);
+    assert_eq!(version_compare("1..2", "1.2"), Ordering::Less);
+    assert_eq!(version_compare("0.1", "v1.0"), Ordering::Greater);
+    assert_eq!(version_compare("abc", "abd"), Ordering::Less);
+    assert_eq!(version_compare("", ""), Ordering::Equal);
+}
+
+#[test]
+pub fn test_is_version_gte() {
+    assert!(is_version_gte("1.10", "1.9"));
+    assert!(is_version_gte("1.9", "1.9"));
+    assert!(is_version_gte("1.9.0", "1.9"));
+    assert!(!is_version_gte("1.9", "1.9.0"));
+    assert!(!is_version_gte("1.9", "1.10"));
+    assert!(!is_version_gte("invalid", "0.0.1"));
+    assert!(is_version_gte("0.0.1", "invalid"));
+}
+
+#[test]
+pub fn test_xor_cipher() {
+    let data = b"The quick brown fox jumps over the lazy dog";
+
+    // 往返加解密
+    for key in [
+        &b"k"[..],
+        b"secret",
+        data,
+        b"a key that is longer than the data itself!!!",
+    ] {
+        let encrypted = xor_cipher(data, key);
+        assert_eq!(encrypted.len(), data.len());
+        assert_ne!(encrypted.as_slice(), &data[..]);
+        assert_eq!(xor_cipher(&encrypted, key), data);
+    }
+
+    // 单字节密钥
+    assert_eq!(
+        xor_cipher(&[0x00, 0xFF, 0x0F], &[0xFF]),
+        vec![0xFF, 0x00, 0xF0]
+    );
+    // 密钥与数据等长
+    assert_eq!(xor_cipher(&[1, 2, 3], &[1, 2, 3]), vec![0, 0, 0]);
+    // 密钥比数据长时只使用前面部分
+    assert_eq!(xor_cipher(&[1, 2], &[3, 3, 3, 3]), vec![2, 1]);
+    // 密钥循环使用
+    assert_eq!(xor_cipher(&[1, 1, 1, 1, 1], &[1, 0]), vec![0, 1, 0, 1, 0]);
+
+    assert!(xor_cipher(&[], b"key").is_empty());
+}
+
+#[test]
+#[should_panic]
+pub fn test_xor_cipher_empty_key() {
+    xor_cipher(b"data", &[]);
+}
+
+#[test]
+pub fn test_xor_cipher_str() {
+    let encrypted = xor_cipher_str("hello, 世界", "key");
+    assert_eq!(encrypted, xor_cipher("hello, 世界".as_bytes(), b"key"));
+    let decrypted = xor_cipher(&encrypted, b"key");
+    assert_eq!(String::from_utf8(decrypted).unwrap(), "hello, 世界");
+    assert!(xor_cipher_str("", "key").is_empty());
+}
+
+#[cfg(feature = "util-otp")]
+#[test]
+pub fn test_generate_otp_rfc4226_vectors() {
+    // RFC 4226 附录 D：密钥 "12345678901234567890"，计数器 0 ~ 9
+    let expected = [
+        "755224", "287082", "359152", "969429", "338314", "254676", "287922", "162583", "399871",
+        "520489",
+    ];
+    let secret = b"12345678901234567890";
+    for (counter, code) in expected.iter().enumerate() {
+        assert_eq!(generate_otp(secret, counter as u64 * 30, 30), *code);
+        // 同一周期内的时间戳得到相同的密码
+        assert_eq!(generate_otp(secret, counter as u64 * 30 + 29, 30), *code);
+    }
+}
+
+#[cfg(feature = "util-otp")]
+#[test]
+pub fn test_generate_otp_rfc6238_vectors() {
+    // RFC 6238 附录 B 中 SHA1 的测试向量，取 8 位结果的后 6 位
+    let secret = b"12345678901234567890";
+    assert_eq!(generate_otp(secret, 59, 30), "287082");
+    assert_eq!(generate_otp(secret, 1_111_111_109, 30), "081804");
+    assert_eq!(generate_otp(secret, 1_111_111_111, 30), "050471");
+    assert_eq!(generate_otp(secret, 1_234_567_890, 30), "005924");
+    assert_eq!(generate_otp(secret, 2_000_000_000, 30), "279037");
+    assert_eq!(generate_otp(secret, 20_000_000_000, 30), "353130");
+}
+
+#[test]
+pub fn test_parse_duration_str() {
+    let secs = std::time::Duration::from_secs;
+    let millis = std::time::Duration::from_millis;
+
+    assert_eq!(parse_duration_str("1h"), Some(secs(3600)));
+    assert_eq!(parse_duration_str("30m"), Some(secs(1800)));
+    assert_eq!(parse_duration_str("90s"), Some(secs(90)));
+    assert_eq!(parse_duration_str("1d"), Some(secs(86_400)));
+    assert_eq!(parse_duration_str("500ms"), Some(millis(500)));
+    assert_eq!(parse_duration_str("1h30m"), Some(secs(5400)));
+    assert_eq!(parse_duration_str("2h15m30s"), Some(secs(8130)));
+    assert_eq!(parse_duration_str("0s"), Some(secs(0)));
+
+    // 各种单位写法
+    for unit in ["d", "day", "days", "D", "Days"] {
+        assert_eq!(
+            parse_duration_str(&format!("2{}", unit)),
+            Some(secs(172_800))
+        );
+    }
+    for unit in ["h", "hr", "hrs", "hour", "hours", "H"] {
+        assert_eq!(parse_duration_str(&format!("2{}", unit)), Some(secs(7200)));
+    }
+    for unit in ["m", "min", "mins", "minute", "minutes", "MIN"] {
+        assert_eq!(parse_duration_str(&format!("2{}", unit)), Some(secs(120)));
+    }
+    for unit in ["s", "sec", "secs", "second", "seconds"] {
+        assert_eq!(parse_duration_str(&format!("2{}", unit)), Some(secs(2)));
+    }
+    for unit in ["ms", "millisecond", "milliseconds", "MS"] {
+        assert_eq!(parse_duration_str(&format!("2{}", unit)), Some(millis(2)));
+    }
+
+    // 任意顺序、重复与空白
+    assert_eq!(parse_duration_str("30s1h"), Some(secs(3630)));
+    assert_eq!(parse_duration_str("10m10m10m"), Some(secs(1800)));
+    assert_eq!(parse_duration_str(" 1 hour 30 minutes "), Some(secs(5400)));
+    assert_eq!(
+        parse_duration_str("1d 2h 3m 4s 5ms"),
+        Some(millis(93_784_005))
+    );
+
+    // 无法识别的格式
+    assert_eq!(parse_duration_str(""), None);
+    assert_eq!(parse_duration_str("   "), None);
+    assert_eq!(parse_duration_str("10"), None);
+    assert_eq!(parse_duration_str("h"), None);
+    assert_eq!(parse_duration_str("1h30"), None);
+    assert_eq!(parse_duration_str("1w"), None);
+    assert_eq!(parse_duration_str("1.5h"), None);
+    assert_eq!(parse_duration_str("-1h"), None);
+    assert_eq!(parse_duration_str("1h,30m"), None);
+    assert_eq!(parse_duration_str("99999999999999999999s"), None);
+    assert_eq!(parse_duration_str("999999999999999d"), None);
+}
+
+#[test]
+pub fn test_format_duration_str() {
+    let secs = std::time::Duration::from_secs;
+    let millis = std::time::Duration::from_millis;
+
+    assert_eq!(format_duration_str(std::time::Duration::ZERO), "0s");
+    assert_eq!(
+        format_duration_str(std::time::Duration::from_nanos(999_999)),
+        "0s"
+    );
+    assert_eq!(format_duration_str(millis(500)), "500ms");
+    assert_eq!(format_duration_str(secs(90)), "1m30s");
+    assert_eq!(format_duration_str(secs(3600)), "1h");
+    assert_eq!(format_duration_str(secs(8130)), "2h15m30s");
+    assert_eq!(format_duration_str(secs(86_400 + 60)), "1d1m");
+    assert_eq!(format_duration_str(millis(93_784_005)), "1d2h3m4s5ms");
+
+    // parse -> format -> parse 往返一致
+    for input in [
+        "1h",
+        "30m",
+        "90s",
+        "1h30m",
+        "2h15m30s",
+        "1d",
+        "500ms",
+        "30s1h",
+        "1 hour 90 minutes",
+        "3days 25hours",
+        "0s",
+    ] {
+        let parsed = parse_duration_str(input).unwrap();
+        let formatted = format_duration_str(parsed);
+        assert_eq!(parse_duration_str(&formatted), Some(parsed));
+        assert_eq!(
+            format_duration_str(parse_duration_str(&formatted).unwrap()),
+            formatted
+        );
+    }
+}
+
+#[test]
+pub fn test_is_valid_phone_e164() {
+    assert!(is_valid_phone_e164("+14155552671"));
+    assert!(is_valid_phone_e164("+8613800138000"));
+    assert!(is_valid_phone_e164("+442079460958"));
+    assert!(is_valid_phone_e164("+1234567"));
+    assert!(is_valid_phone_e164("+123456789012345"));
+
+    assert!(!is_valid_phone_e164(""));
+    assert!(!is_valid_phone_e164("+"));
+    assert!(!is_valid_phone_e164("14155552671"));
+    assert!(!is_valid_phone_e164("+123456"));
+    assert!(!is_valid_phone_e164("+1234567890123456"));
+    assert!(!is_valid_phone_e164("+04155552671"));
+    assert!(!is_valid_phone_e164("+1 415 555 2671"));
+    assert!(!is_valid_phone_e164("+1-415-555-2671"));
+    assert!(!is_valid_phone_e164("++14155552671"));
+    assert!(!is_valid_phone_e164("+1415555267a"));
+    assert!(!is_valid_phone_e164("+１４１５５５５２６７１"));
+}
+
+#[test]
+pub fn test_normalize_phone_e164() {
+    let normalize = |s: &str, cc: &str| normalize_phone_e164(s, cc);
+    let expected = |s: &str| Some(String::from(s));
+
+    // 美国
+    assert_eq!(normalize("(415) 555-2671", "1"), expected("+14155552671"));
+    assert_eq!(normalize("415-555-2671", "1"), expected("+14155552671"));
+    assert_eq!(normalize("4155552671", "1"), expected("+14155552671"));
+    assert_eq!(
+        normalize("+1 (415) 555-2671", "1"),
+        expected("+14155552671")
+    );
+
+    // 中国
+    assert_eq!(normalize("138 0013 8000", "86"), expected("+8613800138000"));
+    assert_eq!(normalize("138-0013-8000", "86"), expected("+8613800138000"));
+    assert_eq!(normalize("010-12345678", "86"), expected("+861012345678"));
+    assert_eq!(
+        normalize("+86 138 0013 8000", "86"),
+        expected("+8613800138000")
+    );
+    assert_eq!(
+        normalize("0086 138 0013 8000", "1"),
+        expected("+8613800138000")
+    );
+
+    // 英国
+    assert_eq!(normalize("020 7946 0958", "44"), expected("+442079460958"));
+    assert_eq!(normalize("07911 123456", "44"), expected("+447911123456"));
+    assert_eq!(
+        normalize("+44 (0)20-7946-0958", "44"),
+        expected("+442079460958")
+    );
+    assert_eq!(
+        normalize("+44 20 7946 0958", "1"),
+        expected("+442079460958")
+    );
+
+    // 无效输入
+    assert_eq!(normalize("", "1"), None);
+    assert_eq!(normalize("call me", "1"), None);
+    assert_eq!(normalize("555-2671", "1"), expected("+15552671"));
+    assert_eq!(normalize("12", "1"), None);
+    assert_eq!(normalize("415.555.2671", "1"), None);
+    assert_eq!(normalize("4155552671", ""), None);
+    assert_eq!(normalize("4155552671", "+1"), None);
+    assert_eq!(normalize("4155552671", "01"), None);
+    assert_eq!(normalize("1234567890123456", "86"), None);
+}
+
+#[test]
+pub fn test_simple_template() {
+    let vars = std::collections::HashMap::from([
+        ("name", "Tom"),
+        ("city", "Beijing"),
+        ("a", "1"),
+        ("b", "2"),
+        ("user.id", "42"),
+    ]);
+
+    assert_eq!(simple_template("", &vars), "");
+    assert_eq!(simple_template("no variables", &vars), "no variables");
+    assert_eq!(simple_template("Hello {{name}}!", &vars), "Hello Tom!");
+    assert_eq!(
+        simple_template("{{ name }} from {{city}}", &vars),
+        "Tom from Beijing"
+    );
+    assert_eq!(simple_template("id={{user.id}}", &vars), "id=42");
+
+    // 变量出现多次与相邻变量
+    assert_eq!(
+        simple_template("{{name}}, {{name}}, {{name}}", &vars),
+        "Tom, Tom, Tom"
+    );
+    assert_eq!(simple_template("{{a}}{{b}}{{a}}", &vars), "121");
+
+    // 缺少的变量原样保留
+    assert_eq!(simple_template("Hi {{nickname}}", &vars), "Hi {{nickname}}");
+    assert_eq!(
+        simple_template("{{a}}{{missing}}{{b}}", &vars),
+        "1{{missing}}2"
+    );
+
+    // 空的变量映射
+    let empty = std::collections::HashMap::new();
+    assert_eq!(simple_template("Hi {{name}}", &empty), "Hi {{name}}");
+
+    // 转义与不完整的占位符
+    assert_eq!(simple_template("{{{{name}}", &vars), "{{name}}");
+    assert_eq!(simple_template("{{{{{{name}}", &vars), "{{Tom");
+    assert_eq!(simple_template("use {{{{ and }}", &vars), "use {{ and }}");
+    assert_eq!(simple_template("{{name", &vars), "{{name");
+    assert_eq!(simple_template("{{}} {{ }}", &vars), "{{}} {{ }}");
+    assert_eq!(
+        simple_template("{{not a var}} {{name}}", &vars),
+        "{{not a var}} Tom"
+    );
+    assert_eq!(simple_template("{ {name} }", &vars), "{ {name} }");
+
+    // 值中的占位符不会被再次替换
+    let nested = std::collections::HashMap::from([("x", "{{y}}"), ("y", "boom")]);
+    assert_eq!(simple_template("{{x}}", &nested), "{{y}}");
+
+    assert_eq!(simple_template("你好，{{name}}！", &vars), "你好，Tom！");
+}
+
+#[test]
+pub fn test_simple_template_strict() {
+    let vars = std::collections::HashMap::from([("name", "Tom"), ("city", "Beijing")]);
+
+    assert_eq!(
+        simple_template_strict("{{name}} from {{city}}", &vars),
+        Ok(String::from("Tom from Beijing"))
+    );
+    assert_eq!(
+        simple_template_strict("plain", &vars),
+        Ok(String::from("plain"))
+    );
+    assert_eq!(
+        simple_template_strict("{{{{x}}", &vars),
+        Ok(String::from("{{x}}"))
+    );
+
+    assert_eq!(
+        simple_template_strict("{{greeting}} {{name}}", &vars),
+        Err(String::from("Missing template variables: greeting"))
+    );
+    assert_eq!(
+        simple_template_strict("{{b}} {{a}} {{b}} {{name}}", &vars),
+        Err(String::from("Missing template variables: b, a"))
+    );
+
+    let empty = std::collections::HashMap::new();
+    assert_eq!(
+        simple_template_strict("{{name}}", &empty),
+        Err(String::from("Missing template variables: name"))
+    );
+    assert_eq!(simple_template_strict("", &empty), Ok(String::new()));
+}
+
+#[test]
+pub fn test_lru_cache_capacity() {
+    let mut cache = LruCache::new(3);
+    assert!(cache.is_empty());
+    assert_eq!(cache.capacity(), 3);
+    assert_eq!(cache.insert(1, "one"), None);
+    assert_eq!(cache.insert(2, "two"), None);
+    assert_eq!(cache.insert(3, "three"), None);
+    assert_eq!(cache.len(), 3);
+
+    // 最旧的条目被淘汰
+    assert_eq!(cache.insert(4, "four"), Some("one"));
+    assert_eq!(cache.len(), 3);
+    assert!(!cache.contains_key(&1));
+    assert!(cache.contains_key(&2));
+    assert!(cache.contains_key(&4));
+
+    // 替换已有键返回旧值，不淘汰其他条目
+    assert_eq!(cache.insert(2, "TWO"), Some("two"));
+    assert_eq!(cache.len(), 3);
+    assert_eq!(cache.get(&2), Some(&"TWO"));
+    assert_eq!(cache.get(&1), None);
+}
+
+#[test]
+pub fn test_lru_cache_get_promotes() {
+    let mut cache = LruCache::new(2);
+    cache.insert("a", 1);
+    cache.insert("b", 2);
+    assert_eq!(cache.get(&"a"), Some(&1));
+    assert_eq!(cache.insert("c", 3), Some(2));
+    assert!(cache.contains_key(&"a"));
+    assert!(!cache.contains_key(&"b"));
+
+    // contains_key 不改变访问顺序
+    assert!(cache.contains_key(&"a"));
+    assert_eq!(cache.insert("d", 4), Some(1));
+    assert!(cache.contains_key(&"c"));
+}
+
+#[test]
+pub fn test_lru_cache_eviction_order() {
+    let mut cache = LruCache::new(2);
+    cache.insert(1, 10);
+    cache.insert(2, 20);
+    assert_eq!(cache.insert(3, 30), Some(10));
+    assert_eq!(cache.insert(4, 40), Some(20));
+    assert_eq!(cache.insert(5, 50), Some(30));
+
+    let mut cache = LruCache::new(1);
+    assert_eq!(cache.insert("x", 1), None);
+    assert_eq!(cache.insert("y", 2), Some(1));
+    assert_eq!(cache.get(&"y"), Some(&2));
+}
+
+#[test]
+#[should_panic(expected = "capacity must be greater than 0")]
+pub fn test_lru_cache_zero_capacity() {
+    let _ = LruCache::<i32, i32>::new(0);
+}
+
+#[test]
+pub fn test_snake_to_display_name() {
+    assert_eq!(snake_to_display_name("name"), "Name");
+    assert_eq!(snake_to_display_name("user_first_name"), "User First Name");
+    assert_eq!(
+        snake_to_display_name("user__first___name"),
+        "User First Name"
+    );
+    assert_eq!(snake_to_display_name("_user_name_"), "User Name");
+    assert_eq!(snake_to_display_name("__"), "");
+    assert_eq!(snake_to_display_name(""), "");
+    assert_eq!(snake_to_display_name("User First Name"), "User First Name");
+    assert_eq!(snake_to_display_name("user_ID"), "User ID");
+    assert_eq!(snake_to_display_name("USER_NAME"), "USER NAME");
+    assert_eq!(snake_to_display_name("address_line_2"), "Address Line 2");
+    assert_eq!(snake_to_display_name("创建_时间"), "创建 时间");
+}
+
+#[test]
+pub fn test_camel_to_display_name() {
+    assert_eq!(camel_to_display_name("name"), "Name");
+    assert_eq!(camel_to_display_name("Name"), "Name");
+    assert_eq!(camel_to_display_name("userFirstName"), "User First Name");
+    assert_eq!(camel_to_display_name("UserFirstName"), "User First Name");
+    assert_eq!(camel_to_display_name("userID"), "User ID");
+    assert_eq!(
+        camel_to_display_name("parseHTTPResponse"),
+        "Parse HTTP Response"
+    );
+    assert_eq!(camel_to_display_name("HTTP"), "HTTP");
+    assert_eq!(camel_to_display_name("address2Line"), "Address2 Line");
+    assert_eq!(camel_to_display_name(""), "");
+    assert_eq!(camel_to_display_name("User First Name"), "User First Name");
+    assert_eq!(camel_to_display_name("  userName  "), "User Name");
+}
+
+#[test]
+pub fn test_chunk_slice() {
+    let items: Vec<i32> = (1..=10).collect();
+    let chunks = chunk_slice(&items, 3);
+    assert_eq!(chunks.len(), 4);
+    assert_eq!(chunks.iter().map(|c| c.len()).sum::<usize>(), items.len());
+    assert_eq!(chunks.last().unwrap(), &[10]);
+    assert!(chunks[..3].iter().all(|c| c.len() == 3));
+    assert_eq!(chunks.concat(), items);
+
+    // 恰好整除时最后一块也是完整的
+    assert_eq!(chunk_slice(&items, 5), vec![&items[..5], &items[5..]]);
+
+    // 每块一个元素
+    let singles = chunk_slice(&items, 1);
+    assert_eq!(singles.len(), 10);
+    assert!(singles.iter().zip(&items).all(|(c, x)| c == &[*x]));
+
+    // 块大小大于切片长度
+    assert_eq!(chunk_slice(&items, 100), vec![&items[..]]);
+    assert!(chunk_slice::<i32>(&[], 3).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "chunk_size must be greater than 0")]
+pub fn test_chunk_slice_zero_size() {
+    let _ = chunk_slice(&[1, 2, 3], 0);
+}
+
+#[test]
+pub fn test_batch_process() {
+    let items: Vec<u32> = (1..=7).collect();
+    let mut batches: Vec<Vec<u32>> = Vec::new();
+    batch_process(&items, 3, |batch| batches.push(batch.to_vec()));
+    assert_eq!(batches, vec![vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+
+    let mut calls = 0;
+    batch_process(&items, 10, |batch| {
+        calls += 1;
+        assert_eq!(batch.len(), 7);
+    });
+    assert_eq!(calls, 1);
+
+    let mut calls = 0;
+    batch_process::<u32, _>(&[], 2, |_| calls += 1);
+    assert_eq!(calls, 0);
+}
+
+#[test]
+pub fn test_bit_flags_single() {
+    const A: u64 = 1;
+    const B: u64 = 1 << 1;
+    const C: u64 = 1 << 63;
+
+    let mut flags = BitFlags::default();
+    assert_eq!(flags.value(), 0);
+    assert!(!flags.has(A));
+
+    flags.set(A);
+    assert!(flags.has(A));
+    assert!(!flags.has(B));
+    assert_eq!(flags.value(), 1);
+
+    // 重复设置不改变结果
+    flags.set(A);
+    assert_eq!(flags.value(), 1);
+
+    flags.set(C);
+    assert!(flags.has(C));
+    assert_eq!(flags.value(), C | A);
+
+    flags.unset(A);
+    assert!(!flags.has(A));
+    flags.unset(A);
+    assert_eq!(flags.value(), C);
+
+    flags.toggle(B);
+    assert!(flags.has(B));
+    flags.toggle(B);
+    assert!(!flags.has(B));
+    assert_eq!(flags.value(), C);
+}
+
+#[test]
+pub fn test_bit_flags_combined() {
+    const READ: u64 = 0b001;
+    const WRITE: u64 = 0b010;
+    const EXECUTE: u64 = 0b100;
+
+    let mut flags = BitFlags::new(0);
+    flags.set(READ | WRITE);
+    assert!(flags.has_all(READ | WRITE));
+    assert!(!flags.has_all(READ | EXECUTE));
+    assert!(flags.has_any(READ | EXECUTE));
+    assert!(!flags.has_any(EXECUTE));
+
+    flags.toggle(WRITE | EXECUTE);
+    assert_eq!(flags.value(), READ | EXECUTE);
+
+    flags.unset(READ | EXECUTE).set(WRITE);
+    assert_eq!(flags.value(), WRITE);
+    assert_eq!(BitFlags::from(WRITE), flags);
+}
+
+#[test]
+pub fn test_bit_flags_boundaries() {
+    let zero = BitFlags::new(0);
+    assert!(zero.has(0));
+    assert!(zero.has_all(0));
+    assert!(!zero.has_any(0));
+    assert!(!zero.has_any(u64::MAX));
+    assert!(!zero.has_all(u64::MAX));
+    assert_eq!(zero.to_string(), "0b0");
+
+    let mut all = BitFlags::new(u64::MAX);
+    assert!(all.has_all(u64::MAX));
+    assert!(all.has_any(1 << 63));
+    assert!(!all.has_any(0));
+    assert_eq!(all.to_string(), format!("0b{}", "1".repeat(64)));
+
+    all.toggle(u64::MAX);
+    assert_eq!(all, zero);
+    all.set(u64::MAX).unset(u64::MAX);
+    assert_eq!(all.value(), 0);
+
+    assert_eq!(BitFlags::new(0b1010).to_string(), "0b1010");
+}
+
+#[cfg(feature = "util-json")]
+#[test]
+pub fn test_deep_merge_json() {
+    use serde_json::json;
+
+    let base = json!({
+        "server": {"host": "localhost", "port": 80, "tls": {"enabled": false, "cert": "a.pem"}},
+        "features": ["a", "b", "c"],
+        "timeout": 30,
+        "name": "app"
+    });
+    let override_ = json!({
+        "server": {"port": 8080, "tls": {"enabled": true}},
+        "features": ["x"],
+        "timeout": null,
+        "extra": {"k": 1}
+    });
+    assert_eq!(
+        deep_merge_json(base.clone(), override_),
+        json!({
+            "server": {"host": "localhost", "port": 8080, "tls": {"enabled": true, "cert": "a.pem"}},
+            "features": ["x"],
+            "timeout": null,
+            "name": "app",
+            "extra": {"k": 1}
+        })
+    );
+
+    // 空对象不改变任何值
+    assert_eq!(deep_merge_json(base.clone(), json!({})), base);
+    assert_eq!(deep_merge_json(json!({}), base.clone()), base);
+
+    // 非对象值直接替换
+    assert_eq!(
+        deep_merge_json(json!({"a": 1}), json!([1, 2])),
+        json!([1, 2])
+    );
+    assert_eq!(
+        deep_merge_json(json!([1, 2]), json!({"a": 1})),
+        json!({"a": 1})
+    );
+    assert_eq!(
+        deep_merge_json(json!({"a": {"b": 1}}), json!({"a": 5})),
+        json!({"a": 5})
+    );
+    assert_eq!(
+        deep_merge_json(json!({"a": 5}), json!({"a": {"b": 1}})),
+        json!({"a": {"b": 1}})
+    );
+    assert_eq!(deep_merge_json(json!({"a": 1}), json!(null)), json!(null));
+}
+
+#[cfg(feature = "util-json")]
+#[test]
+pub fn test_json_get_path() {
+    use serde_json::json;
+
+    let config = json!({
+        "server": {"host": "localhost", "port": 8080, "tags": ["a", {"name": "b"}]},
+        "debug": null,
+        "a.b": 1
+    });
+    assert_eq!(
+        json_get_path(&config, "server.host"),
+        Some(&json!("localhost"))
+    );
+    assert_eq!(json_get_path(&config, "server.port"), Some(&json!(8080)));
+    assert_eq!(json_get_path(&config, "server"), config.get("server"));
+    assert_eq!(json_get_path(&config, "server.tags.0"), Some(&json!("a")));
+    assert_eq!(
+        json_get_path(&config, "server.tags.1.name"),
+        Some(&json!("b"))
+    );
+    assert_eq!(json_get_path(&config, "debug"), Some(&json!(null)));
+    assert_eq!(json_get_path(&config, ""), Some(&config));
+
+    // 不存在的路径
+    assert_eq!(json_get_path(&config, "missing"), None);
+    assert_eq!(json_get_path(&config, "server.missing.host"), None);
+    assert_eq!(json_get_path(&config, "server.host.length"), None);
+    assert_eq!(json_get_path(&config, "server.tags.2"), None);
+    assert_eq!(json_get_path(&config, "server.tags.x"), None);
+    assert_eq!(json_get_path(&config, "server."), None);
+    // 键名本身包含点号时无法通过路径访问
+    assert_eq!(json_get_path(&config, "a.b"), None);
+}
@@ -0,0 +1,67 @@
+use huturs_core::validation::*;
+
+#[test]
+pub fn test_is_numeric_str() {
+    assert!(is_numeric_str("0"));
+    assert!(is_numeric_str("0123456789"));
+
+    assert!(!is_numeric_str(""));
+    assert!(!is_numeric_str(" "));
+    assert!(!is_numeric_str("12a"));
+    assert!(!is_numeric_str("-1"));
+    assert!(!is_numeric_str("1.5"));
+    assert!(!is_numeric_str(" 12"));
+    assert!(!is_numeric_str("１２３")); // 全角数字
+    assert!(!is_numeric_str("٣")); // 阿拉伯-印度数字
+}
+
+#[test]
+pub fn test_is_alpha_str() {
+    assert!(is_alpha_str("a"));
+    assert!(is_alpha_str("abcXYZ"));
+    assert!(is_alpha_str("HELLO"));
+
+    assert!(!is_alpha_str(""));
+    assert!(!is_alpha_str("abc1"));
+    assert!(!is_alpha_str("hello world"));
+    assert!(!is_alpha_str("café"));
+    assert!(!is_alpha_str("中文"));
+}
+
+#[test]
+pub fn test_is_alphanumeric_str() {
+    assert!(is_alphanumeric_str("abc"));
+    assert!(is_alphanumeric_str("123"));
+    assert!(is_alphanumeric_str("aBc123XyZ"));
+
+    assert!(!is_alphanumeric_str(""));
+    assert!(!is_alphanumeric_str("abc-123"));
+    assert!(!is_alphanumeric_str("abc 123"));
+    assert!(!is_alphanumeric_str("ß1"));
+    assert!(!is_alphanumeric_str("中文123"));
+}
+
+#[test]
+pub fn test_is_ascii_str() {
+    assert!(is_ascii_str("abc"));
+    assert!(is_ascii_str("\t\r\n"));
+    assert!(is_ascii_str("\u{0}\u{7f}"));
+
+    assert!(!is_ascii_str(""));
+    assert!(!is_ascii_str("\u{80}"));
+    assert!(!is_ascii_str("abc中"));
+    assert!(!is_ascii_str("😀"));
+}
+
+#[test]
+pub fn test_is_ascii_printable_str() {
+    assert!(is_ascii_printable_str(" "));
+    assert!(is_ascii_printable_str("~"));
+    assert!(is_ascii_printable_str("Hello, World! 123 #$%"));
+
+    assert!(!is_ascii_printable_str(""));
+    assert!(!is_ascii_printable_str("\u{1f}"));
+    assert!(!is_ascii_printable_str("\u{7f}"));
+    assert!(!is_ascii_printable_str("line\n"));
+    assert!(!is_ascii_printable_str("é"));
+}